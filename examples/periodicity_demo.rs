@@ -1,5 +1,5 @@
-/// Example usage demonstrations for the Periodicity system
-/// This file shows how to use the new constraint-based periodicity model
+//! Example usage demonstrations for the Periodicity system
+//! This file shows how to use the new constraint-based periodicity model
 
 use tsadaash::domain::{
     Periodicity,
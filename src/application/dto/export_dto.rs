@@ -0,0 +1,79 @@
+//! Data export DTOs
+
+use chrono::{DateTime, Month, NaiveTime, Utc, Weekday};
+use crate::application::types::{ScheduleTemplateId, TaskId, UserId};
+use crate::domain::entities::schedule::ScheduleTemplate;
+use crate::domain::entities::task::{Task, TaskOccurrence};
+use crate::domain::entities::user::{Location, Timezone, User};
+
+/// Everything stored about a user, as one self-contained document
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserDataExport {
+    pub exported_at: DateTime<Utc>,
+    pub user_id: u64,
+    pub profile: UserProfileExport,
+    pub active_schedule_template_id: Option<u64>,
+    pub schedule_templates: Vec<ScheduleTemplateExport>,
+    pub tasks: Vec<TaskExport>,
+}
+
+/// User account and calendar settings (the password hash is never exported)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserProfileExport {
+    pub username: String,
+    pub email: String,
+    pub timezone: Timezone,
+    pub locations: Vec<Option<Location>>,
+    pub week_start: Weekday,
+    pub year_start: Month,
+    pub day_start: NaiveTime,
+}
+
+impl From<User> for UserProfileExport {
+    fn from(user: User) -> Self {
+        Self {
+            username: user.username,
+            email: user.email,
+            timezone: user.timezone,
+            locations: user.locations,
+            week_start: user.week_start,
+            year_start: user.year_start,
+            day_start: user.day_start,
+        }
+    }
+}
+
+/// A schedule template with its repository ID
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleTemplateExport {
+    pub id: u64,
+    pub template: ScheduleTemplate,
+}
+
+/// A task with its repository ID and recorded occurrences
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaskExport {
+    pub id: u64,
+    pub task: Task,
+    /// Empty until occurrences are persisted by the task repository
+    pub occurrences: Vec<TaskOccurrence>,
+}
+
+/// Input for restoring a user from an export bundle
+#[derive(Debug, Clone)]
+pub struct ImportUserDataInput {
+    pub export: UserDataExport,
+    pub password: String, // Plain password - exports never carry the hash
+}
+
+/// Output after a successful import, mapping exported IDs to the new ones
+#[derive(Debug, Clone)]
+pub struct ImportUserDataOutput {
+    pub user_id: UserId,
+    pub template_ids: Vec<(u64, ScheduleTemplateId)>,
+    pub task_ids: Vec<(u64, TaskId)>,
+}
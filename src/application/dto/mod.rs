@@ -1,11 +1,13 @@
-/// Data Transfer Objects
+//! Data Transfer Objects
 
 pub mod user_dto;
 pub mod task_dto;
 pub mod schedule_dto;
 pub mod view_dto;
+pub mod export_dto;
 
 pub use user_dto::*;
 pub use task_dto::*;
 pub use schedule_dto::*;
 pub use view_dto::*;
+pub use export_dto::*;
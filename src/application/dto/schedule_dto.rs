@@ -1,4 +1,4 @@
-/// Schedule-related DTOs
+//! Schedule-related DTOs
 
 use crate::domain::entities::schedule::{AvailabilityKind, CapabilitySet, LocationConstraint};
 use crate::application::types::{ScheduleTemplateId, RecurringRuleId};
@@ -1,5 +1,6 @@
-/// Task-related DTOs
+//! Task-related DTOs
 
+use chrono::NaiveTime;
 use crate::domain::entities::task::{Periodicity, TaskPriority};
 use crate::domain::entities::user::Location;
 use crate::domain::entities::schedule::{AvailabilityLevel, DeviceAccess, Mobility};
@@ -20,6 +21,7 @@ pub struct CreateTaskInput {
     pub min_device: Option<DeviceAccess>,
     pub allowed_mobility: Option<Mobility>,
     pub locations: Vec<Option<Location>>,
+    pub preferred_time_window: Option<(NaiveTime, NaiveTime)>,
 }
 
 /// Input for updating an existing task
@@ -38,6 +40,7 @@ pub struct UpdateTaskInput {
     pub min_device: Option<DeviceAccess>,
     pub allowed_mobility: Option<Mobility>,
     pub locations: Option<Vec<Option<Location>>>,
+    pub preferred_time_window: Option<Option<(NaiveTime, NaiveTime)>>, // Some(None) clears the preference
 }
 
 /// Input for completing an occurrence rep
@@ -1,6 +1,6 @@
-/// User-related DTOs
+//! User-related DTOs
 
-use crate::domain::entities::user::Timezone;
+use crate::domain::entities::user::{Location, Timezone};
 use chrono::{Month, NaiveTime, Weekday};
 
 /// Input for registering a new user
@@ -19,6 +19,7 @@ pub struct UpdateUserSettingsInput {
     pub year_start: Option<Month>,
     pub day_start: Option<NaiveTime>,
     pub timezone: Option<Timezone>,
+    pub home_location: Option<Location>,
 }
 
 /// Output after successful registration
@@ -1,8 +1,11 @@
-/// View/Query DTOs
+//! View/Query DTOs
+
+use std::collections::HashMap;
 
 use crate::application::types::TaskId;
 use chrono::{DateTime, FixedOffset};
 use crate::domain::entities::schedule::TimeBlock;
+use crate::domain::entities::task::TaskOccurrence;
 
 /// Input for getting a day overview
 #[derive(Debug, Clone)]
@@ -27,11 +30,76 @@ pub struct SuggestedSlot {
     pub reason: String,
 }
 
+/// Why a task due on the day fits none of its time blocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnscheduledReason {
+    /// Every block of the day is unavailable
+    Unavailable,
+    /// No usable block offers the capabilities the task needs
+    CapabilityMismatch,
+    /// No blocks at all, or usable blocks rejected for another reason
+    /// (too short, location, timing window, busy-but-flexible limits)
+    NoMatchingBlock,
+}
+
+/// A task due on the day that could not be matched to any block
+#[derive(Debug, Clone)]
+pub struct UnscheduledTask {
+    pub task_id: TaskId,
+    pub title: String,
+    pub reason: UnscheduledReason,
+}
+
 /// Output for day overview query
 #[derive(Debug, Clone)]
 pub struct DayOverview {
     pub date: DateTime<FixedOffset>,
     pub time_blocks: Vec<TimeBlock>,
+    /// Tasks that fit each of `time_blocks` (same index), in suggestion order
+    pub block_candidates: Vec<Vec<TaskId>>,
     pub scheduled_tasks: Vec<ScheduledTask>,
     pub suggestions: Vec<(TaskId, Vec<SuggestedSlot>)>, // Task ID -> suggested slots
+    pub unscheduled: Vec<UnscheduledTask>,
+}
+
+/// Input for auto-scheduling a day
+#[derive(Debug, Clone)]
+pub struct AutoScheduleDayInput {
+    pub date: DateTime<FixedOffset>,
+    /// Minutes kept free between consecutive tasks (0 = back to back)
+    pub min_buffer_minutes: u32,
+    /// Extra buffer per kilometre between two location-bound tasks, if any
+    pub travel_minutes_per_km: Option<f64>,
+    /// Tasks that must be done before a task can be planned, by task
+    pub dependencies: HashMap<TaskId, Vec<TaskId>>,
+    /// Known occurrences, used to tell whether a dependency is done
+    pub occurrences: Vec<(TaskId, TaskOccurrence)>,
+    /// Prefer blocks already holding a task with a shared tag
+    pub cluster_by_tag: bool,
+}
+
+/// A task given a concrete time slot by the auto-scheduler
+#[derive(Debug, Clone)]
+pub struct PlannedTask {
+    pub task_id: TaskId,
+    pub title: String,
+    pub start: DateTime<FixedOffset>,
+    pub end: DateTime<FixedOffset>,
+}
+
+/// A task the auto-scheduler did not place
+#[derive(Debug, Clone)]
+pub struct UnplacedTask {
+    pub task_id: TaskId,
+    pub title: String,
+    /// Dependencies not yet done; empty when no free slot was found
+    pub blocked_by: Vec<TaskId>,
+}
+
+/// Output for auto-scheduling a day
+#[derive(Debug, Clone)]
+pub struct DayPlan {
+    pub date: DateTime<FixedOffset>,
+    pub assignments: Vec<PlannedTask>, // Chronological, never overlapping
+    pub unplaced: Vec<UnplacedTask>,
 }
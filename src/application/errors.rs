@@ -1,4 +1,4 @@
-/// Application layer errors
+//! Application layer errors
 
 use std::fmt;
 
@@ -1,4 +1,4 @@
-/// Application layer
+//! Application layer
 
 pub mod dto;
 pub mod errors;
@@ -1,4 +1,4 @@
-/// Repository port traits
+//! Repository port traits
 
 pub mod user_repository;
 pub mod task_repository;
@@ -1,4 +1,4 @@
-/// Schedule repository port
+//! Schedule repository port
 
 use crate::application::errors::AppResult;
 use crate::application::types::{ScheduleTemplateId, RecurringRuleId, UserId};
@@ -16,6 +16,7 @@ pub trait ScheduleRepository {
     fn update_template(&mut self, user_id: UserId, template_id: ScheduleTemplateId, template: ScheduleTemplate) -> AppResult<()>;
     
     /// Delete a schedule template
+    /// Does not touch the user's active reference; use `DeleteScheduleTemplate` for that
     fn delete_template(&mut self, user_id: UserId, template_id: ScheduleTemplateId) -> AppResult<()>;
     
     /// List all schedule templates for a user
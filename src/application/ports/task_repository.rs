@@ -1,8 +1,8 @@
-/// Task repository port
+//! Task repository port
 
 use crate::application::errors::AppResult;
 use crate::application::types::{TaskId, UserId};
-use crate::domain::entities::task::Task;
+use crate::domain::entities::task::{Task, TaskPriority, TaskStatus};
 use chrono::{DateTime, Utc};
 
 /// Trait for task persistence operations
@@ -25,6 +25,16 @@ pub trait TaskRepository {
     /// List active tasks for a user
     fn list_active_by_user(&self, user_id: UserId) -> AppResult<Vec<(TaskId, Task)>>;
     
+    /// List a user's tasks carrying a tag, compared in normalized form
+    /// (see `Task::normalize_tag`)
+    fn find_by_tag(&self, user_id: UserId, tag: &str) -> AppResult<Vec<(TaskId, Task)>>;
+    
+    /// List a user's tasks in a given status
+    fn find_by_status(&self, user_id: UserId, status: TaskStatus) -> AppResult<Vec<(TaskId, Task)>>;
+    
+    /// List a user's tasks with a given (stored, not effective) priority
+    fn find_by_priority(&self, user_id: UserId, priority: TaskPriority) -> AppResult<Vec<(TaskId, Task)>>;
+    
     /// Find tasks that should occur on a specific date
     fn find_tasks_for_date(&self, user_id: UserId, date: DateTime<Utc>) -> AppResult<Vec<(TaskId, Task)>>;
 }
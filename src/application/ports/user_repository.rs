@@ -1,4 +1,4 @@
-/// User repository port
+//! User repository port
 
 use crate::application::errors::AppResult;
 use crate::application::types::UserId;
@@ -1,4 +1,4 @@
-/// Type-safe identifier types for the application layer
+//! Type-safe identifier types for the application layer
 
 use std::fmt;
 
@@ -0,0 +1,635 @@
+//! AutoScheduleDay use case
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use crate::application::dto::{AutoScheduleDayInput, DayPlan, PlannedTask, UnplacedTask};
+use crate::application::errors::{AppError, AppResult};
+use crate::application::ports::{UserRepository, TaskRepository, ScheduleRepository};
+use crate::application::types::{TaskId, UserId};
+use crate::config;
+use crate::domain::entities::schedule::{expand_template, find_candidate_slots_stepped, score_block_for_task, SchedulableTask, TimeBlock};
+use crate::domain::entities::user::Location;
+use chrono::{DateTime, Duration, FixedOffset, NaiveTime};
+
+/// Use case for greedily placing the day's tasks into its free time
+///
+/// Tasks are placed one by one, highest effective priority first (as of the
+/// start of the day, so nearing soft deadlines count), then longest first
+/// (ties broken by task ID). Each task takes the earliest candidate slot
+/// from `find_candidate_slots_stepped` that overlaps no task placed before it,
+/// so a placement is never revisited. A task's preferred time window is a soft
+/// preference: the earliest free slot overlapping it wins over earlier ones.
+///
+/// With `cluster_by_tag`, a task without a preferred window takes the free
+/// slot whose block ranks best in `score_block_for_task`, counting the tags
+/// of the tasks already placed there, so same-tag tasks land side by side.
+/// It is still a preference: a task goes elsewhere when that block is full.
+///
+/// Placed tasks are kept `min_buffer_minutes` apart. With
+/// `travel_minutes_per_km`, two location-bound tasks are kept further apart
+/// by the travel time between their (first known) locations.
+///
+/// A task with `dependencies` is only planned once each of them has a
+/// completed occurrence overlapping the day; otherwise it is reported as
+/// unplaced with the dependencies still blocking it. Cyclic dependencies are
+/// rejected as a validation error.
+pub struct AutoScheduleDay<'a> {
+    user_repo: &'a dyn UserRepository,
+    task_repo: &'a dyn TaskRepository,
+    schedule_repo: &'a dyn ScheduleRepository,
+}
+
+impl<'a> AutoScheduleDay<'a> {
+    pub fn new(
+        user_repo: &'a dyn UserRepository,
+        task_repo: &'a dyn TaskRepository,
+        schedule_repo: &'a dyn ScheduleRepository,
+    ) -> Self {
+        Self {
+            user_repo,
+            task_repo,
+            schedule_repo,
+        }
+    }
+
+    pub fn execute(&self, user_id: UserId, input: AutoScheduleDayInput) -> AppResult<DayPlan> {
+        if let Some(cycle) = dependency_cycle(&input.dependencies) {
+            let path: Vec<String> = cycle.iter().map(ToString::to_string).collect();
+            return Err(AppError::ValidationError(format!("Task dependencies form a cycle: {}", path.join(" -> "))));
+        }
+
+        let user = self.user_repo.find_by_id(user_id)?;
+
+        let active_template_id = self.user_repo.get_active_schedule_template(user_id)?
+            .ok_or_else(|| AppError::ValidationError("User has no active schedule template".to_string()))?;
+        let template = self.schedule_repo.find_template(user_id, active_template_id)?;
+
+        let time_blocks = expand_template(&template, input.date, input.date + Duration::days(1));
+
+        let day_start_utc = input.date.with_timezone(&chrono::Utc);
+        let mut tasks = self.task_repo.find_tasks_for_date(user_id, day_start_utc)?;
+        tasks.sort_by_key(|(task_id, task)| {
+            (Reverse(task.effective_priority(day_start_utc)), Reverse(task.estimated_duration_minutes()), task_id.value())
+        });
+
+        // Same location as the day overview: the first known one
+        let user_location = user.locations.iter()
+            .find(|loc| loc.is_some())
+            .and_then(|loc| loc.clone());
+
+        let step_minutes = config::schedule_slot_step_minutes() as i64;
+        let mut placed: Vec<(PlannedTask, Option<Location>, Vec<String>)> = Vec::new();
+        let mut unplaced = Vec::new();
+
+        let (day_start, day_end) = (input.date, input.date + Duration::days(1));
+        let done: HashSet<TaskId> = input.occurrences.iter()
+            .filter(|(_, occurrence)| {
+                occurrence.is_completed() && occurrence.window_start() < day_end && occurrence.window_end() >= day_start
+            })
+            .map(|(task_id, _)| *task_id)
+            .collect();
+
+        for (task_id, task) in tasks {
+            let blocked_by: Vec<TaskId> = input.dependencies.get(&task_id)
+                .into_iter()
+                .flatten()
+                .filter(|dependency| !done.contains(dependency))
+                .copied()
+                .collect();
+            if !blocked_by.is_empty() {
+                unplaced.push(UnplacedTask {
+                    task_id,
+                    title: task.title().to_string(),
+                    blocked_by,
+                });
+                continue;
+            }
+
+            let candidates = find_candidate_slots_stepped(
+                &time_blocks,
+                &task,
+                user_location.as_ref(),
+                task.periodicity().occurrence_settings.as_ref(),
+                step_minutes,
+            );
+            let site = task.locations().iter().flatten().next().cloned();
+            let free: Vec<_> = candidates.into_iter()
+                .filter(|(start, end)| {
+                    placed.iter().all(|(other, other_site, _)| {
+                        let buffer = buffer_between(&input, site.as_ref(), other_site.as_ref());
+                        *end + buffer <= other.start || *start >= other.end + buffer
+                    })
+                })
+                .collect();
+            let preferred = task.preferred_time_window().and_then(|window| {
+                free.iter().find(|(start, end)| overlaps_time_window(*start, *end, window))
+            });
+            let slot = preferred
+                .or_else(|| match input.cluster_by_tag {
+                    true => clustered_slot(&task, &free, &time_blocks, &placed, user_location.as_ref()),
+                    false => free.first(),
+                })
+                .copied();
+
+            match slot {
+                Some((start, end)) => placed.push((
+                    PlannedTask {
+                        task_id,
+                        title: task.title().to_string(),
+                        start,
+                        end,
+                    },
+                    site,
+                    task.tags().to_vec(),
+                )),
+                None => unplaced.push(UnplacedTask {
+                    task_id,
+                    title: task.title().to_string(),
+                    blocked_by: Vec::new(),
+                }),
+            }
+        }
+
+        let mut assignments: Vec<PlannedTask> = placed.into_iter().map(|(planned, _, _)| planned).collect();
+        assignments.sort_by_key(|planned| planned.start);
+
+        Ok(DayPlan {
+            date: input.date,
+            assignments,
+            unplaced,
+        })
+    }
+}
+
+/// First dependency cycle found, as a path ending on the task it starts from
+fn dependency_cycle(dependencies: &HashMap<TaskId, Vec<TaskId>>) -> Option<Vec<TaskId>> {
+    fn visit(
+        task_id: TaskId,
+        dependencies: &HashMap<TaskId, Vec<TaskId>>,
+        cleared: &mut HashSet<TaskId>,
+        path: &mut Vec<TaskId>,
+    ) -> Option<Vec<TaskId>> {
+        if let Some(position) = path.iter().position(|on_path| *on_path == task_id) {
+            let mut cycle = path[position..].to_vec();
+            cycle.push(task_id);
+            return Some(cycle);
+        }
+        if cleared.contains(&task_id) {
+            return None;
+        }
+
+        path.push(task_id);
+        for dependency in dependencies.get(&task_id).into_iter().flatten() {
+            if let Some(cycle) = visit(*dependency, dependencies, cleared, path) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        cleared.insert(task_id);
+        None
+    }
+
+    let mut roots: Vec<TaskId> = dependencies.keys().copied().collect();
+    roots.sort_by_key(|task_id| task_id.value());
+    let mut cleared = HashSet::new();
+    roots.into_iter().find_map(|root| visit(root, dependencies, &mut cleared, &mut Vec::new()))
+}
+
+/// The free slot whose block scores best for the task, given the tags of the
+/// tasks already placed in that block; earliest first on ties
+fn clustered_slot<'s>(
+    task: &impl SchedulableTask,
+    free: &'s [(DateTime<FixedOffset>, DateTime<FixedOffset>)],
+    blocks: &[TimeBlock],
+    placed: &[(PlannedTask, Option<Location>, Vec<String>)],
+    current_location: Option<&Location>,
+) -> Option<&'s (DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    free.iter()
+        .filter_map(|slot| {
+            let block = blocks.iter().find(|block| block.start <= slot.0 && slot.1 <= block.end)?;
+            let block_tags: Vec<String> = placed.iter()
+                .filter(|(other, _, _)| other.start < block.end && other.end > block.start)
+                .flat_map(|(_, _, tags)| tags.iter().cloned())
+                .collect();
+            score_block_for_task(task, block, current_location, &block_tags).map(|score| (score, slot))
+        })
+        .max_by_key(|(score, slot)| (*score, Reverse(slot.0)))
+        .map(|(_, slot)| slot)
+}
+
+/// Free time to keep between two tasks at the given locations
+fn buffer_between(input: &AutoScheduleDayInput, a: Option<&Location>, b: Option<&Location>) -> Duration {
+    let travel_minutes = match (input.travel_minutes_per_km, a, b) {
+        (Some(per_km), Some(a), Some(b)) => (a.geoloc().distance_to(b.geoloc()) / 1000.0 * per_km).ceil() as i64,
+        _ => 0,
+    };
+    Duration::minutes(input.min_buffer_minutes as i64 + travel_minutes)
+}
+
+/// Whether a slot overlaps a time-of-day window on the slot's local day
+fn overlaps_time_window(
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+    (window_start, window_end): (NaiveTime, NaiveTime),
+) -> bool {
+    let day = start.date_naive();
+    start.naive_local() < day.and_time(window_end)
+        && end.with_timezone(start.offset()).naive_local() > day.and_time(window_start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::TaskRepository;
+    use crate::domain::entities::schedule::{
+        AvailabilityKind, CapabilitySet, LocationConstraint, Mobility, RecurringRule, ScheduleTemplate,
+    };
+    use crate::domain::entities::task::{
+        OccurrenceTimingSettings, PeriodicityBuilder, Task, TaskBuilder, TaskOccurrence, TaskPriority,
+    };
+    use crate::domain::entities::user::{GeoCoordinates, Timezone, User};
+    use crate::infrastructure::{InMemoryScheduleRepository, InMemoryTaskRepository, InMemoryUserRepository};
+    use chrono::{TimeZone, Utc, Weekday};
+
+    fn task(title: &str, minutes: u16, priority: TaskPriority) -> TaskBuilder {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .with_occurrence_settings(OccurrenceTimingSettings {
+                duration: Some(minutes),
+                not_before: None,
+                best_before: None,
+                rep_timing_settings: None,
+            })
+            .build()
+            .unwrap();
+        TaskBuilder::new(title, periodicity).priority(priority)
+    }
+
+    /// Input with no buffer, dependencies or known occurrences
+    fn input(date: DateTime<FixedOffset>) -> AutoScheduleDayInput {
+        AutoScheduleDayInput {
+            date,
+            min_buffer_minutes: 0,
+            travel_minutes_per_km: None,
+            dependencies: HashMap::new(),
+            occurrences: Vec::new(),
+            cluster_by_tag: false,
+        }
+    }
+
+    #[test]
+    fn test_three_tasks_fill_afternoon_and_fourth_is_unplaced() {
+        let mut user_repo = InMemoryUserRepository::new();
+        let mut task_repo = InMemoryTaskRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+
+        let user_id = user_repo.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let afternoon = RecurringRule::new(
+            vec![Weekday::Tue],
+            NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Afternoon".to_string()),
+            0,
+        ).unwrap();
+        let template = ScheduleTemplate::new("Half day".to_string(), "Europe/Paris".to_string(), vec![afternoon]).unwrap();
+        let template_id = schedule_repo.save_template(user_id, template).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        let save = |repo: &mut InMemoryTaskRepository, builder: TaskBuilder| {
+            let task: Task = builder.build().unwrap();
+            repo.save(user_id, task).unwrap()
+        };
+        let stretch = save(&mut task_repo, task("Stretch", 30, TaskPriority::Low));
+        let emails = save(&mut task_repo, task("Emails", 60, TaskPriority::High));
+        let report = save(&mut task_repo, task("Report", 120, TaskPriority::Urgent));
+        let review = save(&mut task_repo, task("Review", 60, TaskPriority::High));
+        // Only fits a block spent driving, which the afternoon is not
+        let podcast = save(
+            &mut task_repo,
+            task("Podcast", 15, TaskPriority::Urgent).allowed_mobility(vec![Mobility::Driving]),
+        );
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let plan = AutoScheduleDay::new(&user_repo, &task_repo, &schedule_repo)
+            .execute(user_id, input(paris.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap()))
+            .unwrap();
+
+        let at = |hour| paris.with_ymd_and_hms(2026, 2, 10, hour, 0, 0).unwrap();
+        let placed: Vec<_> = plan.assignments.iter().map(|p| (p.task_id, p.start, p.end)).collect();
+        assert_eq!(placed, vec![
+            (report, at(13), at(15)),
+            (emails, at(15), at(16)),
+            (review, at(16), at(17)),
+        ]);
+
+        let mut unplaced: Vec<_> = plan.unplaced.iter().map(|t| t.task_id).collect();
+        unplaced.sort_by_key(|id| id.value());
+        assert_eq!(unplaced, vec![stretch, podcast]);
+    }
+
+    #[test]
+    fn test_preferred_time_window_beats_earlier_slot() {
+        let mut user_repo = InMemoryUserRepository::new();
+        let mut task_repo = InMemoryTaskRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+
+        let user_id = user_repo.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let slot = |start, end, label: &str| RecurringRule::new(
+            vec![Weekday::Tue],
+            time(start),
+            time(end),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some(label.to_string()),
+            0,
+        ).unwrap();
+        let template = ScheduleTemplate::new(
+            "Two slots".to_string(),
+            "Europe/Paris".to_string(),
+            vec![slot(9, 10, "Morning"), slot(15, 16, "Afternoon")],
+        ).unwrap();
+        let template_id = schedule_repo.save_template(user_id, template).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        // The afternoon task is planned first and would take 9-10 if it were earliest-first
+        let afternoon = task_repo.save(
+            user_id,
+            task("Gardening", 60, TaskPriority::Medium).preferred_time_window(time(14), time(18)).build().unwrap(),
+        ).unwrap();
+        let morning = task_repo.save(
+            user_id,
+            task("Deep work", 60, TaskPriority::Medium).preferred_time_window(time(8), time(12)).build().unwrap(),
+        ).unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let plan = AutoScheduleDay::new(&user_repo, &task_repo, &schedule_repo)
+            .execute(user_id, input(paris.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap()))
+            .unwrap();
+
+        let at = |hour| paris.with_ymd_and_hms(2026, 2, 10, hour, 0, 0).unwrap();
+        let placed: Vec<_> = plan.assignments.iter().map(|p| (p.task_id, p.start, p.end)).collect();
+        assert_eq!(placed, vec![(morning, at(9), at(10)), (afternoon, at(15), at(16))]);
+        assert!(plan.unplaced.is_empty());
+    }
+
+    #[test]
+    fn test_cluster_by_tag_keeps_same_tag_tasks_together() {
+        let mut user_repo = InMemoryUserRepository::new();
+        let mut task_repo = InMemoryTaskRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+
+        let user_id = user_repo.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let slot = |start, end, label: &str| RecurringRule::new(
+            vec![Weekday::Tue],
+            time(start),
+            time(end),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some(label.to_string()),
+            0,
+        ).unwrap();
+        let template = ScheduleTemplate::new(
+            "Two equal slots".to_string(),
+            "Europe/Paris".to_string(),
+            vec![slot(9, 10, "Morning"), slot(14, 15, "Afternoon")],
+        ).unwrap();
+        let template_id = schedule_repo.save_template(user_id, template).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        let errands = vec!["errands".to_string()];
+        // Goes first and settles in the afternoon
+        let groceries = task_repo.save(
+            user_id,
+            task("Groceries", 30, TaskPriority::High)
+                .tags(errands.clone())
+                .preferred_time_window(time(13), time(18))
+                .build()
+                .unwrap(),
+        ).unwrap();
+        let pharmacy = task_repo.save(
+            user_id,
+            task("Pharmacy", 30, TaskPriority::Medium).tags(errands).build().unwrap(),
+        ).unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let at = |hour, minute| paris.with_ymd_and_hms(2026, 2, 10, hour, minute, 0).unwrap();
+        let plan = |cluster_by_tag| {
+            let plan = AutoScheduleDay::new(&user_repo, &task_repo, &schedule_repo)
+                .execute(user_id, AutoScheduleDayInput { cluster_by_tag, ..input(at(0, 0)) })
+                .unwrap();
+            plan.assignments.iter().map(|p| (p.task_id, p.start)).collect::<Vec<_>>()
+        };
+
+        // Earliest first scatters the errands over both blocks
+        assert_eq!(plan(false), vec![(pharmacy, at(9, 0)), (groceries, at(14, 0))]);
+        // Clustering puts the pharmacy right after the groceries
+        assert_eq!(plan(true), vec![(groceries, at(14, 0)), (pharmacy, at(14, 30))]);
+    }
+
+    /// A user in Paris whose active template is free on Tuesday 2026-02-10
+    /// from 13:00 for `minutes`
+    fn user_free_for(minutes: u32) -> (InMemoryUserRepository, InMemoryScheduleRepository, UserId) {
+        let mut user_repo = InMemoryUserRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+
+        let mut user = User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        );
+        // Location-bound tasks need a known location
+        user.set_locations(vec![Some(Location::new(
+            Some("Home".to_string()),
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(48.86, 2.35).unwrap(),
+        ).unwrap())]);
+        let user_id = user_repo.save(user).unwrap();
+
+        let start = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+        let window = RecurringRule::new(
+            vec![Weekday::Tue],
+            start,
+            start + Duration::minutes(minutes as i64),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Window".to_string()),
+            0,
+        ).unwrap();
+        let template = ScheduleTemplate::new("Window".to_string(), "Europe/Paris".to_string(), vec![window]).unwrap();
+        let template_id = schedule_repo.save_template(user_id, template).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        (user_repo, schedule_repo, user_id)
+    }
+
+    #[test]
+    fn test_overdue_task_goes_before_higher_priority() {
+        let (user_repo, schedule_repo, user_id) = user_free_for(60);
+        let mut task_repo = InMemoryTaskRepository::new();
+        let emails = task_repo.save(user_id, task("Emails", 60, TaskPriority::High).build().unwrap()).unwrap();
+        let taxes = task_repo.save(
+            user_id,
+            task("Taxes", 60, TaskPriority::Medium)
+                .soft_deadline(Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap())
+                .build()
+                .unwrap(),
+        ).unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let plan = AutoScheduleDay::new(&user_repo, &task_repo, &schedule_repo)
+            .execute(user_id, input(paris.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap()))
+            .unwrap();
+
+        let placed: Vec<_> = plan.assignments.iter().map(|p| p.task_id).collect();
+        assert_eq!(placed, vec![taxes]);
+        assert_eq!(plan.unplaced.iter().map(|t| t.task_id).collect::<Vec<_>>(), vec![emails]);
+    }
+
+    #[test]
+    fn test_buffer_keeps_tasks_apart() {
+        let (user_repo, schedule_repo, user_id) = user_free_for(70);
+        let mut task_repo = InMemoryTaskRepository::new();
+        let first = task_repo.save(user_id, task("Call", 30, TaskPriority::High).build().unwrap()).unwrap();
+        let second = task_repo.save(user_id, task("Emails", 30, TaskPriority::Medium).build().unwrap()).unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let at = |hour, minute| paris.with_ymd_and_hms(2026, 2, 10, hour, minute, 0).unwrap();
+        let plan = |min_buffer_minutes| AutoScheduleDay::new(&user_repo, &task_repo, &schedule_repo)
+            .execute(user_id, AutoScheduleDayInput { min_buffer_minutes, ..input(at(0, 0)) })
+            .unwrap();
+
+        // Back to back, both fit
+        let placed: Vec<_> = plan(0).assignments.iter().map(|p| (p.task_id, p.start)).collect();
+        assert_eq!(placed, vec![(first, at(13, 0)), (second, at(13, 30))]);
+
+        // 30 + 15 + 30 minutes is more than the 70-minute window
+        let buffered = plan(15);
+        let placed: Vec<_> = buffered.assignments.iter().map(|p| (p.task_id, p.start)).collect();
+        assert_eq!(placed, vec![(first, at(13, 0))]);
+        assert_eq!(buffered.unplaced.iter().map(|t| t.task_id).collect::<Vec<_>>(), vec![second]);
+    }
+
+    #[test]
+    fn test_travel_time_scales_buffer_between_locations() {
+        let (user_repo, schedule_repo, user_id) = user_free_for(180);
+        let mut task_repo = InMemoryTaskRepository::new();
+
+        let place = |name: &str, longitude| Location::new(
+            Some(name.to_string()),
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(48.85, longitude).unwrap(),
+        ).unwrap();
+        // About 7.3 km apart
+        let (office, gym) = (place("Office", 2.30), place("Gym", 2.40));
+        let meeting = task_repo.save(
+            user_id,
+            task("Meeting", 60, TaskPriority::High).locations(vec![Some(office)]).build().unwrap(),
+        ).unwrap();
+        let workout = task_repo.save(
+            user_id,
+            task("Workout", 60, TaskPriority::Medium).locations(vec![Some(gym)]).build().unwrap(),
+        ).unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let at = |hour, minute| paris.with_ymd_and_hms(2026, 2, 10, hour, minute, 0).unwrap();
+        let plan = AutoScheduleDay::new(&user_repo, &task_repo, &schedule_repo)
+            .execute(user_id, AutoScheduleDayInput {
+                min_buffer_minutes: 5,
+                travel_minutes_per_km: Some(3.0),
+                ..input(at(0, 0))
+            })
+            .unwrap();
+
+        // 5 + ceil(7.3 * 3) = 27 minutes apart, so the next 15-minute step is 14:30
+        let placed: Vec<_> = plan.assignments.iter().map(|p| (p.task_id, p.start)).collect();
+        assert_eq!(placed, vec![(meeting, at(13, 0)), (workout, at(14, 30))]);
+    }
+
+    #[test]
+    fn test_dependent_task_waits_for_completed_occurrence() {
+        let (user_repo, schedule_repo, user_id) = user_free_for(120);
+        let mut task_repo = InMemoryTaskRepository::new();
+        let draft = task_repo.save(user_id, task("Draft", 30, TaskPriority::Medium).build().unwrap()).unwrap();
+        let review = task_repo.save(user_id, task("Review", 30, TaskPriority::Medium).build().unwrap()).unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let date = paris.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap();
+        let mut draft_today = TaskOccurrence::new(
+            date.with_timezone(&Utc),
+            (date + Duration::days(1)).with_timezone(&Utc) - Duration::seconds(1),
+            1,
+        ).unwrap();
+        let plan = |draft_today: &TaskOccurrence| AutoScheduleDay::new(&user_repo, &task_repo, &schedule_repo)
+            .execute(user_id, AutoScheduleDayInput {
+                dependencies: HashMap::from([(review, vec![draft])]),
+                occurrences: vec![(draft, draft_today.clone())],
+                ..input(date)
+            })
+            .unwrap();
+
+        // Draft not done yet: Review waits
+        let blocked = plan(&draft_today);
+        assert_eq!(blocked.assignments.iter().map(|p| p.task_id).collect::<Vec<_>>(), vec![draft]);
+        assert_eq!(blocked.unplaced.len(), 1);
+        assert_eq!(blocked.unplaced[0].task_id, review);
+        assert_eq!(blocked.unplaced[0].blocked_by, vec![draft]);
+
+        // Once the draft is done, the review is planned too
+        draft_today.mark_all_complete();
+        let unblocked = plan(&draft_today);
+        assert_eq!(unblocked.assignments.len(), 2);
+        assert!(unblocked.unplaced.is_empty());
+    }
+
+    #[test]
+    fn test_dependency_cycle_is_rejected() {
+        let (user_repo, schedule_repo, user_id) = user_free_for(120);
+        let task_repo = InMemoryTaskRepository::new();
+        let (a, b, c) = (TaskId::new(1), TaskId::new(2), TaskId::new(3));
+        let date = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap();
+
+        let result = AutoScheduleDay::new(&user_repo, &task_repo, &schedule_repo)
+            .execute(user_id, AutoScheduleDayInput {
+                dependencies: HashMap::from([(a, vec![b]), (b, vec![a]), (c, vec![a])]),
+                ..input(date)
+            });
+        match result {
+            Err(AppError::ValidationError(message)) => assert!(message.contains("Task(1) -> Task(2) -> Task(1)"), "{}", message),
+            other => panic!("expected a cycle error, got {:?}", other),
+        }
+
+        // A chain without a cycle is fine
+        assert_eq!(dependency_cycle(&HashMap::from([(c, vec![b]), (b, vec![a])])), None);
+        assert_eq!(dependency_cycle(&HashMap::from([(a, vec![a])])), Some(vec![a, a]));
+    }
+}
@@ -1,4 +1,4 @@
-/// CompleteOccurrenceRep use case
+//! CompleteOccurrenceRep use case
 
 use crate::application::dto::CompleteOccurrenceRepInput;
 use crate::application::errors::{AppError, AppResult};
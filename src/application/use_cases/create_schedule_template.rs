@@ -1,10 +1,11 @@
-/// CreateScheduleTemplate use case
+//! CreateScheduleTemplate use case
 
 use crate::application::dto::{CreateScheduleTemplateInput, CreateScheduleTemplateOutput};
-use crate::application::errors::AppResult;
+use crate::application::errors::{AppError, AppResult};
 use crate::application::ports::ScheduleRepository;
 use crate::application::types::UserId;
 use crate::domain::entities::schedule::ScheduleTemplate;
+use crate::domain::entities::user::Timezone;
 
 /// Use case for creating a new schedule template
 pub struct CreateScheduleTemplate<'a> {
@@ -17,10 +18,13 @@ impl<'a> CreateScheduleTemplate<'a> {
     }
 
     pub fn execute(&mut self, user_id: UserId, input: CreateScheduleTemplateInput) -> AppResult<CreateScheduleTemplateOutput> {
+        // Use description as timezone for now, or default to UTC
+        let timezone = Self::resolve_timezone(input.description.unwrap_or_else(|| "Etc/UTC".to_string()))?;
+
         // Create the domain entity (no persistence IDs at domain level)
         let template = ScheduleTemplate::new(
             input.name.clone(),
-            input.description.unwrap_or_else(|| "UTC".to_string()), // Use description as timezone for now, or default to UTC
+            timezone.into_string(),
             Vec::new(), // Start with no rules
         )?;
 
@@ -32,4 +36,64 @@ impl<'a> CreateScheduleTemplate<'a> {
             name: input.name,
         })
     }
+
+    /// Check the timezone format and that it resolves to a known IANA zone,
+    /// so a bad identifier is rejected here rather than breaking expansion later
+    pub(crate) fn resolve_timezone(identifier: String) -> AppResult<Timezone> {
+        Timezone::new_validated(identifier)
+            .map_err(|e| AppError::ValidationError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::InMemoryScheduleRepository;
+
+    fn input(timezone: Option<&str>) -> CreateScheduleTemplateInput {
+        CreateScheduleTemplateInput {
+            name: "Work week".to_string(),
+            description: timezone.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_create_template_with_valid_timezone() {
+        let mut repo = InMemoryScheduleRepository::new();
+        let output = CreateScheduleTemplate::new(&mut repo)
+            .execute(UserId::new(1), input(Some("Europe/Paris")))
+            .unwrap();
+
+        let template = repo.find_template(UserId::new(1), output.template_id).unwrap();
+        assert_eq!(template.timezone, "Europe/Paris");
+    }
+
+    #[test]
+    fn test_create_template_defaults_to_utc() {
+        let mut repo = InMemoryScheduleRepository::new();
+        let output = CreateScheduleTemplate::new(&mut repo)
+            .execute(UserId::new(1), input(None))
+            .unwrap();
+
+        let template = repo.find_template(UserId::new(1), output.template_id).unwrap();
+        assert_eq!(template.timezone, "Etc/UTC");
+    }
+
+    #[test]
+    fn test_create_template_rejects_unknown_timezone() {
+        let mut repo = InMemoryScheduleRepository::new();
+        let result = CreateScheduleTemplate::new(&mut repo).execute(UserId::new(1), input(Some("Foo/Bar")));
+
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+        assert!(repo.list_templates_by_user(UserId::new(1)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_create_template_rejects_malformed_timezone() {
+        let mut repo = InMemoryScheduleRepository::new();
+        let result = CreateScheduleTemplate::new(&mut repo).execute(UserId::new(1), input(Some("Paris")));
+
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+        assert!(repo.list_templates_by_user(UserId::new(1)).unwrap().is_empty());
+    }
 }
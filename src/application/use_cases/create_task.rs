@@ -1,4 +1,4 @@
-/// CreateTask use case
+//! CreateTask use case
 
 use crate::application::dto::{CreateTaskInput, CreateTaskOutput};
 use crate::application::errors::AppResult;
@@ -56,6 +56,10 @@ impl<'a> CreateTask<'a> {
         if !input.locations.is_empty() {
             task.set_locations(input.locations);
         }
+        if input.preferred_time_window.is_some() {
+            task.set_preferred_time_window(input.preferred_time_window)
+                .map_err(|e| crate::application::errors::AppError::ValidationError(e.to_string()))?;
+        }
 
         // Save the task
         let task_id = self.task_repo.save(user_id, task)?;
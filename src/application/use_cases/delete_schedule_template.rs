@@ -0,0 +1,110 @@
+//! DeleteScheduleTemplate use case
+
+use crate::application::errors::AppResult;
+use crate::application::ports::{ScheduleRepository, UserRepository};
+use crate::application::types::{UserId, ScheduleTemplateId};
+
+/// Use case for deleting a schedule template
+///
+/// Deleting the user's active template also clears their active reference.
+/// No other template is promoted in its place: picking the schedule to follow
+/// is left to the user, via `SetActiveScheduleTemplate`.
+pub struct DeleteScheduleTemplate<'a> {
+    user_repo: &'a mut dyn UserRepository,
+    schedule_repo: &'a mut dyn ScheduleRepository,
+}
+
+impl<'a> DeleteScheduleTemplate<'a> {
+    pub fn new(
+        user_repo: &'a mut dyn UserRepository,
+        schedule_repo: &'a mut dyn ScheduleRepository,
+    ) -> Self {
+        Self {
+            user_repo,
+            schedule_repo,
+        }
+    }
+
+    pub fn execute(&mut self, user_id: UserId, template_id: ScheduleTemplateId) -> AppResult<()> {
+        // Read the active reference first so a missing user fails before anything is deleted
+        let active = self.user_repo.get_active_schedule_template(user_id)?;
+
+        self.schedule_repo.delete_template(user_id, template_id)?;
+
+        // Never leave the user pointing at a template that no longer exists
+        if active == Some(template_id) {
+            self.user_repo.set_active_schedule_template(user_id, None)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::errors::AppError;
+    use crate::domain::entities::schedule::ScheduleTemplate;
+    use crate::domain::entities::user::{Timezone, User};
+    use crate::infrastructure::{InMemoryScheduleRepository, InMemoryUserRepository};
+
+    fn setup() -> (InMemoryUserRepository, InMemoryScheduleRepository, UserId, ScheduleTemplateId, ScheduleTemplateId) {
+        let mut user_repo = InMemoryUserRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+
+        let user_id = user_repo.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let work = ScheduleTemplate::new("Work week".to_string(), "Europe/Paris".to_string(), Vec::new()).unwrap();
+        let holiday = ScheduleTemplate::new("Holidays".to_string(), "Europe/Paris".to_string(), Vec::new()).unwrap();
+        let work_id = schedule_repo.save_template(user_id, work).unwrap();
+        let holiday_id = schedule_repo.save_template(user_id, holiday).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(work_id)).unwrap();
+
+        (user_repo, schedule_repo, user_id, work_id, holiday_id)
+    }
+
+    #[test]
+    fn test_deleting_active_template_clears_active_reference() {
+        let (mut user_repo, mut schedule_repo, user_id, work_id, holiday_id) = setup();
+
+        DeleteScheduleTemplate::new(&mut user_repo, &mut schedule_repo)
+            .execute(user_id, work_id)
+            .unwrap();
+
+        assert_eq!(user_repo.get_active_schedule_template(user_id).unwrap(), None);
+        assert!(matches!(
+            schedule_repo.find_template(user_id, work_id),
+            Err(AppError::ScheduleTemplateNotFound(_))
+        ));
+        // The remaining template is not promoted
+        assert!(schedule_repo.find_template(user_id, holiday_id).is_ok());
+    }
+
+    #[test]
+    fn test_deleting_inactive_template_keeps_active_reference() {
+        let (mut user_repo, mut schedule_repo, user_id, work_id, holiday_id) = setup();
+
+        DeleteScheduleTemplate::new(&mut user_repo, &mut schedule_repo)
+            .execute(user_id, holiday_id)
+            .unwrap();
+
+        assert_eq!(user_repo.get_active_schedule_template(user_id).unwrap(), Some(work_id));
+        assert_eq!(schedule_repo.list_templates_by_user(user_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_deleting_unknown_template_fails_without_side_effects() {
+        let (mut user_repo, mut schedule_repo, user_id, work_id, _) = setup();
+
+        let result = DeleteScheduleTemplate::new(&mut user_repo, &mut schedule_repo)
+            .execute(user_id, ScheduleTemplateId::new(99));
+
+        assert!(matches!(result, Err(AppError::ScheduleTemplateNotFound(_))));
+        assert_eq!(user_repo.get_active_schedule_template(user_id).unwrap(), Some(work_id));
+    }
+}
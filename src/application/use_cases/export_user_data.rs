@@ -0,0 +1,128 @@
+//! ExportUserData use case
+
+use crate::application::dto::{ScheduleTemplateExport, TaskExport, UserDataExport};
+use crate::application::errors::AppResult;
+use crate::application::ports::{UserRepository, TaskRepository, ScheduleRepository};
+use crate::application::types::UserId;
+use crate::infrastructure::Clock;
+
+/// Use case for exporting everything stored about a user (GDPR-style data export)
+pub struct ExportUserData<'a> {
+    user_repo: &'a dyn UserRepository,
+    task_repo: &'a dyn TaskRepository,
+    schedule_repo: &'a dyn ScheduleRepository,
+    clock: &'a dyn Clock,
+}
+
+impl<'a> ExportUserData<'a> {
+    pub fn new(
+        user_repo: &'a dyn UserRepository,
+        task_repo: &'a dyn TaskRepository,
+        schedule_repo: &'a dyn ScheduleRepository,
+        clock: &'a dyn Clock,
+    ) -> Self {
+        Self {
+            user_repo,
+            task_repo,
+            schedule_repo,
+            clock,
+        }
+    }
+
+    pub fn execute(&self, user_id: UserId) -> AppResult<UserDataExport> {
+        let user = self.user_repo.find_by_id(user_id)?;
+        let active_template_id = self.user_repo.get_active_schedule_template(user_id)?;
+
+        let schedule_templates = self.schedule_repo.list_templates_by_user(user_id)?
+            .into_iter()
+            .map(|(id, template)| ScheduleTemplateExport { id: id.value(), template })
+            .collect();
+
+        // Occurrences are not persisted yet, so there is nothing to load for them
+        let tasks = self.task_repo.list_by_user(user_id)?
+            .into_iter()
+            .map(|(id, task)| TaskExport { id: id.value(), task, occurrences: Vec::new() })
+            .collect();
+
+        Ok(UserDataExport {
+            exported_at: self.clock.now(),
+            user_id: user_id.value(),
+            profile: user.into(),
+            active_schedule_template_id: active_template_id.map(|id| id.value()),
+            schedule_templates,
+            tasks,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::errors::AppError;
+    use crate::domain::entities::schedule::ScheduleTemplate;
+    use crate::domain::entities::task::{PeriodicityBuilder, Task};
+    use crate::domain::entities::user::{Timezone, User};
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::{InMemoryScheduleRepository, InMemoryTaskRepository, InMemoryUserRepository};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_export_contains_user_template_and_task() {
+        let mut user_repo = InMemoryUserRepository::new();
+        let mut task_repo = InMemoryTaskRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap());
+
+        let user_id = user_repo.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let template = ScheduleTemplate::new("Work week".to_string(), "Europe/Paris".to_string(), Vec::new()).unwrap();
+        let template_id = schedule_repo.save_template(user_id, template).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        let periodicity = PeriodicityBuilder::new().daily(1).build().unwrap();
+        let task = Task::new("Stretch".to_string(), periodicity).unwrap();
+        let task_id = task_repo.save(user_id, task).unwrap();
+
+        let export = ExportUserData::new(&user_repo, &task_repo, &schedule_repo, &clock)
+            .execute(user_id)
+            .unwrap();
+
+        assert_eq!(export.user_id, user_id.value());
+        assert_eq!(export.profile.username, "alice");
+        assert_eq!(export.active_schedule_template_id, Some(template_id.value()));
+        assert_eq!(export.schedule_templates.len(), 1);
+        assert_eq!(export.schedule_templates[0].id, template_id.value());
+        assert_eq!(export.schedule_templates[0].template.name, "Work week");
+        assert_eq!(export.tasks.len(), 1);
+        assert_eq!(export.tasks[0].id, task_id.value());
+        assert_eq!(export.tasks[0].task.title(), "Stretch");
+        assert!(export.tasks[0].occurrences.is_empty());
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&export).unwrap();
+            assert_eq!(json["profile"]["email"], "alice@example.com");
+            assert_eq!(json["schedule_templates"][0]["template"]["name"], "Work week");
+            assert_eq!(json["tasks"][0]["task"]["title"], "Stretch");
+            assert!(!json.to_string().contains("secret-hash"));
+        }
+    }
+
+    #[test]
+    fn test_export_unknown_user() {
+        let user_repo = InMemoryUserRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let schedule_repo = InMemoryScheduleRepository::new();
+        let clock = FixedClock::new(Utc::now());
+
+        let result = ExportUserData::new(&user_repo, &task_repo, &schedule_repo, &clock)
+            .execute(UserId::new(42));
+
+        assert!(matches!(result, Err(AppError::UserNotFound(_))));
+    }
+}
@@ -1,17 +1,65 @@
-/// GetDayOverview use case
+//! GetDayOverview use case
 
-use crate::application::dto::{GetDayOverviewInput, DayOverview, SuggestedSlot};
+use crate::application::dto::{GetDayOverviewInput, DayOverview, SuggestedSlot, UnscheduledReason, UnscheduledTask};
 use crate::application::errors::{AppError, AppResult};
 use crate::application::ports::{UserRepository, TaskRepository, ScheduleRepository};
 use crate::application::types::UserId;
-use crate::domain::entities::schedule::{expand_template, find_candidate_slots, TimeBlock};
-use chrono::Duration;
+use crate::domain::entities::schedule::{
+    can_schedule_task_in_block, expand_template, AvailabilityKind, ScheduleTemplate, TimeBlock,
+};
+use crate::domain::entities::schedule::matching::check_capability_requirements;
+use crate::domain::entities::task::Task;
+use chrono::{DateTime, Duration, FixedOffset};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Signature of the template expansion step
+type ExpandFn = fn(&ScheduleTemplate, DateTime<FixedOffset>, DateTime<FixedOffset>) -> Vec<TimeBlock>;
+
+/// Day overviews remembered per user and day, for `GetDayOverview::execute_cached`
+///
+/// Each entry records the content hash of the template it was computed from,
+/// so editing the active template (or switching to another one) recomputes
+/// the day on the next request. Task changes are not tracked: call
+/// `invalidate_user` after creating, updating or deleting a user's tasks.
+#[derive(Debug, Default)]
+pub struct DayOverviewCache {
+    entries: HashMap<(UserId, DateTime<FixedOffset>), (u64, DayOverview)>,
+}
+
+impl DayOverviewCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of cached days across all users
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forget every cached day of a user
+    pub fn invalidate_user(&mut self, user_id: UserId) {
+        self.entries.retain(|(cached_user, _), _| *cached_user != user_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
 
 /// Use case for getting a day overview with schedule and task suggestions
+///
+/// Suggestions are listed highest effective priority first, as of the start
+/// of the day (ties broken by task ID).
 pub struct GetDayOverview<'a> {
     user_repo: &'a dyn UserRepository,
     task_repo: &'a dyn TaskRepository,
     schedule_repo: &'a dyn ScheduleRepository,
+    expand: ExpandFn,
 }
 
 impl<'a> GetDayOverview<'a> {
@@ -24,31 +72,64 @@ impl<'a> GetDayOverview<'a> {
             user_repo,
             task_repo,
             schedule_repo,
+            expand: expand_template,
         }
     }
 
     pub fn execute(&self, user_id: UserId, input: GetDayOverviewInput) -> AppResult<DayOverview> {
-        // Get the user to access their location and week_start
-        let user = self.user_repo.find_by_id(user_id)?;
+        let template = self.active_template(user_id)?;
+        self.overview(user_id, &template, input)
+    }
+
+    /// Like `execute`, but reuses an overview from `cache` while the active
+    /// template's content is unchanged
+    pub fn execute_cached(
+        &self,
+        cache: &mut DayOverviewCache,
+        user_id: UserId,
+        input: GetDayOverviewInput,
+    ) -> AppResult<DayOverview> {
+        let template = self.active_template(user_id)?;
+        let template_hash = template.content_hash();
+        let key = (user_id, input.date);
+
+        if let Some((cached_hash, overview)) = cache.entries.get(&key) {
+            if *cached_hash == template_hash {
+                return Ok(overview.clone());
+            }
+        }
 
-        // Get the user's active schedule template
+        let overview = self.overview(user_id, &template, input)?;
+        cache.entries.insert(key, (template_hash, overview.clone()));
+        Ok(overview)
+    }
+
+    /// Load the user's active schedule template
+    fn active_template(&self, user_id: UserId) -> AppResult<ScheduleTemplate> {
         let active_template_id = self.user_repo.get_active_schedule_template(user_id)?
             .ok_or_else(|| AppError::ValidationError("User has no active schedule template".to_string()))?;
 
-        let template = self.schedule_repo.find_template(user_id, active_template_id)?;
+        self.schedule_repo.find_template(user_id, active_template_id)
+    }
+
+    fn overview(&self, user_id: UserId, template: &ScheduleTemplate, input: GetDayOverviewInput) -> AppResult<DayOverview> {
+        // Get the user to access their location and week_start
+        let user = self.user_repo.find_by_id(user_id)?;
 
         // Expand the template for the requested day
         let start_of_day = input.date;
         let end_of_day = input.date + Duration::days(1);
         
-        let time_blocks = expand_template(
-            &template,
+        let time_blocks = (self.expand)(
+            template,
             start_of_day,
             end_of_day,
         );
 
-        // Get active tasks for the day
-        let tasks = self.task_repo.find_tasks_for_date(user_id, input.date.with_timezone(&chrono::Utc))?;
+        // Get active tasks for the day, most pressing first
+        let day_start_utc = input.date.with_timezone(&chrono::Utc);
+        let mut tasks = self.task_repo.find_tasks_for_date(user_id, day_start_utc)?;
+        tasks.sort_by_key(|(task_id, task)| (Reverse(task.effective_priority(day_start_utc)), task_id.value()));
 
         // For now, we don't have scheduled tasks (that would require a separate occurrence tracking system)
         let scheduled_tasks = Vec::new();
@@ -61,68 +142,332 @@ impl<'a> GetDayOverview<'a> {
             .find(|loc| loc.is_some())
             .and_then(|loc| loc.clone());
 
+        let mut unscheduled = Vec::new();
+        let mut block_candidates = vec![Vec::new(); time_blocks.len()];
+
         for (task_id, task) in tasks {
-            // Find candidate slots where this task could be scheduled
-            let candidate_times: Vec<(chrono::DateTime<chrono::FixedOffset>, chrono::DateTime<chrono::FixedOffset>)> = 
-                find_candidate_slots(
-                    &time_blocks,
-                    &task,
-                    user_location.as_ref(),
-                );
+            // Every block of the day the task fits in, in chronological order
+            let fitting: Vec<usize> = (0..time_blocks.len())
+                .filter(|&index| {
+                    can_schedule_task_in_block(&task, &time_blocks[index], user_location.as_ref(), task.periodicity().occurrence_settings.as_ref())
+                })
+                .collect();
+            for &index in &fitting {
+                block_candidates[index].push(task_id);
+            }
+            let fitting_blocks: Vec<&TimeBlock> = fitting.iter().map(|&index| &time_blocks[index]).collect();
+
+            if fitting_blocks.is_empty() {
+                unscheduled.push(UnscheduledTask {
+                    task_id,
+                    title: task.title().to_string(),
+                    reason: unscheduled_reason(&task, &time_blocks),
+                });
+                continue;
+            }
 
             // Convert to SuggestedSlot DTOs with scoring
-            // For MVP, we use a simple scoring: higher priority = higher score
-            let task_suggestions: Vec<SuggestedSlot> = candidate_times
+            // For MVP, we use a simple scoring: earlier slots get higher scores
+            let task_suggestions: Vec<SuggestedSlot> = fitting_blocks
                 .into_iter()
                 .take(5) // Limit to 5 suggestions per task (techno-business rule)
                 .enumerate()
-                .map(|(idx, (start, end))| {
-                    // Simple scoring: first slots get higher scores
+                .map(|(idx, block)| {
                     let score = 100 - (idx as u8 * 10).min(50);
-                    
+
                     let reason = format!(
                         "Available slot at {}",
-                        start.format("%H:%M")
+                        block.start.format("%H:%M")
                     );
 
-                    // Find the corresponding TimeBlock for this candidate
-                    // (In a more sophisticated implementation, we'd track this better)
-                    let time_block = time_blocks.iter()
-                        .find(|block| block.start <= start && block.end >= end)
-                        .cloned()
-                        .unwrap_or_else(|| {
-                            // Fallback: create a minimal TimeBlock
-                            // This shouldn't happen, but we handle it gracefully
-                            use crate::domain::entities::schedule::{AvailabilityKind, CapabilitySet, LocationConstraint};
-                            TimeBlock {
-                                start,
-                                end,
-                                availability: AvailabilityKind::Available,
-                                capabilities: CapabilitySet::free(),
-                                location_constraint: LocationConstraint::Any,
-                                label: None,
-                                priority: 0,
-                            }
-                        });
-
                     SuggestedSlot {
-                        time_block,
+                        time_block: block.clone(),
                         score,
                         reason,
                     }
                 })
                 .collect();
 
-            if !task_suggestions.is_empty() {
-                suggestions.push((task_id, task_suggestions));
-            }
+            suggestions.push((task_id, task_suggestions));
         }
 
         Ok(DayOverview {
             date: input.date,
             time_blocks,
+            block_candidates,
             scheduled_tasks,
             suggestions,
+            unscheduled,
         })
     }
 }
+
+/// Explain why a task fits none of the day's blocks
+///
+/// Blocks that are not Unavailable are "usable"; the capability check only
+/// looks at those, so a task blocked by availability alone is reported as such.
+fn unscheduled_reason(task: &Task, blocks: &[TimeBlock]) -> UnscheduledReason {
+    let usable: Vec<&TimeBlock> = blocks.iter()
+        .filter(|block| !matches!(block.availability, AvailabilityKind::Unavailable(_)))
+        .collect();
+
+    if usable.is_empty() {
+        if blocks.is_empty() {
+            UnscheduledReason::NoMatchingBlock
+        } else {
+            UnscheduledReason::Unavailable
+        }
+    } else if !usable.iter().any(|block| check_capability_requirements(task, block)) {
+        UnscheduledReason::CapabilityMismatch
+    } else {
+        UnscheduledReason::NoMatchingBlock
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::TaskRepository;
+    use crate::application::types::TaskId;
+    use crate::domain::entities::schedule::{
+        CapabilitySet, DeviceAccess, LocationConstraint, RecurringRule, ScheduleTemplate, UnavailableReason,
+    };
+    use crate::domain::entities::task::{OccurrenceTimingSettings, PeriodicityBuilder, TaskBuilder, TaskPriority};
+    use crate::domain::entities::user::{Timezone, User};
+    use crate::infrastructure::{InMemoryScheduleRepository, InMemoryTaskRepository, InMemoryUserRepository};
+    use chrono::{NaiveTime, TimeZone, Timelike, Weekday};
+    use std::cell::Cell;
+
+    fn daily_task(title: &str, duration: u16, device: DeviceAccess) -> Task {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .with_occurrence_settings(OccurrenceTimingSettings {
+                duration: Some(duration),
+                not_before: None,
+                best_before: None,
+                rep_timing_settings: None,
+            })
+            .build()
+            .unwrap();
+        TaskBuilder::new(title, periodicity).min_device(device).build().unwrap()
+    }
+
+    fn suggested_hours(overview: &DayOverview, task_id: TaskId) -> Vec<u32> {
+        overview.suggestions.iter()
+            .find(|(id, _)| *id == task_id)
+            .map(|(_, slots)| slots.iter().map(|slot| slot.time_block.start.hour()).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_work_day_matches_computer_and_micro_tasks() {
+        let mut user_repo = InMemoryUserRepository::new();
+        let mut task_repo = InMemoryTaskRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+
+        let user_id = user_repo.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let mut phone_only = CapabilitySet::free();
+        phone_only.device = DeviceAccess::PhoneOnly;
+        let rules = vec![
+            RecurringRule::new(
+                vec![Weekday::Tue], time(0), time(7),
+                AvailabilityKind::Unavailable(UnavailableReason::Sleep),
+                CapabilitySet::free(), LocationConstraint::Any, Some("Sleep".to_string()), 0,
+            ).unwrap(),
+            RecurringRule::new(
+                vec![Weekday::Tue], time(9), time(17),
+                AvailabilityKind::BusyButFlexible,
+                phone_only, LocationConstraint::Any, Some("Work".to_string()), 0,
+            ).unwrap(),
+            RecurringRule::new(
+                vec![Weekday::Tue], time(19), time(21),
+                AvailabilityKind::Available,
+                CapabilitySet::free(), LocationConstraint::Any, Some("Evening".to_string()), 0,
+            ).unwrap(),
+        ];
+        let template = ScheduleTemplate::new("Work week".to_string(), "Europe/Paris".to_string(), rules).unwrap();
+        let template_id = schedule_repo.save_template(user_id, template).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        let computer_task = task_repo.save(user_id, daily_task("Write report", 60, DeviceAccess::Computer)).unwrap();
+        let micro_task = task_repo.save(user_id, daily_task("Drink water", 5, DeviceAccess::None)).unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let overview_for = |day| {
+            GetDayOverview::new(&user_repo, &task_repo, &schedule_repo)
+                .execute(user_id, GetDayOverviewInput { date: paris.with_ymd_and_hms(2026, 2, day, 0, 0, 0).unwrap() })
+                .unwrap()
+        };
+
+        // Tuesday: the micro task fits work and evening, the computer task only the evening
+        let tuesday = overview_for(10);
+        assert_eq!(suggested_hours(&tuesday, micro_task), vec![9, 19]);
+        assert_eq!(suggested_hours(&tuesday, computer_task), vec![19]);
+        assert!(tuesday.unscheduled.is_empty());
+
+        // The same matches, block by block
+        let candidates: Vec<(&str, &[TaskId])> = tuesday.time_blocks.iter()
+            .zip(&tuesday.block_candidates)
+            .map(|(block, tasks)| (block.label.as_deref().unwrap(), tasks.as_slice()))
+            .collect();
+        assert_eq!(candidates, vec![
+            ("Sleep", &[][..]),
+            ("Work", &[micro_task][..]),
+            ("Evening", &[computer_task, micro_task][..]),
+        ]);
+
+        // Wednesday has no rules at all
+        let wednesday = overview_for(11);
+        assert!(wednesday.suggestions.is_empty());
+        assert!(wednesday.block_candidates.is_empty());
+        assert_eq!(wednesday.unscheduled.len(), 2);
+        assert!(wednesday.unscheduled.iter().all(|t| t.reason == UnscheduledReason::NoMatchingBlock));
+    }
+
+    #[test]
+    fn test_suggestions_follow_effective_priority() {
+        let mut user_repo = InMemoryUserRepository::new();
+        let mut task_repo = InMemoryTaskRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+
+        let user_id = user_repo.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let evening = RecurringRule::new(
+            vec![Weekday::Tue],
+            NaiveTime::from_hms_opt(19, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(), LocationConstraint::Any, Some("Evening".to_string()), 0,
+        ).unwrap();
+        let template = ScheduleTemplate::new("Evenings".to_string(), "Europe/Paris".to_string(), vec![evening]).unwrap();
+        let template_id = schedule_repo.save_template(user_id, template).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        let mut report = daily_task("Write report", 60, DeviceAccess::None);
+        report.set_priority(TaskPriority::High);
+        let mut water = daily_task("Drink water", 5, DeviceAccess::None);
+        water.set_priority(TaskPriority::Low);
+        let mut taxes = daily_task("Taxes", 30, DeviceAccess::None);
+        taxes.set_soft_deadline(Some(chrono::Utc.with_ymd_and_hms(2026, 2, 9, 12, 0, 0).unwrap()));
+        let water = task_repo.save(user_id, water).unwrap();
+        let report = task_repo.save(user_id, report).unwrap();
+        let taxes = task_repo.save(user_id, taxes).unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let overview = GetDayOverview::new(&user_repo, &task_repo, &schedule_repo)
+            .execute(user_id, GetDayOverviewInput { date: paris.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap() })
+            .unwrap();
+
+        let order: Vec<TaskId> = overview.suggestions.iter().map(|(id, _)| *id).collect();
+        assert_eq!(order, vec![taxes, report, water]);
+    }
+
+    #[test]
+    fn test_unscheduled_reason() {
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let block = |availability, device| {
+            let mut capabilities = CapabilitySet::free();
+            capabilities.device = device;
+            TimeBlock {
+                start: paris.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap(),
+                end: paris.with_ymd_and_hms(2026, 2, 10, 10, 0, 0).unwrap(),
+                availability,
+                capabilities,
+                location_constraint: LocationConstraint::Any,
+                label: None,
+                priority: 0,
+            }
+        };
+        let computer_task = daily_task("Write report", 30, DeviceAccess::Computer);
+        let sleep = block(AvailabilityKind::Unavailable(UnavailableReason::Sleep), DeviceAccess::Computer);
+
+        assert_eq!(unscheduled_reason(&computer_task, &[]), UnscheduledReason::NoMatchingBlock);
+        assert_eq!(unscheduled_reason(&computer_task, std::slice::from_ref(&sleep)), UnscheduledReason::Unavailable);
+        assert_eq!(
+            unscheduled_reason(&computer_task, &[sleep, block(AvailabilityKind::Available, DeviceAccess::PhoneOnly)]),
+            UnscheduledReason::CapabilityMismatch
+        );
+
+        // Capabilities match, but the task is too long for the hour
+        let long_task = daily_task("Deep work", 120, DeviceAccess::Computer);
+        assert_eq!(
+            unscheduled_reason(&long_task, &[block(AvailabilityKind::Available, DeviceAccess::Computer)]),
+            UnscheduledReason::NoMatchingBlock
+        );
+    }
+
+    thread_local! {
+        static EXPANSIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn counting_expand(
+        template: &ScheduleTemplate,
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+    ) -> Vec<TimeBlock> {
+        EXPANSIONS.with(|count| count.set(count.get() + 1));
+        expand_template(template, start, end)
+    }
+
+    #[test]
+    fn test_cached_overview_reuses_until_template_changes() {
+        let mut user_repo = InMemoryUserRepository::new();
+        let task_repo = InMemoryTaskRepository::new();
+        let mut schedule_repo = InMemoryScheduleRepository::new();
+
+        let user_id = user_repo.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let focus = RecurringRule::new(
+            vec![Weekday::Tue], time(9), time(12),
+            AvailabilityKind::Available,
+            CapabilitySet::free(), LocationConstraint::Any, Some("Focus".to_string()), 0,
+        ).unwrap();
+        let template = ScheduleTemplate::new("Week".to_string(), "Europe/Paris".to_string(), vec![focus.clone()]).unwrap();
+        let template_id = schedule_repo.save_template(user_id, template).unwrap();
+        user_repo.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        let input = GetDayOverviewInput {
+            date: FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap(),
+        };
+        let mut cache = DayOverviewCache::new();
+        let overview_for = |schedule_repo: &InMemoryScheduleRepository, cache: &mut DayOverviewCache| {
+            let mut use_case = GetDayOverview::new(&user_repo, &task_repo, schedule_repo);
+            use_case.expand = counting_expand;
+            use_case.execute_cached(cache, user_id, input.clone()).unwrap()
+        };
+
+        EXPANSIONS.with(|count| count.set(0));
+        let first = overview_for(&schedule_repo, &mut cache);
+        let second = overview_for(&schedule_repo, &mut cache);
+        assert_eq!(EXPANSIONS.with(Cell::get), 1);
+        assert_eq!(first.time_blocks, second.time_blocks);
+
+        // Editing the template busts the entry
+        let longer_focus = RecurringRule { end: time(13), ..focus };
+        let template = ScheduleTemplate::new("Week".to_string(), "Europe/Paris".to_string(), vec![longer_focus]).unwrap();
+        schedule_repo.update_template(user_id, template_id, template).unwrap();
+
+        let third = overview_for(&schedule_repo, &mut cache);
+        assert_eq!(EXPANSIONS.with(Cell::get), 2);
+        assert_eq!(third.time_blocks[0].end.hour(), 13);
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -0,0 +1,370 @@
+//! ImportUserData use case
+
+use crate::application::dto::{ImportUserDataInput, ImportUserDataOutput, UserDataExport};
+use crate::application::errors::{AppError, AppResult};
+use crate::application::ports::{UserRepository, TaskRepository, ScheduleRepository};
+use crate::application::use_cases::{CreateScheduleTemplate, RegisterUser};
+use crate::domain::entities::schedule::ScheduleTemplate;
+use crate::domain::entities::task::Task;
+use crate::domain::entities::user::User;
+
+/// Use case for restoring a user from an `ExportUserData` bundle
+///
+/// Every entity is validated before anything is written, so an invalid
+/// bundle is rejected as a whole. Templates and tasks are stored as rebuilt
+/// by `ScheduleTemplate::revalidated` and `Task::revalidated`, not as
+/// exported, and the profile email is checked like at registration.
+/// Repositories assign fresh IDs; the output maps the exported IDs to the
+/// new ones.
+pub struct ImportUserData<'a> {
+    user_repo: &'a mut dyn UserRepository,
+    task_repo: &'a mut dyn TaskRepository,
+    schedule_repo: &'a mut dyn ScheduleRepository,
+}
+
+impl<'a> ImportUserData<'a> {
+    pub fn new(
+        user_repo: &'a mut dyn UserRepository,
+        task_repo: &'a mut dyn TaskRepository,
+        schedule_repo: &'a mut dyn ScheduleRepository,
+    ) -> Self {
+        Self {
+            user_repo,
+            task_repo,
+            schedule_repo,
+        }
+    }
+
+    pub fn execute(&mut self, input: ImportUserDataInput) -> AppResult<ImportUserDataOutput> {
+        let export = input.export;
+
+        // Validate everything up front
+        let (templates, tasks) = Self::validate(&export)?;
+
+        if self.user_repo.exists_by_username(&export.profile.username) {
+            return Err(AppError::UserAlreadyExists(export.profile.username));
+        }
+
+        let password_hash = RegisterUser::hash_password(&input.password)
+            .map_err(|e| AppError::InternalError(format!("Password hashing failed: {}", e)))?;
+
+        let profile = export.profile;
+        let mut user = User::try_new(profile.username, profile.email, password_hash, profile.timezone)
+            .map_err(|e| AppError::ValidationError(e.to_string()))?;
+        user.set_locations(profile.locations);
+        user.set_week_start(profile.week_start);
+        user.set_year_start(profile.year_start);
+        user.set_day_start(profile.day_start);
+
+        // Persist, re-mapping IDs as we go
+        let user_id = self.user_repo.save(user)?;
+
+        let mut template_ids = Vec::new();
+        for (exported, template) in export.schedule_templates.iter().zip(templates) {
+            let new_id = self.schedule_repo.save_template(user_id, template)?;
+            template_ids.push((exported.id, new_id));
+        }
+
+        if let Some(old_id) = export.active_schedule_template_id {
+            let new_id = template_ids.iter()
+                .find(|(id, _)| *id == old_id)
+                .map(|(_, new_id)| *new_id);
+            self.user_repo.set_active_schedule_template(user_id, new_id)?;
+        }
+
+        // Occurrences are not persisted yet, so only the tasks are restored
+        let mut task_ids = Vec::new();
+        for (exported, task) in export.tasks.iter().zip(tasks) {
+            let new_id = self.task_repo.save(user_id, task)?;
+            task_ids.push((exported.id, new_id));
+        }
+
+        Ok(ImportUserDataOutput {
+            user_id,
+            template_ids,
+            task_ids,
+        })
+    }
+
+    /// Re-run domain validation on every entity in the bundle, returning the
+    /// rebuilt templates and tasks in export order
+    fn validate(export: &UserDataExport) -> AppResult<(Vec<ScheduleTemplate>, Vec<Task>)> {
+        CreateScheduleTemplate::resolve_timezone(export.profile.timezone.as_str().to_string())?;
+
+        let mut templates = Vec::new();
+        for exported in &export.schedule_templates {
+            let template = &exported.template;
+            CreateScheduleTemplate::resolve_timezone(template.timezone.clone())?;
+            let rebuilt = template.revalidated()
+                .map_err(|e| AppError::ValidationError(format!("Schedule template '{}': {}", template.name, e)))?;
+            templates.push(rebuilt);
+        }
+
+        if let Some(active_id) = export.active_schedule_template_id {
+            if !export.schedule_templates.iter().any(|t| t.id == active_id) {
+                return Err(AppError::ValidationError(format!(
+                    "Active schedule template {} is not part of the export",
+                    active_id
+                )));
+            }
+        }
+
+        let mut tasks = Vec::new();
+        for exported in &export.tasks {
+            let task = &exported.task;
+            let rebuilt = task.revalidated()
+                .map_err(|e| AppError::ValidationError(format!("Task '{}': {}", task.title(), e)))?;
+            task.periodicity().validate()
+                .map_err(|e| AppError::ValidationError(format!("Task '{}': {}", task.title(), e)))?;
+            tasks.push(rebuilt);
+        }
+
+        Ok((templates, tasks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::types::UserId;
+    use crate::application::use_cases::ExportUserData;
+    use crate::domain::entities::schedule::{AvailabilityKind, CapabilitySet, LocationConstraint, RecurringRule};
+    use crate::domain::entities::task::PeriodicityBuilder;
+    use crate::domain::entities::user::Timezone;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::{InMemoryScheduleRepository, InMemoryTaskRepository, InMemoryUserRepository};
+    use crate::domain::entities::schedule::{DateOverride, OverrideBlock};
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+
+    struct Repos {
+        users: InMemoryUserRepository,
+        tasks: InMemoryTaskRepository,
+        schedules: InMemoryScheduleRepository,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            Self {
+                users: InMemoryUserRepository::new(),
+                tasks: InMemoryTaskRepository::new(),
+                schedules: InMemoryScheduleRepository::new(),
+            }
+        }
+
+        fn export(&self, user_id: UserId) -> UserDataExport {
+            let clock = FixedClock::new(Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap());
+            ExportUserData::new(&self.users, &self.tasks, &self.schedules, &clock)
+                .execute(user_id)
+                .unwrap()
+        }
+
+        fn import(&mut self, export: UserDataExport) -> AppResult<ImportUserDataOutput> {
+            ImportUserData::new(&mut self.users, &mut self.tasks, &mut self.schedules)
+                .execute(ImportUserDataInput { export, password: "new password".to_string() })
+        }
+    }
+
+    fn populated_repos() -> (Repos, UserId) {
+        let mut repos = Repos::new();
+        let user_id = repos.users.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "secret-hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let rule = RecurringRule::new(
+            vec![Weekday::Mon, Weekday::Tue],
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::BusyButFlexible,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Work".to_string()),
+            10,
+        ).unwrap();
+        let template = ScheduleTemplate::new("Work week".to_string(), "Europe/Paris".to_string(), vec![rule]).unwrap();
+        let template_id = repos.schedules.save_template(user_id, template).unwrap();
+        repos.users.set_active_schedule_template(user_id, Some(template_id)).unwrap();
+
+        let periodicity = PeriodicityBuilder::new()
+            .daily(2)
+            .on_weekdays(vec![Weekday::Mon, Weekday::Thu])
+            .build()
+            .unwrap();
+        let mut task = Task::new("Stretch".to_string(), periodicity).unwrap();
+        task.set_description(Some("Ten minutes".to_string())).unwrap();
+        repos.tasks.save(user_id, task).unwrap();
+
+        (repos, user_id)
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let (source, user_id) = populated_repos();
+        let export = source.export(user_id);
+
+        let mut target = Repos::new();
+        let output = target.import(export.clone()).unwrap();
+
+        assert_eq!(output.template_ids.len(), 1);
+        assert_eq!(output.task_ids.len(), 1);
+        assert_eq!(target.export(output.user_id), export);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_exported_bundle_imports_from_json() {
+        let (source, user_id) = populated_repos();
+        let export = source.export(user_id);
+
+        let json = serde_json::to_string(&export).unwrap();
+        let parsed: UserDataExport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, export);
+
+        let mut target = Repos::new();
+        let output = target.import(parsed).unwrap();
+        assert_eq!(target.export(output.user_id), export);
+
+        // Timezones are format-checked while parsing
+        let bad_zone = json.replace("\"Europe/Paris\"", "\"Paris\"");
+        assert!(serde_json::from_str::<UserDataExport>(&bad_zone).is_err());
+    }
+
+    #[test]
+    fn test_import_stores_rebuilt_templates() {
+        let (source, user_id) = populated_repos();
+        let mut export = source.export(user_id);
+        export.schedule_templates[0].template.name = "  Work week  ".to_string();
+
+        let mut target = Repos::new();
+        let output = target.import(export).unwrap();
+
+        // Stored as the constructor would have built it, not as exported
+        let stored = target.schedules.find_template(output.user_id, output.template_ids[0].1).unwrap();
+        assert_eq!(stored.name, "Work week");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_import_stores_rebuilt_tasks() {
+        let (source, user_id) = populated_repos();
+        let export = source.export(user_id);
+        let tampered = |edit: &dyn Fn(&mut serde_json::Value)| {
+            let mut json = serde_json::to_value(&export).unwrap();
+            edit(&mut json["tasks"][0]["task"]);
+            serde_json::from_value::<UserDataExport>(json).unwrap()
+        };
+
+        let untidy = tampered(&|task| {
+            task["title"] = "  Stretch  ".into();
+            task["tags"] = serde_json::json!([" WORK", "work"]);
+        });
+        let mut target = Repos::new();
+        let output = target.import(untidy).unwrap();
+        let stored = target.tasks.find_by_id(output.user_id, output.task_ids[0].1).unwrap();
+        assert_eq!(stored.title(), "Stretch");
+        assert_eq!(stored.tags(), ["work".to_string()]);
+
+        let inverted_window = tampered(&|task| {
+            task["preferred_time_window"] = serde_json::json!(["12:00:00", "09:00:00"]);
+        });
+        let mut target = Repos::new();
+        assert!(matches!(target.import(inverted_window), Err(AppError::ValidationError(message)) if message.contains("Stretch")));
+        assert!(!target.users.exists_by_username("alice"));
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_email() {
+        let (source, user_id) = populated_repos();
+        let mut export = source.export(user_id);
+        export.profile.email = "not-an-email".to_string();
+
+        let mut target = Repos::new();
+        assert!(matches!(target.import(export), Err(AppError::ValidationError(_))));
+        assert!(!target.users.exists_by_username("alice"));
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_rule_cadence_and_overrides() {
+        let (source, user_id) = populated_repos();
+        let export = source.export(user_id);
+        let import = |edit: &dyn Fn(&mut ScheduleTemplate)| {
+            let mut export = export.clone();
+            edit(&mut export.schedule_templates[0].template);
+            let mut target = Repos::new();
+            let result = target.import(export);
+            assert!(!target.users.exists_by_username("alice"));
+            result
+        };
+
+        let zero_cadence = import(&|template| {
+            template.rules[0].week_cadence = Some((0, NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()));
+        });
+        assert!(matches!(zero_cadence, Err(AppError::ValidationError(message)) if message.contains("Rule 1: Week cadence")));
+
+        let backwards_validity = import(&|template| {
+            template.rules[0].valid_from = NaiveDate::from_ymd_opt(2026, 6, 1);
+            template.rules[0].valid_until = NaiveDate::from_ymd_opt(2026, 5, 1);
+        });
+        assert!(matches!(backwards_validity, Err(AppError::ValidationError(_))));
+
+        let backwards_block = import(&|template| {
+            let mut holiday = DateOverride::new(NaiveDate::from_ymd_opt(2026, 5, 1).unwrap(), AvailabilityKind::Available, None);
+            holiday.blocks.push(OverrideBlock {
+                start: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+                end: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                availability: AvailabilityKind::Available,
+                label: None,
+            });
+            template.date_overrides = vec![holiday];
+        });
+        assert!(matches!(backwards_block, Err(AppError::ValidationError(message)) if message.contains("Override for 2026-05-01")));
+    }
+
+    #[test]
+    fn test_import_remaps_ids_around_existing_data() {
+        let (source, user_id) = populated_repos();
+        let export = source.export(user_id);
+
+        // Target already holds another user with their own template and task
+        let mut target = Repos::new();
+        let other = target.users.save(User::new(
+            "bob".to_string(),
+            "bob@example.com".to_string(),
+            "hash".to_string(),
+            Timezone::new("Europe/London".to_string()).unwrap(),
+        )).unwrap();
+        target.schedules.save_template(other, ScheduleTemplate::new("Bob".to_string(), "Europe/London".to_string(), Vec::new()).unwrap()).unwrap();
+
+        let output = target.import(export.clone()).unwrap();
+        assert_ne!(output.user_id, other);
+
+        let reimported = target.export(output.user_id);
+        let new_template_id = output.template_ids[0].1.value();
+        assert_eq!(reimported.active_schedule_template_id, Some(new_template_id));
+        assert_eq!(reimported.schedule_templates[0].template, export.schedule_templates[0].template);
+        assert_eq!(reimported.tasks[0].task, export.tasks[0].task);
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_bundle_without_writing() {
+        let (source, user_id) = populated_repos();
+        let mut export = source.export(user_id);
+        export.schedule_templates[0].template.timezone = "Mars/Olympus".to_string();
+
+        let mut target = Repos::new();
+        let result = target.import(export);
+
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+        assert!(!target.users.exists_by_username("alice"));
+    }
+
+    #[test]
+    fn test_import_rejects_existing_username() {
+        let (mut repos, user_id) = populated_repos();
+        let export = repos.export(user_id);
+
+        assert!(matches!(repos.import(export), Err(AppError::UserAlreadyExists(_))));
+    }
+}
@@ -1,13 +1,16 @@
-/// Application use cases
+//! Application use cases
 
 // User use cases
 pub mod register_user;
 pub mod update_user_settings;
+pub mod export_user_data;
+pub mod import_user_data;
 
 // Schedule use cases
 pub mod create_schedule_template;
 pub mod upsert_recurring_rule;
 pub mod set_active_schedule_template;
+pub mod delete_schedule_template;
 
 // Task use cases
 pub mod create_task;
@@ -16,14 +19,19 @@ pub mod complete_occurrence_rep;
 
 // View use cases
 pub mod get_day_overview;
+pub mod auto_schedule_day;
 
 // Re-exports
 pub use register_user::RegisterUser;
 pub use update_user_settings::UpdateUserSettings;
+pub use export_user_data::ExportUserData;
+pub use import_user_data::ImportUserData;
 pub use create_schedule_template::CreateScheduleTemplate;
 pub use upsert_recurring_rule::UpsertRecurringRule;
 pub use set_active_schedule_template::SetActiveScheduleTemplate;
+pub use delete_schedule_template::DeleteScheduleTemplate;
 pub use create_task::CreateTask;
 pub use update_task::UpdateTask;
 pub use complete_occurrence_rep::CompleteOccurrenceRep;
-pub use get_day_overview::GetDayOverview;
+pub use get_day_overview::{DayOverviewCache, GetDayOverview};
+pub use auto_schedule_day::AutoScheduleDay;
@@ -1,4 +1,4 @@
-/// RegisterUser use case
+//! RegisterUser use case
 
 use crate::application::dto::{RegisterUserInput, RegisterUserOutput};
 use crate::application::errors::{AppError, AppResult};
@@ -30,12 +30,13 @@ impl<'a> RegisterUser<'a> {
             .map_err(|e| AppError::InternalError(format!("Password hashing failed: {}", e)))?;
 
         // Create the user
-        let user = User::new(
+        let user = User::try_new(
             input.username.clone(),
             input.email,
             password_hash,
             input.timezone,
-        );
+        )
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
         // Save the user
         let user_id = self.user_repo.save(user)?;
@@ -47,7 +48,7 @@ impl<'a> RegisterUser<'a> {
     }
 
     /// Hash a password using argon2
-    fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    pub(crate) fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
         let salt = SaltString::generate(&mut OsRng);
         let argon2 = Argon2::default();
         let password_hash = argon2
@@ -68,6 +69,23 @@ impl<'a> RegisterUser<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::entities::user::Timezone;
+    use crate::infrastructure::InMemoryUserRepository;
+
+    #[test]
+    fn test_invalid_email_is_rejected() {
+        let mut user_repo = InMemoryUserRepository::new();
+
+        let result = RegisterUser::new(&mut user_repo).execute(RegisterUserInput {
+            username: "alice".to_string(),
+            email: "notanemail".to_string(),
+            password: "secret".to_string(),
+            timezone: Timezone::new("Europe/Paris".to_string()).unwrap(),
+        });
+
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+        assert!(!user_repo.exists_by_username("alice"));
+    }
 
     #[test]
     fn test_hash_and_verify_password() {
@@ -1,4 +1,4 @@
-/// SetActiveScheduleTemplate use case
+//! SetActiveScheduleTemplate use case
 
 use crate::application::errors::AppResult;
 use crate::application::ports::{ScheduleRepository, UserRepository};
@@ -1,4 +1,4 @@
-/// UpdateTask use case
+//! UpdateTask use case
 
 use crate::application::dto::UpdateTaskInput;
 use crate::application::errors::AppResult;
@@ -60,6 +60,10 @@ impl<'a> UpdateTask<'a> {
         if let Some(locations) = input.locations {
             task.set_locations(locations);
         }
+        if let Some(window) = input.preferred_time_window {
+            task.set_preferred_time_window(window)
+                .map_err(|e| crate::application::errors::AppError::ValidationError(e.to_string()))?;
+        }
 
         // Save the updated task
         self.task_repo.update(user_id, task_id, task)?;
@@ -1,4 +1,4 @@
-/// UpdateUserSettings use case
+//! UpdateUserSettings use case
 
 use crate::application::dto::UpdateUserSettingsInput;
 use crate::application::errors::AppResult;
@@ -33,7 +33,11 @@ impl<'a> UpdateUserSettings<'a> {
         }
 
         if let Some(timezone) = input.timezone {
-            user.timezone = timezone;
+            user.set_timezone(timezone);
+        }
+
+        if let Some(home_location) = input.home_location {
+            user.set_home_location(home_location);
         }
 
         // Save the updated user
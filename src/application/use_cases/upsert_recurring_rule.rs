@@ -1,4 +1,4 @@
-/// UpsertRecurringRule use case
+//! UpsertRecurringRule use case
 
 use crate::application::dto::{UpsertRecurringRuleInput, UpsertRecurringRuleOutput};
 use crate::application::errors::AppResult;
@@ -30,7 +30,7 @@ impl<'a> UpsertRecurringRule<'a> {
             input.label,
             input.priority,
         )
-        .map_err(|e| crate::application::errors::AppError::ValidationError(e))?;
+        .map_err(crate::application::errors::AppError::ValidationError)?;
 
         // Upsert the rule
         let rule_id = self.schedule_repo.upsert_rule(
@@ -12,6 +12,8 @@
 //! TASK_MAX_TITLE_LENGTH=200
 //! TASK_MAX_DESCRIPTION_LENGTH=2000
 //! TASK_DEFAULT_DURATION_MINUTES=30
+//! TASK_MAX_TAGS=20
+//! TASK_DEADLINE_ESCALATION_HOURS=24
 //! 
 //! # Task Occurrence Settings
 //! OCCURRENCE_MAX_NOTES_LENGTH=1000
@@ -21,7 +23,8 @@
 //! SCHEDULE_BUSY_FLEX_MAX_MINUTES=15
 //! SCHEDULE_BUSY_FLEX_MAX_HANDS_LEVEL=1  # 0=None, 1=Limited, 2=Full
 //! SCHEDULE_BUSY_FLEX_MAX_EYES_LEVEL=1
-//! SCHEDULE_BUSY_FLEX_MAX_DEVICE_LEVEL=1  # 0=None, 1=PhoneOnly, 2=Computer
+//! SCHEDULE_BUSY_FLEX_MAX_DEVICE_LEVEL=1  # 0=None, 1=PhoneOnly, 2=Computer, 3=Tablet
+//! SCHEDULE_SLOT_STEP_MINUTES=15
 //! ```
 
 use once_cell::sync::Lazy;
@@ -37,6 +40,8 @@ pub struct Config {
     pub task_max_title_length: usize,
     pub task_max_description_length: usize,
     pub task_default_duration_minutes: u16,
+    pub task_max_tags: usize,
+    pub task_deadline_escalation_hours: u32,
     
     // ── TASK OCCURRENCE SETTINGS ────────────────────────────
     pub occurrence_max_notes_length: usize,
@@ -47,6 +52,7 @@ pub struct Config {
     pub schedule_busy_flex_max_hands_level: u8,
     pub schedule_busy_flex_max_eyes_level: u8,
     pub schedule_busy_flex_max_device_level: u8,
+    pub schedule_slot_step_minutes: u32,
 }
 
 impl Config {
@@ -60,6 +66,8 @@ impl Config {
             task_max_title_length: env_var_or("TASK_MAX_TITLE_LENGTH", 200),
             task_max_description_length: env_var_or("TASK_MAX_DESCRIPTION_LENGTH", 2000),
             task_default_duration_minutes: env_var_or("TASK_DEFAULT_DURATION_MINUTES", 30),
+            task_max_tags: env_var_or("TASK_MAX_TAGS", 20),
+            task_deadline_escalation_hours: env_var_or("TASK_DEADLINE_ESCALATION_HOURS", 24),
             
             // Task occurrence settings
             occurrence_max_notes_length: env_var_or("OCCURRENCE_MAX_NOTES_LENGTH", 1000),
@@ -70,6 +78,7 @@ impl Config {
             schedule_busy_flex_max_hands_level: env_var_or("SCHEDULE_BUSY_FLEX_MAX_HANDS_LEVEL", 1),
             schedule_busy_flex_max_eyes_level: env_var_or("SCHEDULE_BUSY_FLEX_MAX_EYES_LEVEL", 1),
             schedule_busy_flex_max_device_level: env_var_or("SCHEDULE_BUSY_FLEX_MAX_DEVICE_LEVEL", 1),
+            schedule_slot_step_minutes: env_var_or("SCHEDULE_SLOT_STEP_MINUTES", 15),
         }
     }
 }
@@ -109,6 +118,14 @@ pub fn task_default_duration_minutes() -> u16 {
     CONFIG.task_default_duration_minutes
 }
 
+pub fn task_max_tags() -> usize {
+    CONFIG.task_max_tags
+}
+
+pub fn task_deadline_escalation_hours() -> u32 {
+    CONFIG.task_deadline_escalation_hours
+}
+
 // Task Occurrence
 pub fn occurrence_max_notes_length() -> usize {
     CONFIG.occurrence_max_notes_length
@@ -135,6 +152,10 @@ pub fn schedule_busy_flex_max_device_level() -> u8 {
     CONFIG.schedule_busy_flex_max_device_level
 }
 
+pub fn schedule_slot_step_minutes() -> u32 {
+    CONFIG.schedule_slot_step_minutes
+}
+
 // ========================================================================
 // TESTS
 // ========================================================================
@@ -151,9 +172,12 @@ mod tests {
         assert_eq!(config.task_max_title_length, 200);
         assert_eq!(config.task_max_description_length, 2000);
         assert_eq!(config.task_default_duration_minutes, 30);
+        assert_eq!(config.task_max_tags, 20);
+        assert_eq!(config.task_deadline_escalation_hours, 24);
         assert_eq!(config.occurrence_max_notes_length, 1000);
         assert_eq!(config.occurrence_rep_max_notes_length, 500);
         assert_eq!(config.schedule_busy_flex_max_minutes, 15);
+        assert_eq!(config.schedule_slot_step_minutes, 15);
     }
 
     #[test]
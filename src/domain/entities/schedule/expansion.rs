@@ -1,5 +1,6 @@
-use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveTime};
 use chrono_tz::Tz;
+use std::cmp::Reverse;
 use std::str::FromStr;
 
 use super::template::{RecurringRule, ScheduleTemplate};
@@ -13,6 +14,7 @@ use super::types::{AvailabilityKind, CapabilitySet, LocationConstraint};
 /// 
 /// Generated by expanding a schedule template over a date range.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeBlock {
     pub start: DateTime<FixedOffset>,
     pub end: DateTime<FixedOffset>,
@@ -27,7 +29,7 @@ impl TimeBlock {
     /// Check if two time blocks can be merged (same properties except time bounds)
     fn can_merge_with(&self, other: &TimeBlock) -> bool {
         self.availability == other.availability
-            && self.capabilities == other.capabilities
+            && self.capabilities.canonical() == other.capabilities.canonical()
             && self.location_constraint == other.location_constraint
             && self.priority == other.priority
             && self.label == other.label
@@ -38,6 +40,46 @@ impl TimeBlock {
 // EXPANSION ENGINE
 // ========================================================================
 
+/// How overlapping rules combine during expansion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExpansionMode {
+    /// The highest-priority rule alone defines the segment
+    #[default]
+    PriorityOverride,
+    /// The highest-priority rule still provides the label, location and
+    /// priority, but capabilities are intersected across every overlapping
+    /// rule and the most restrictive availability applies
+    IntersectCapabilities,
+}
+
+/// Why the winner of an overlap beat its runner-up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResolutionReason {
+    /// The winner has a strictly higher priority
+    Priority,
+    /// Equal priorities; the winner's availability is more restrictive
+    Restrictiveness,
+    /// Equal priority and availability; the winner comes first in `rules`
+    RuleOrder,
+}
+
+/// An overlap between rules that expansion resolved silently
+/// 
+/// Consecutive segments with the same competitors and winner are reported
+/// as one entry. Date overrides are not rules and never appear.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConflictResolution {
+    pub start: DateTime<FixedOffset>,
+    pub end: DateTime<FixedOffset>,
+    /// Indices into the template's `rules`, strongest first
+    pub rules: Vec<usize>,
+    /// Index of the rule that defines the block
+    pub winner: usize,
+    pub reason: ResolutionReason,
+}
+
 /// Expand a schedule template into concrete time blocks for a date range
 /// 
 /// # Algorithm
@@ -50,40 +92,117 @@ impl TimeBlock {
 /// - Higher priority wins
 /// - If same priority, prefer more restrictive availability:
 ///   Unavailable > BusyButFlexible > Available
+/// - Each segment keeps a single winner, so duplicate rules never produce
+///   duplicate blocks
+/// - Date overrides replace every rule for their whole local day
 pub fn expand_template(
     template: &ScheduleTemplate,
     range_start: DateTime<FixedOffset>,
     range_end: DateTime<FixedOffset>,
 ) -> Vec<TimeBlock> {
-    if range_start >= range_end {
-        return vec![];
-    }
+    expand_template_with_mode(template, range_start, range_end, ExpansionMode::default())
+}
 
+/// Expand a schedule template, choosing how overlapping rules combine
+/// 
+/// `ExpansionMode::PriorityOverride` is exactly `expand_template`. With
+/// `ExpansionMode::IntersectCapabilities`, a lower-priority rule such as a
+/// commute still clips the capabilities of a higher-priority "available"
+/// rule wherever the two overlap.
+pub fn expand_template_with_mode(
+    template: &ScheduleTemplate,
+    range_start: DateTime<FixedOffset>,
+    range_end: DateTime<FixedOffset>,
+    mode: ExpansionMode,
+) -> Vec<TimeBlock> {
     // Parse timezone
     let tz = match Tz::from_str(&template.timezone) {
         Ok(tz) => tz,
-        Err(_) => return vec![], // Invalid timezone, return empty
+        Err(_) => {
+            debug!("template {:?} has invalid timezone {:?}, no blocks produced", template.name, template.timezone);
+            return vec![];
+        }
     };
 
+    expand_in_zone(template, range_start, range_end, tz, mode, None)
+}
+
+/// Expand a schedule template like `expand_template`, also reporting every
+/// overlap between rules and how it was resolved
+/// 
+/// Lets the UI show that a rule was silently overridden, in particular by
+/// the tie-break between equal-priority rules.
+pub fn expand_template_with_report(
+    template: &ScheduleTemplate,
+    range_start: DateTime<FixedOffset>,
+    range_end: DateTime<FixedOffset>,
+) -> (Vec<TimeBlock>, Vec<ConflictResolution>) {
+    let Ok(tz) = Tz::from_str(&template.timezone) else {
+        debug!("template {:?} has invalid timezone {:?}, no blocks produced", template.name, template.timezone);
+        return (vec![], vec![]);
+    };
+
+    let mut report = Vec::new();
+    let blocks = expand_in_zone(template, range_start, range_end, tz, ExpansionMode::default(), Some(&mut report));
+    (blocks, report)
+}
+
+/// Expand a schedule template with its rules read as wall-clock times in `tz`
+/// 
+/// Ignores `template.timezone`, e.g. to show a schedule in the zone the user
+/// is travelling in. Block boundaries are resolved in `tz`, so a rule that
+/// crosses a DST transition lasts the real wall-clock time: 23:00–07:00 is
+/// 7 hours on a spring-forward night and 9 on a fall-back night.
+pub fn expand_template_tz(
+    template: &ScheduleTemplate,
+    range_start: DateTime<FixedOffset>,
+    range_end: DateTime<FixedOffset>,
+    tz: Tz,
+) -> Vec<TimeBlock> {
+    expand_in_zone(template, range_start, range_end, tz, ExpansionMode::default(), None)
+}
+
+fn expand_in_zone(
+    template: &ScheduleTemplate,
+    range_start: DateTime<FixedOffset>,
+    range_end: DateTime<FixedOffset>,
+    tz: Tz,
+    mode: ExpansionMode,
+    report: Option<&mut Vec<ConflictResolution>>,
+) -> Vec<TimeBlock> {
+    if range_start >= range_end {
+        return vec![];
+    }
+
     // Generate all rule occurrences
     let mut occurrences: Vec<RuleOccurrence> = vec![];
     
-    for rule in &template.rules {
-        let rule_occurrences = generate_rule_occurrences(rule, range_start, range_end, tz);
+    for (rule_index, rule) in template.rules.iter().enumerate() {
+        let rule_occurrences = generate_rule_occurrences(rule, rule_index, range_start, range_end, tz);
         occurrences.extend(rule_occurrences);
     }
 
+    // Date overrides replace the rules for their whole day
+    let overrides = generate_override_occurrences(template, range_start, range_end, tz);
+    if !overrides.is_empty() {
+        occurrences = subtract_spans(occurrences, &overrides);
+        occurrences.extend(overrides);
+    }
+
     if occurrences.is_empty() {
         return vec![];
     }
 
     // Resolve conflicts and create segments using sweep-line algorithm
-    let segments = resolve_conflicts(occurrences);
+    let segments = resolve_conflicts(occurrences, mode, report);
 
     // Merge adjacent blocks with same properties
-    let merged = merge_adjacent_blocks(segments);
-
-    merged
+    let blocks = merge_adjacent_blocks(segments);
+    debug!(
+        "expanded template {:?} ({} rules) into {} blocks",
+        template.name, template.rules.len(), blocks.len()
+    );
+    blocks
 }
 
 /// Internal representation of a rule occurrence
@@ -96,11 +215,14 @@ struct RuleOccurrence {
     location_constraint: LocationConstraint,
     label: Option<String>,
     priority: i16,
+    /// Index of the rule in the template; None for date overrides
+    rule_index: Option<usize>,
 }
 
 /// Generate all occurrences of a recurring rule within a date range
 fn generate_rule_occurrences(
     rule: &RecurringRule,
+    rule_index: usize,
     range_start: DateTime<FixedOffset>,
     range_end: DateTime<FixedOffset>,
     tz: Tz,
@@ -111,117 +233,171 @@ fn generate_rule_occurrences(
     let start_in_tz = range_start.with_timezone(&tz);
     let end_in_tz = range_end.with_timezone(&tz);
 
-    // Find the date range to iterate (expand a bit to catch overnight rules)
-    let start_date = start_in_tz.date_naive() - Duration::days(1);
-    let end_date = end_in_tz.date_naive() + Duration::days(1);
-
-    let mut current_date = start_date;
+    // Every piece of an occurrence lies within a single local day
+    let mut current_date = start_in_tz.date_naive();
+    let end_date = end_in_tz.date_naive();
 
     while current_date <= end_date {
-        let weekday = current_date.weekday();
-
-        if rule.days.contains(&weekday) {
-            // Generate occurrence(s) for this day
-            let day_occurrences = generate_day_occurrence(rule, current_date, tz, range_start, range_end);
+        if rule.applies_on_date(current_date) {
+            let day_occurrences = generate_day_occurrence(rule, rule_index, current_date, tz, range_start, range_end);
             occurrences.extend(day_occurrences);
         }
 
-        current_date = current_date + Duration::days(1);
+        current_date += Duration::days(1);
     }
 
     occurrences
 }
 
-/// Generate occurrence(s) for a single day
+/// Generate the part(s) of a rule that fall on a single local day
 /// 
-/// Handles overnight rules by potentially splitting into multiple occurrences
+/// That is the occurrence starting on `date`, up to midnight for an
+/// overnight rule, plus the spill of an overnight occurrence that started
+/// the day before.
 fn generate_day_occurrence(
     rule: &RecurringRule,
+    rule_index: usize,
     date: NaiveDate,
     tz: Tz,
     range_start: DateTime<FixedOffset>,
     range_end: DateTime<FixedOffset>,
 ) -> Vec<RuleOccurrence> {
-    let mut occurrences = vec![];
+    let next_day = date + Duration::days(1);
+    let mut spans = vec![];
 
     if rule.is_overnight() {
-        // Overnight rule: create one occurrence for same day and potentially one for next day
-        
-        // Part 1: from start time on date to midnight
-        let start_dt = date.and_time(rule.start).and_local_timezone(tz).single();
-        let next_day = date + Duration::days(1);
-        let midnight = next_day.and_hms_opt(0, 0, 0).unwrap().and_local_timezone(tz).single();
-        
-        if let (Some(start), Some(mid)) = (start_dt, midnight) {
-            let start_fixed = start.fixed_offset();
-            let mid_fixed = mid.fixed_offset();
-            
-            // Check if this part overlaps with the range
-            if start_fixed < range_end && mid_fixed > range_start {
-                occurrences.push(RuleOccurrence {
-                    start: start_fixed,
-                    end: mid_fixed,
-                    availability: rule.availability.clone(),
-                    capabilities: rule.capabilities.clone(),
-                    location_constraint: rule.location_constraint.clone(),
-                    label: rule.label.clone(),
-                    priority: rule.priority,
-                });
-            }
+        // Spill from the previous day: from midnight to the end time
+        if rule.end > NaiveTime::MIN && date.pred_opt().is_some_and(|previous| rule.starts_on_date(previous)) {
+            spans.push((local_instant(date, NaiveTime::MIN, tz), local_instant(date, rule.end, tz)));
         }
-        
-        // Part 2: from midnight to end time on next day
-        let end_dt = next_day.and_time(rule.end).and_local_timezone(tz).single();
-        
-        if let (Some(mid), Some(end)) = (midnight, end_dt) {
-            let mid_fixed = mid.fixed_offset();
-            let end_fixed = end.fixed_offset();
-            
-            // Check if this part overlaps with the range
-            if mid_fixed < range_end && end_fixed > range_start {
-                occurrences.push(RuleOccurrence {
-                    start: mid_fixed,
-                    end: end_fixed,
-                    availability: rule.availability.clone(),
-                    capabilities: rule.capabilities.clone(),
-                    location_constraint: rule.location_constraint.clone(),
-                    label: rule.label.clone(),
-                    priority: rule.priority,
-                });
-            }
+        // From the start time to midnight
+        if rule.starts_on_date(date) {
+            spans.push((local_instant(date, rule.start, tz), local_instant(next_day, NaiveTime::MIN, tz)));
         }
-    } else {
-        // Normal rule: single occurrence
-        let start_dt = date.and_time(rule.start).and_local_timezone(tz).single();
-        let end_dt = date.and_time(rule.end).and_local_timezone(tz).single();
-        
-        if let (Some(start), Some(end)) = (start_dt, end_dt) {
-            let start_fixed = start.fixed_offset();
-            let end_fixed = end.fixed_offset();
-            
-            // Check if this occurrence overlaps with the range
-            if start_fixed < range_end && end_fixed > range_start {
-                occurrences.push(RuleOccurrence {
-                    start: start_fixed,
-                    end: end_fixed,
-                    availability: rule.availability.clone(),
-                    capabilities: rule.capabilities.clone(),
-                    location_constraint: rule.location_constraint.clone(),
-                    label: rule.label.clone(),
-                    priority: rule.priority,
-                });
-            }
+    } else if rule.starts_on_date(date) {
+        spans.push((local_instant(date, rule.start, tz), local_instant(date, rule.end, tz)));
+    }
+
+    spans.into_iter()
+        .filter_map(|(start, end)| {
+            let (start, end) = (start?.fixed_offset(), end?.fixed_offset());
+            // Keep non-empty parts that overlap the range
+            (start < end && start < range_end && end > range_start).then(|| RuleOccurrence {
+                start,
+                end,
+                availability: rule.availability.clone(),
+                capabilities: rule.capabilities.clone(),
+                location_constraint: rule.location_constraint.clone(),
+                label: rule.label.clone(),
+                priority: rule.priority,
+                rule_index: Some(rule_index),
+            })
+        })
+        .collect()
+}
+
+/// Resolve a wall-clock time on `date` to an instant in `tz`
+/// 
+/// A time repeated by a fall-back transition maps to its first occurrence; a
+/// time skipped by a spring-forward gap moves an hour later, like the clocks.
+fn local_instant(date: NaiveDate, time: NaiveTime, tz: Tz) -> Option<DateTime<Tz>> {
+    let local = date.and_time(time);
+    local.and_local_timezone(tz).earliest()
+        .or_else(|| (local + Duration::hours(1)).and_local_timezone(tz).earliest())
+}
+
+/// Generate the occurrences of every date override overlapping the range
+/// 
+/// Each override covers its whole day: its blocks, plus its own availability
+/// in between. Overrides get the highest priority and neutral
+/// capabilities/location, so they win in every expansion mode once the rules
+/// beneath are cut away.
+fn generate_override_occurrences(
+    template: &ScheduleTemplate,
+    range_start: DateTime<FixedOffset>,
+    range_end: DateTime<FixedOffset>,
+    tz: Tz,
+) -> Vec<RuleOccurrence> {
+    let mut occurrences = vec![];
+
+    for date_override in &template.date_overrides {
+        let next_day = date_override.date + Duration::days(1);
+        let (Some(start), Some(end)) = (
+            local_instant(date_override.date, NaiveTime::MIN, tz),
+            local_instant(next_day, NaiveTime::MIN, tz),
+        ) else {
+            continue;
+        };
+        let (start, end) = (start.fixed_offset(), end.fixed_offset());
+        if start >= range_end || end <= range_start {
+            continue;
         }
+
+        let occurrence = |start, end, availability: &AvailabilityKind, label: &Option<String>| RuleOccurrence {
+            start,
+            end,
+            availability: availability.clone(),
+            capabilities: CapabilitySet::free(),
+            location_constraint: LocationConstraint::Any,
+            label: label.clone(),
+            priority: i16::MAX,
+            rule_index: None,
+        };
+
+        let blocks: Vec<RuleOccurrence> = date_override.blocks.iter()
+            .filter_map(|block| {
+                let block_start = local_instant(date_override.date, block.start, tz)?.fixed_offset();
+                let block_end = if block.end == NaiveTime::MIN {
+                    end
+                } else {
+                    local_instant(date_override.date, block.end, tz)?.fixed_offset()
+                };
+                (block_start < block_end)
+                    .then(|| occurrence(block_start, block_end, &block.availability, &block.label))
+            })
+            .collect();
+
+        let day = occurrence(start, end, &date_override.availability, &date_override.label);
+        occurrences.extend(subtract_spans(vec![day], &blocks));
+        occurrences.extend(blocks);
     }
 
     occurrences
 }
 
+/// Remove the time covered by `spans` from every occurrence, splitting
+/// occurrences that straddle a span
+fn subtract_spans(occurrences: Vec<RuleOccurrence>, spans: &[RuleOccurrence]) -> Vec<RuleOccurrence> {
+    spans.iter().fold(occurrences, |remaining, span| {
+        remaining.into_iter()
+            .flat_map(|occ| {
+                if occ.end <= span.start || occ.start >= span.end {
+                    return vec![occ];
+                }
+                let mut parts = vec![];
+                if occ.start < span.start {
+                    parts.push(RuleOccurrence { end: span.start, ..occ.clone() });
+                }
+                if occ.end > span.end {
+                    parts.push(RuleOccurrence { start: span.end, ..occ });
+                }
+                parts
+            })
+            .collect()
+    })
+}
+
 /// Resolve conflicts using a sweep-line algorithm
 /// 
 /// For each segment between boundaries, choose the winning rule (highest priority,
-/// then most restrictive availability as tie-breaker)
-fn resolve_conflicts(occurrences: Vec<RuleOccurrence>) -> Vec<TimeBlock> {
+/// then most restrictive availability as tie-breaker). In intersect mode the
+/// winner's availability and capabilities are narrowed by the other rules.
+/// Overlaps between rules are recorded in `report` when given.
+fn resolve_conflicts(
+    occurrences: Vec<RuleOccurrence>,
+    mode: ExpansionMode,
+    mut report: Option<&mut Vec<ConflictResolution>>,
+) -> Vec<TimeBlock> {
     if occurrences.is_empty() {
         return vec![];
     }
@@ -250,17 +426,31 @@ fn resolve_conflicts(occurrences: Vec<RuleOccurrence>) -> Vec<TimeBlock> {
             })
             .collect();
 
-        if !active_rules.is_empty() {
-            // Sort by priority (descending), then by restrictiveness
-            active_rules.sort_by(|a, b| {
-                b.priority.cmp(&a.priority).then_with(|| {
-                    // Tie-breaker: more restrictive availability wins
-                    availability_restrictiveness(&b.availability)
-                        .cmp(&availability_restrictiveness(&a.availability))
-                })
-            });
+        if let Some(winner) = resolve_block_winner(&active_rules) {
+            if active_rules.len() > 1 {
+                trace!(
+                    "overlap at {}: rule {:?} (priority {}) wins over {} other rule(s)",
+                    seg_start_ts, winner.label, winner.priority, active_rules.len() - 1
+                );
+            }
 
-            let winner = active_rules[0];
+            let (availability, capabilities) = match mode {
+                ExpansionMode::PriorityOverride => {
+                    (winner.availability.clone(), winner.capabilities.clone())
+                }
+                ExpansionMode::IntersectCapabilities => {
+                    let strictest = active_rules.iter()
+                        .copied()
+                        .reduce(|a, b| {
+                            if availability_restrictiveness(&b.availability) > availability_restrictiveness(&a.availability) { b } else { a }
+                        })
+                        .unwrap_or(winner);
+                    let capabilities = active_rules.iter()
+                        .filter(|occ| !std::ptr::eq(**occ, winner))
+                        .fold(winner.capabilities.clone(), |acc, occ| acc.intersect(&occ.capabilities));
+                    (strictest.availability.clone(), capabilities)
+                }
+            };
 
             // Create segment
             // Reconstruct DateTime from timestamp
@@ -271,11 +461,17 @@ fn resolve_conflicts(occurrences: Vec<RuleOccurrence>) -> Vec<TimeBlock> {
                 .unwrap()
                 .with_timezone(&winner.end.timezone());
 
+            if let Some(report) = report.as_deref_mut() {
+                // Strongest first; the sort is stable, so the winner stays first
+                active_rules.sort_by_key(|occ| Reverse(block_rank(occ.priority, &occ.availability)));
+                record_resolution(report, &active_rules, seg_start, seg_end);
+            }
+
             segments.push(TimeBlock {
                 start: seg_start,
                 end: seg_end,
-                availability: winner.availability.clone(),
-                capabilities: winner.capabilities.clone(),
+                availability,
+                capabilities,
                 location_constraint: winner.location_constraint.clone(),
                 label: winner.label.clone(),
                 priority: winner.priority,
@@ -286,6 +482,164 @@ fn resolve_conflicts(occurrences: Vec<RuleOccurrence>) -> Vec<TimeBlock> {
     segments
 }
 
+/// Add a segment's overlap to the report, extending the previous entry when
+/// the same contest simply continues
+/// 
+/// `active_rules` must be sorted strongest first.
+fn record_resolution(
+    report: &mut Vec<ConflictResolution>,
+    active_rules: &[&RuleOccurrence],
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+) {
+    let rules: Vec<usize> = active_rules.iter().filter_map(|occ| occ.rule_index).collect();
+    let (Some(winner), Some(runner_up)) = (active_rules.first(), active_rules.get(1)) else {
+        return;
+    };
+    let Some(winner_index) = winner.rule_index.filter(|_| rules.len() > 1) else {
+        return;
+    };
+
+    let reason = if winner.priority != runner_up.priority {
+        ResolutionReason::Priority
+    } else if availability_restrictiveness(&winner.availability) != availability_restrictiveness(&runner_up.availability) {
+        ResolutionReason::Restrictiveness
+    } else {
+        ResolutionReason::RuleOrder
+    };
+
+    match report.last_mut() {
+        Some(last) if last.end == start && last.rules == rules && last.winner == winner_index => {
+            last.end = end;
+        }
+        _ => report.push(ConflictResolution { start, end, rules, winner: winner_index, reason }),
+    }
+}
+
+/// Anything that can compete for a segment during conflict resolution
+pub trait BlockContender {
+    fn priority(&self) -> i16;
+    fn availability(&self) -> &AvailabilityKind;
+}
+
+impl BlockContender for RecurringRule {
+    fn priority(&self) -> i16 {
+        self.priority
+    }
+
+    fn availability(&self) -> &AvailabilityKind {
+        &self.availability
+    }
+}
+
+impl BlockContender for RuleOccurrence {
+    fn priority(&self) -> i16 {
+        self.priority
+    }
+
+    fn availability(&self) -> &AvailabilityKind {
+        &self.availability
+    }
+}
+
+/// Pick the rule that defines a segment where several rules overlap
+/// 
+/// # Ordering
+/// 1. Strictly higher `priority` wins
+/// 2. At equal priority, the more restrictive availability wins:
+///    Unavailable > BusyButFlexible > Available
+/// 3. If still tied, the earliest rule in `rules` wins
+/// 
+/// Returns None for an empty slice. `expand_template` picks the winner of
+/// every overlapping segment with this function.
+pub fn resolve_block_winner<'a, R: BlockContender>(rules: &[&'a R]) -> Option<&'a R> {
+    rules.iter()
+        .copied()
+        .min_by_key(|rule| Reverse(block_rank(rule.priority(), rule.availability())))
+}
+
+/// Find the gaps in a set of blocks within `[range_start, range_end)`
+/// 
+/// Time is occupied by Available and BusyButFlexible blocks; Unavailable
+/// blocks are ignored, so time covered only by them counts as a gap too.
+/// Blocks may overlap and need not be sorted. Gaps shorter than
+/// `min_minutes` are dropped.
+/// 
+/// Returns `(start, end)` pairs in chronological order.
+pub fn free_gaps(
+    blocks: &[TimeBlock],
+    range_start: DateTime<FixedOffset>,
+    range_end: DateTime<FixedOffset>,
+    min_minutes: i64,
+) -> Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    let occupied = blocks.iter()
+        .filter(|block| !matches!(block.availability, AvailabilityKind::Unavailable(_)));
+    uncovered_spans(occupied, range_start, range_end, Duration::minutes(min_minutes))
+}
+
+/// Find the time within `[day_start, day_end)` not covered by any block
+/// 
+/// Unlike `free_gaps`, every block counts, whatever its availability: the
+/// result is when nothing at all is scheduled. Blocks may be unsorted,
+/// touching or overlapping.
+/// 
+/// Returns `(start, end)` pairs in chronological order.
+pub fn find_gaps(
+    blocks: &[TimeBlock],
+    day_start: DateTime<FixedOffset>,
+    day_end: DateTime<FixedOffset>,
+) -> Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    uncovered_spans(blocks.iter(), day_start, day_end, Duration::zero())
+}
+
+/// Total length in minutes of the Available blocks, for capacity planning
+/// 
+/// With `include_busy_flexible`, BusyButFlexible blocks count too. Lengths
+/// come from absolute timestamps, so overnight blocks and blocks spanning a
+/// DST change count their real duration. Blocks are assumed not to overlap,
+/// as expansion guarantees.
+pub fn total_available_minutes(blocks: &[TimeBlock], include_busy_flexible: bool) -> u64 {
+    blocks.iter()
+        .filter(|block| match block.availability {
+            AvailabilityKind::Available => true,
+            AvailabilityKind::BusyButFlexible => include_busy_flexible,
+            AvailabilityKind::Unavailable(_) => false,
+        })
+        .map(|block| (block.end - block.start).num_minutes().max(0) as u64)
+        .sum()
+}
+
+/// Spans of `[range_start, range_end)` outside every block, at least `min_length` long
+fn uncovered_spans<'a>(
+    blocks: impl Iterator<Item = &'a TimeBlock>,
+    range_start: DateTime<FixedOffset>,
+    range_end: DateTime<FixedOffset>,
+    min_length: Duration,
+) -> Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    let mut occupied: Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> = blocks
+        .map(|block| (block.start.max(range_start), block.end.min(range_end)))
+        .filter(|(start, end)| start < end)
+        .collect();
+    occupied.sort_by_key(|(start, _)| *start);
+
+    let mut gaps = Vec::new();
+    let mut cursor = range_start;
+
+    for (start, end) in occupied.into_iter().chain(std::iter::once((range_end, range_end))) {
+        if start > cursor && start - cursor >= min_length {
+            gaps.push((cursor, start));
+        }
+        cursor = cursor.max(end);
+    }
+
+    gaps
+}
+
+/// Sort key for conflict resolution (higher = wins)
+fn block_rank(priority: i16, availability: &AvailabilityKind) -> (i16, u8) {
+    (priority, availability_restrictiveness(availability))
+}
+
 /// Assign a restrictiveness score to availability (higher = more restrictive)
 fn availability_restrictiveness(availability: &AvailabilityKind) -> u8 {
     match availability {
@@ -295,35 +649,45 @@ fn availability_restrictiveness(availability: &AvailabilityKind) -> u8 {
     }
 }
 
-/// Merge adjacent time blocks with identical properties
+/// Merge adjacent time blocks with identical properties, dropping duplicates
 fn merge_adjacent_blocks(mut blocks: Vec<TimeBlock>) -> Vec<TimeBlock> {
-    if blocks.is_empty() {
-        return vec![];
-    }
-
     // Sort by start time
     blocks.sort_by_key(|b| b.start.timestamp());
 
-    let mut merged = vec![];
-    let mut current = blocks[0].clone();
+    // Drop repeats of a block: same time span and every non-time field equal
+    let mut unique: Vec<TimeBlock> = Vec::with_capacity(blocks.len());
+    for block in blocks {
+        let repeat = unique.iter()
+            .rev()
+            .take_while(|kept| kept.start == block.start)
+            .any(|kept| kept.end == block.end && kept.can_merge_with(&block));
+        if !repeat {
+            unique.push(block);
+        }
+    }
+
+    coalesce_blocks(unique)
+}
 
-    for i in 1..blocks.len() {
-        let next = &blocks[i];
+/// Merge each block into its predecessor when it starts exactly where the
+/// predecessor ends and every non-time field is equal
+/// 
+/// Blocks are taken in the given order, without sorting. Expansion already
+/// runs this pass; it is exposed for callers that cut blocks up and need
+/// to stitch the pieces back together.
+pub fn coalesce_blocks(blocks: Vec<TimeBlock>) -> Vec<TimeBlock> {
+    let mut merged: Vec<TimeBlock> = Vec::with_capacity(blocks.len());
 
-        // Check if they're adjacent and can be merged
-        if current.end == next.start && current.can_merge_with(next) {
-            // Merge by extending current
-            current.end = next.end;
-        } else {
-            // Cannot merge, push current and start new
-            merged.push(current);
-            current = next.clone();
+    for next in blocks {
+        match merged.last_mut() {
+            // Adjacent and identical: extend the previous block
+            Some(current) if current.end == next.start && current.can_merge_with(&next) => {
+                current.end = next.end;
+            }
+            _ => merged.push(next),
         }
     }
 
-    // Push the last block
-    merged.push(current);
-
     merged
 }
 
@@ -333,7 +697,8 @@ mod tests {
     use crate::domain::entities::schedule::types::{
         AvailabilityKind, CapabilitySet, LocationConstraint, UnavailableReason,
     };
-    use chrono::{TimeZone, Timelike, Weekday};
+    use chrono::{Datelike, TimeZone, Timelike, Weekday};
+    use crate::domain::entities::schedule::template::DateOverride;
 
     #[test]
     fn test_expand_empty_template() {
@@ -382,6 +747,216 @@ mod tests {
         assert!(matches!(blocks[0].availability, AvailabilityKind::Available));
     }
 
+    #[test]
+    fn test_biweekly_rule_skips_off_weeks() {
+        // Alternating shift: Mon-Fri, aligned on the week of Monday Feb 2, 2026
+        let rule = RecurringRule::new(
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Unavailable(UnavailableReason::Work),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Shift".to_string()),
+            0,
+        ).unwrap()
+        .with_week_cadence(2, NaiveDate::from_ymd_opt(2026, 2, 4).unwrap())
+        .unwrap();
+
+        // The reference is a local date, so the weeks line up in any zone
+        for (zone, offset) in [("UTC", 0), ("Asia/Tokyo", 9 * 3600), ("America/Los_Angeles", -8 * 3600)] {
+            let template = ScheduleTemplate::new("Shifts".to_string(), zone.to_string(), vec![rule.clone()]).unwrap();
+            let local = FixedOffset::east_opt(offset).unwrap();
+            let week = |monday: u32| expand_template(
+                &template,
+                local.with_ymd_and_hms(2026, 2, monday, 0, 0, 0).unwrap(),
+                local.with_ymd_and_hms(2026, 2, monday + 7, 0, 0, 0).unwrap(),
+            );
+
+            assert_eq!(week(2).len(), 5, "{}", zone);
+            assert!(week(9).is_empty(), "{}", zone);
+            assert_eq!(week(16).len(), 5, "{}", zone);
+        }
+
+        // A cadence of 0 slipped in through the public field never applies
+        let mut broken = rule;
+        broken.week_cadence = Some((0, NaiveDate::from_ymd_opt(2026, 2, 4).unwrap()));
+        let template = ScheduleTemplate::new("Shifts".to_string(), "UTC".to_string(), vec![broken]).unwrap();
+        let utc = FixedOffset::east_opt(0).unwrap();
+        assert!(expand_template(
+            &template,
+            utc.with_ymd_and_hms(2026, 2, 2, 0, 0, 0).unwrap(),
+            utc.with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap(),
+        ).is_empty());
+    }
+
+    #[test]
+    fn test_equal_time_rule_is_not_a_full_day() {
+        let mut rule = RecurringRule::new(
+            vec![Weekday::Mon],
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            None,
+            0,
+        ).unwrap();
+        // `new` rejects this; the public field does not
+        rule.end = rule.start;
+        assert!(!rule.is_overnight());
+
+        let template = ScheduleTemplate::new("Empty".to_string(), "UTC".to_string(), vec![rule]).unwrap();
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let blocks = expand_template(
+            &template,
+            utc.with_ymd_and_hms(2026, 2, 2, 0, 0, 0).unwrap(),
+            utc.with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap(),
+        );
+        assert!(blocks.is_empty());
+    }
+
+    #[test]
+    fn test_seasonal_rule_only_expands_within_validity() {
+        let every_day = vec![
+            Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu,
+            Weekday::Fri, Weekday::Sat, Weekday::Sun,
+        ];
+        let summer_hours = RecurringRule::new(
+            every_day,
+            chrono::NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Early swim".to_string()),
+            0,
+        ).unwrap()
+        .with_validity(NaiveDate::from_ymd_opt(2026, 6, 1), NaiveDate::from_ymd_opt(2026, 8, 31))
+        .unwrap();
+
+        let template = ScheduleTemplate::new("Summer".to_string(), "UTC".to_string(), vec![summer_hours]).unwrap();
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let blocks = expand_template(
+            &template,
+            utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap(),
+        );
+
+        // June, July and August: 30 + 31 + 31 days
+        assert_eq!(blocks.len(), 92);
+        assert!(blocks.iter().all(|block| (6..=8).contains(&block.start.month())));
+    }
+
+    #[test]
+    fn test_coalesce_blocks_joins_adjacent_identical_blocks() {
+        let rule = RecurringRule::new(
+            vec![Weekday::Tue],
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Unavailable(UnavailableReason::Work),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Work".to_string()),
+            0,
+        ).unwrap();
+        let template = ScheduleTemplate::new("Simple".to_string(), "UTC".to_string(), vec![rule]).unwrap();
+        let at = |hour| FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2026, 2, 10, hour, 0, 0).unwrap();
+
+        // Two identical work blocks meeting at 13:00
+        let work = expand_template(&template, at(0), at(23)).remove(0);
+        let morning = TimeBlock { end: at(13), ..work.clone() };
+        let afternoon = TimeBlock { start: at(13), ..work };
+
+        let blocks = coalesce_blocks(vec![morning, afternoon]);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!((blocks[0].start, blocks[0].end), (at(9), at(17)));
+
+        // A differing label keeps neighbours apart
+        let mut relabelled = blocks[0].clone();
+        relabelled.label = Some("Overtime".to_string());
+        (relabelled.start, relabelled.end) = (at(17), at(18));
+        assert_eq!(coalesce_blocks(vec![blocks[0].clone(), relabelled]).len(), 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_expanded_day_json_round_trip() {
+        use crate::domain::entities::user::{GeoCoordinates, Location};
+
+        let office = Location::new(
+            Some("Office".to_string()),
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(48.8566, 2.3522).unwrap(),
+        ).unwrap();
+        let time = |hour| chrono::NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let rules = vec![
+            RecurringRule::new(
+                vec![Weekday::Tue], time(9), time(17),
+                AvailabilityKind::BusyButFlexible,
+                CapabilitySet::in_transit(),
+                LocationConstraint::MustBeOneOf(vec![office]),
+                Some("Work".to_string()),
+                0,
+            ).unwrap(),
+            RecurringRule::new(
+                vec![Weekday::Tue], time(12), time(13),
+                AvailabilityKind::Unavailable(UnavailableReason::Other("Lunch".to_string())),
+                CapabilitySet::free(),
+                LocationConstraint::Any,
+                None,
+                1,
+            ).unwrap(),
+        ];
+        let template = ScheduleTemplate::new("Office".to_string(), "Europe/Paris".to_string(), rules).unwrap();
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let blocks = expand_template(
+            &template,
+            paris.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap(),
+            paris.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap(),
+        );
+        assert_eq!(blocks.len(), 3);
+
+        let json = serde_json::to_string(&blocks).unwrap();
+        assert!(json.contains(r#"{"Unavailable":{"Other":"Lunch"}}"#), "got {}", json);
+        let restored: Vec<TimeBlock> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, blocks);
+    }
+
+    #[test]
+    fn test_identical_rules_yield_one_block_per_day() {
+        let work = RecurringRule::new(
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Unavailable(UnavailableReason::Work),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Work".to_string()),
+            0,
+        ).unwrap();
+
+        let template = ScheduleTemplate::new(
+            "Duplicated".to_string(),
+            "Europe/Paris".to_string(),
+            vec![work.clone(), work],
+        ).unwrap();
+
+        // Monday Feb 9 to Monday Feb 16, 2026
+        let start = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap();
+        let end = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2026, 2, 16, 0, 0, 0).unwrap();
+
+        let blocks = expand_template(&template, start, end);
+
+        assert_eq!(blocks.len(), 5);
+        for (block, day) in blocks.iter().zip(9..) {
+            assert_eq!(block.start.day(), day);
+            assert_eq!(block.start.hour(), 9);
+            assert_eq!(block.end.hour(), 17);
+        }
+    }
+
     #[test]
     fn test_overnight_rule_expansion() {
         let rule = RecurringRule::new(
@@ -417,6 +992,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_overnight_spill_into_range_start() {
+        let rule = RecurringRule::new(
+            vec![Weekday::Fri],
+            chrono::NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+            AvailabilityKind::Unavailable(UnavailableReason::Sleep),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Sleep".to_string()),
+            0,
+        ).unwrap();
+        let template = ScheduleTemplate::new("Sleep".to_string(), "UTC".to_string(), vec![rule]).unwrap();
+
+        // Saturday Feb 14 only: just the spill of Friday night
+        let start = FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2026, 2, 14, 0, 0, 0).unwrap();
+        let end = FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2026, 2, 15, 0, 0, 0).unwrap();
+        let blocks = expand_template(&template, start, end);
+
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].start, start);
+        assert_eq!(blocks[0].end, FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2026, 2, 14, 6, 0, 0).unwrap());
+    }
+
     #[test]
     fn test_priority_conflict_resolution() {
         let base_rule = RecurringRule::new(
@@ -468,6 +1067,152 @@ mod tests {
         assert!(matches!(blocks[2].availability, AvailabilityKind::Available));
     }
 
+    #[test]
+    fn test_intersect_mode_commute_clips_available() {
+        let available = RecurringRule::new(
+            vec![Weekday::Tue],
+            chrono::NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Available".to_string()),
+            10,
+        ).unwrap();
+
+        let commute = RecurringRule::new(
+            vec![Weekday::Tue],
+            chrono::NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            AvailabilityKind::BusyButFlexible,
+            CapabilitySet::in_transit(),
+            LocationConstraint::Any,
+            Some("Commute".to_string()),
+            0,
+        ).unwrap();
+
+        let template = ScheduleTemplate::new(
+            "Commute Test".to_string(),
+            "America/New_York".to_string(),
+            vec![available, commute],
+        ).unwrap();
+
+        let start = FixedOffset::west_opt(5 * 3600).unwrap()
+            .with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap();
+        let end = FixedOffset::west_opt(5 * 3600).unwrap()
+            .with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap();
+
+        // Default mode: the higher-priority rule hides the commute entirely
+        let overridden = expand_template(&template, start, end);
+        assert_eq!(overridden, expand_template_with_mode(&template, start, end, ExpansionMode::PriorityOverride));
+        assert_eq!(overridden.len(), 1);
+        assert_eq!(overridden[0].capabilities, CapabilitySet::free());
+
+        // Intersect mode: 07-08 free, 08-09 clipped to the commute, 09-12 free
+        let blocks = expand_template_with_mode(&template, start, end, ExpansionMode::IntersectCapabilities);
+        assert_eq!(blocks.len(), 3);
+
+        assert_eq!(blocks[1].start.hour(), 8);
+        assert_eq!(blocks[1].end.hour(), 9);
+        assert_eq!(blocks[1].label, Some("Available".to_string()));
+        assert_eq!(blocks[1].availability, AvailabilityKind::BusyButFlexible);
+        assert_eq!(blocks[1].capabilities, CapabilitySet::free().intersect(&CapabilitySet::in_transit()));
+
+        assert_eq!(blocks[0].capabilities, CapabilitySet::free());
+        assert_eq!(blocks[2].availability, AvailabilityKind::Available);
+    }
+
+    fn rule(availability: AvailabilityKind, priority: i16, label: &str) -> RecurringRule {
+        RecurringRule::new(
+            vec![Weekday::Mon],
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            availability,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some(label.to_string()),
+            priority,
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_block_winner_priority_first() {
+        let blocked = rule(AvailabilityKind::Unavailable(UnavailableReason::Work), 0, "blocked");
+        let open = rule(AvailabilityKind::Available, 1, "open");
+
+        assert_eq!(resolve_block_winner(&[&blocked, &open]), Some(&open));
+        assert_eq!(resolve_block_winner(&[&open, &blocked]), Some(&open));
+        assert_eq!(resolve_block_winner::<RecurringRule>(&[]), None);
+    }
+
+    #[test]
+    fn test_resolve_block_winner_ties() {
+        let available = rule(AvailabilityKind::Available, 5, "available");
+        let busy = rule(AvailabilityKind::BusyButFlexible, 5, "busy");
+        let unavailable = rule(AvailabilityKind::Unavailable(UnavailableReason::Sleep), 5, "unavailable");
+
+        // Three-way tie at equal priority, in every order
+        for order in [
+            [&available, &busy, &unavailable],
+            [&busy, &unavailable, &available],
+            [&unavailable, &available, &busy],
+        ] {
+            assert_eq!(resolve_block_winner(&order), Some(&unavailable));
+        }
+        assert_eq!(resolve_block_winner(&[&available, &busy]), Some(&busy));
+
+        // Fully tied rules: the first one wins
+        let other_busy = rule(AvailabilityKind::BusyButFlexible, 5, "other busy");
+        assert_eq!(resolve_block_winner(&[&other_busy, &busy]), Some(&other_busy));
+    }
+
+    #[test]
+    fn test_date_override_turns_work_tuesday_into_holiday() {
+        let work = RecurringRule::new(
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed],
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::BusyButFlexible,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Work".to_string()),
+            10,
+        ).unwrap();
+
+        // 2026-12-22 is a Tuesday
+        let holiday = NaiveDate::from_ymd_opt(2026, 12, 22).unwrap();
+        let template = ScheduleTemplate::new(
+            "Holiday Test".to_string(),
+            "Europe/Paris".to_string(),
+            vec![work],
+        ).unwrap()
+        .with_date_overrides(vec![DateOverride::new(
+            holiday,
+            AvailabilityKind::Unavailable(UnavailableReason::Other("Holiday".to_string())),
+            Some("Holiday".to_string()),
+        )])
+        .unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let start = paris.with_ymd_and_hms(2026, 12, 21, 0, 0, 0).unwrap();
+        let end = paris.with_ymd_and_hms(2026, 12, 24, 0, 0, 0).unwrap();
+        let blocks = expand_template(&template, start, end);
+
+        // Monday work, Tuesday holiday (whole day), Wednesday work
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].label, Some("Work".to_string()));
+        assert_eq!(blocks[1].label, Some("Holiday".to_string()));
+        assert!(matches!(blocks[1].availability, AvailabilityKind::Unavailable(_)));
+        assert_eq!(blocks[1].start, paris.with_ymd_and_hms(2026, 12, 22, 0, 0, 0).unwrap());
+        assert_eq!(blocks[1].end, paris.with_ymd_and_hms(2026, 12, 23, 0, 0, 0).unwrap());
+        assert_eq!(blocks[2].label, Some("Work".to_string()));
+        assert_eq!(blocks[2].start.day(), 23);
+
+        // Duplicate dates are rejected
+        let twice = DateOverride::new(holiday, AvailabilityKind::Available, None);
+        assert!(template.with_date_overrides(vec![twice.clone(), twice]).is_err());
+    }
+
     #[test]
     fn test_merge_adjacent_blocks() {
         let tz = FixedOffset::west_opt(5 * 3600).unwrap();
@@ -498,4 +1243,154 @@ mod tests {
         assert_eq!(merged[0].start.hour(), 9);
         assert_eq!(merged[0].end.hour(), 11);
     }
+
+    fn block(start_hour: u32, end_hour: u32, availability: AvailabilityKind) -> TimeBlock {
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        TimeBlock {
+            start: tz.with_ymd_and_hms(2026, 2, 10, start_hour, 0, 0).unwrap(),
+            end: tz.with_ymd_and_hms(2026, 2, 10, end_hour, 0, 0).unwrap(),
+            availability,
+            capabilities: CapabilitySet::free(),
+            location_constraint: LocationConstraint::Any,
+            label: None,
+            priority: 0,
+        }
+    }
+
+    #[test]
+    fn test_free_gaps_lunch_break() {
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let at = |hour, minute| tz.with_ymd_and_hms(2026, 2, 10, hour, minute, 0).unwrap();
+
+        let mut afternoon = block(13, 17, AvailabilityKind::BusyButFlexible);
+        afternoon.start = at(12, 45);
+        let blocks = vec![
+            afternoon,
+            block(9, 12, AvailabilityKind::Available),
+            block(12, 13, AvailabilityKind::Unavailable(UnavailableReason::Appointment)),
+        ];
+
+        // Lunch is only covered by an Unavailable block, so it is free
+        let gaps = free_gaps(&blocks, at(9, 0), at(17, 0), 30);
+        assert_eq!(gaps, vec![(at(12, 0), at(12, 45))]);
+
+        // Too short once the minimum length is raised
+        assert!(free_gaps(&blocks, at(9, 0), at(17, 0), 60).is_empty());
+    }
+
+    #[test]
+    fn test_free_gaps_at_range_edges() {
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let at = |hour| tz.with_ymd_and_hms(2026, 2, 10, hour, 0, 0).unwrap();
+
+        // Blocks sticking out of the range are clipped to it
+        let blocks = vec![
+            block(6, 9, AvailabilityKind::Available),
+            block(16, 20, AvailabilityKind::Available),
+        ];
+        assert_eq!(free_gaps(&blocks, at(8), at(18), 0), vec![(at(9), at(16))]);
+
+        // Open time at both ends of the range
+        let blocks = vec![block(10, 12, AvailabilityKind::Available)];
+        assert_eq!(
+            free_gaps(&blocks, at(8), at(18), 0),
+            vec![(at(8), at(10)), (at(12), at(18))]
+        );
+
+        // No blocks at all: the whole range is free
+        assert_eq!(free_gaps(&[], at(8), at(18), 0), vec![(at(8), at(18))]);
+    }
+
+    #[test]
+    fn test_find_gaps_in_working_day() {
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let at = |hour| tz.with_ymd_and_hms(2026, 2, 10, hour, 0, 0).unwrap();
+        let midnight = tz.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap();
+
+        let blocks = vec![
+            block(13, 17, AvailabilityKind::Unavailable(UnavailableReason::Work)),
+            block(9, 12, AvailabilityKind::Available),
+        ];
+        assert_eq!(
+            find_gaps(&blocks, at(0), midnight),
+            vec![(at(0), at(9)), (at(12), at(13)), (at(17), midnight)]
+        );
+
+        // Touching and overlapping blocks leave no gap between them
+        let blocks = vec![
+            block(9, 12, AvailabilityKind::Available),
+            block(10, 11, AvailabilityKind::BusyButFlexible),
+            block(12, 13, AvailabilityKind::Available),
+        ];
+        assert_eq!(find_gaps(&blocks, at(8), at(14)), vec![(at(8), at(9)), (at(13), at(14))]);
+    }
+
+    #[test]
+    fn test_total_available_minutes_over_a_week() {
+        let rules = vec![
+            RecurringRule::new(
+                vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+                chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                chrono::NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+                AvailabilityKind::Available,
+                CapabilitySet::free(),
+                LocationConstraint::Any,
+                None,
+                0,
+            ).unwrap(),
+            RecurringRule::new(
+                vec![Weekday::Sat],
+                chrono::NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                chrono::NaiveTime::from_hms_opt(2, 0, 0).unwrap(),
+                AvailabilityKind::BusyButFlexible,
+                CapabilitySet::free(),
+                LocationConstraint::Any,
+                None,
+                0,
+            ).unwrap(),
+        ];
+        let template = ScheduleTemplate::new("Office".to_string(), "Europe/Paris".to_string(), rules).unwrap();
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let blocks = expand_template(
+            &template,
+            paris.with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap(),
+            paris.with_ymd_and_hms(2026, 2, 16, 0, 0, 0).unwrap(),
+        );
+
+        assert_eq!(total_available_minutes(&blocks, false), 2400);
+        // The Saturday night block crosses midnight
+        assert_eq!(total_available_minutes(&blocks, true), 2400 + 240);
+    }
+
+    #[test]
+    fn test_merge_drops_duplicate_blocks() {
+        let available = block(9, 12, AvailabilityKind::Available);
+        let mut relabelled = available.clone();
+        relabelled.label = Some("Copy".to_string());
+        let busy = block(9, 12, AvailabilityKind::BusyButFlexible);
+
+        let merged = merge_adjacent_blocks(vec![available.clone(), relabelled.clone(), busy.clone(), available.clone()]);
+        assert_eq!(merged, vec![available, relabelled, busy]);
+
+        // Identical rules expand to a single block
+        let rule = rule(AvailabilityKind::Available, 0, "Focus");
+        let template = ScheduleTemplate::new("Twice".to_string(), "UTC".to_string(), vec![rule.clone(), rule]).unwrap();
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let blocks = expand_template(
+            &template,
+            utc.with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap(),
+            utc.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap(),
+        );
+        assert_eq!(blocks.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_keeps_same_span_with_other_location_constraint() {
+        let anywhere = block(9, 12, AvailabilityKind::Available);
+        let mut known_only = anywhere.clone();
+        known_only.location_constraint = LocationConstraint::MustBeKnown;
+
+        let merged = merge_adjacent_blocks(vec![anywhere.clone(), known_only.clone()]);
+        assert_eq!(merged, vec![anywhere, known_only]);
+    }
 }
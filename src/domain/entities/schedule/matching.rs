@@ -1,10 +1,10 @@
-use chrono::DateTime;
+use std::cmp::Reverse;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveTime};
+use crate::domain::entities::task::OccurrenceTimingSettings;
 use crate::domain::entities::user::Location;
 use super::expansion::TimeBlock;
-use super::types::{
-    AvailabilityKind, AvailabilityLevel, DeviceAccess, Mobility,
-    busy_flex_max_minutes, busy_flex_max_hands, busy_flex_max_eyes,
-};
+use super::types::{AvailabilityKind, AvailabilityLevel, BusyFlexLimits, DeviceAccess, Mobility};
 
 // ========================================================================
 // SCHEDULABLE TASK TRAIT
@@ -38,6 +38,11 @@ pub trait SchedulableTask {
     
     /// Allowed mobility states (empty = all allowed)
     fn allowed_mobility(&self) -> Vec<Mobility>;
+
+    /// Tags grouping related tasks, used to cluster them (none by default)
+    fn tags(&self) -> &[String] {
+        &[]
+    }
 }
 
 // ========================================================================
@@ -53,13 +58,14 @@ pub trait SchedulableTask {
 ///    - BusyButFlexible → only allow micro tasks (see below)
 ///    - Available → check normal requirements
 /// 
-/// 2. **BusyButFlexible Constraints (micro tasks only)**
-///    - Duration <= busy_flex_max_minutes() (default 15)
+/// 2. **BusyButFlexible Constraints (micro tasks only)**, within the
+///    configured `BusyFlexLimits`
+///    - Duration <= max_minutes (default 15)
 ///    - requires_location() == false
 ///    - Location constraint allows unknown/any
-///    - Device requirement != Computer
-///    - Hands <= Limited
-///    - Eyes <= Limited
+///    - Device requirement <= max_device (default PhoneOnly)
+///    - Hands <= max_hands (default Limited)
+///    - Eyes <= max_eyes (default Limited)
 /// 
 /// 3. **Location Matching**
 ///    - Block's location constraint must accept current_location
@@ -69,22 +75,45 @@ pub trait SchedulableTask {
 ///    - Block capabilities >= task requirements for all dimensions
 ///    - Device: None < PhoneOnly < Computer
 ///    - Mobility: if task specifies allowed states, block must match
+/// 
+/// 5. **Timing Window** (when `timing` is given)
+///    - The block must leave the task's duration between `not_before` and
+///      `best_before`, read in the block's local time
+///    - With per-rep settings, fitting any one rep's window is enough
+///    - A window crossing midnight runs into the following day
 pub fn can_schedule_task_in_block(
     task: &impl SchedulableTask,
     block: &TimeBlock,
     current_location: Option<&Location>,
+    timing: Option<&OccurrenceTimingSettings>,
+) -> bool {
+    can_schedule_task_in_block_with(task, block, current_location, timing, &BusyFlexLimits::configured())
+}
+
+/// `can_schedule_task_in_block` with explicit BusyButFlexible limits
+/// instead of the configured ones
+pub fn can_schedule_task_in_block_with(
+    task: &impl SchedulableTask,
+    block: &TimeBlock,
+    current_location: Option<&Location>,
+    timing: Option<&OccurrenceTimingSettings>,
+    limits: &BusyFlexLimits,
 ) -> bool {
     // 1. Availability gating
     match &block.availability {
-        AvailabilityKind::Unavailable(_) => return false,
+        AvailabilityKind::Unavailable(reason) => {
+            trace!("task rejected from block at {}: unavailable ({:?})", block.start, reason);
+            return false;
+        }
         
         AvailabilityKind::BusyButFlexible => {
             // Only allow micro tasks during busy-but-flexible periods
-            if !is_micro_task(task) {
+            if !is_micro_task(task, limits) {
+                trace!("task rejected from busy-but-flexible block at {}: not a micro task", block.start);
                 return false;
             }
             // Additional constraints for busy-but-flexible
-            if !check_busy_flex_constraints(task, block, current_location) {
+            if !check_busy_flex_constraints(task, block, current_location, limits) {
                 return false;
             }
         }
@@ -107,6 +136,15 @@ pub fn can_schedule_task_in_block(
     // 4. Duration check (block must be long enough)
     let block_duration_minutes = (block.end.timestamp() - block.start.timestamp()) / 60;
     if (block_duration_minutes as u32) < task.estimated_duration_minutes() {
+        trace!(
+            "task rejected from block at {}: block lasts {} min, task needs {} min",
+            block.start, block_duration_minutes, task.estimated_duration_minutes()
+        );
+        return false;
+    }
+
+    // 5. Timing window
+    if !check_timing_window(task, block, timing) {
         return false;
     }
 
@@ -114,8 +152,8 @@ pub fn can_schedule_task_in_block(
 }
 
 /// Check if a task qualifies as a "micro task" for BusyButFlexible periods
-fn is_micro_task(task: &impl SchedulableTask) -> bool {
-    task.estimated_duration_minutes() <= busy_flex_max_minutes()
+fn is_micro_task(task: &impl SchedulableTask, limits: &BusyFlexLimits) -> bool {
+    task.estimated_duration_minutes() <= limits.max_minutes
         && !task.requires_location()
 }
 
@@ -124,30 +162,39 @@ fn check_busy_flex_constraints(
     task: &impl SchedulableTask,
     block: &TimeBlock,
     current_location: Option<&Location>,
+    limits: &BusyFlexLimits,
 ) -> bool {
     // Location constraint must allow unknown/any
     let location_ok = match &block.location_constraint {
         super::types::LocationConstraint::Any => true,
         super::types::LocationConstraint::MustBeUnknown => current_location.is_none(),
-        _ => false,
+        // Concrete location requirements cannot be checked on the move
+        super::types::LocationConstraint::MustBeKnown
+        | super::types::LocationConstraint::MustBeOneOf(_)
+        | super::types::LocationConstraint::MustBeNear { .. }
+        | super::types::LocationConstraint::MustBeWithin { .. } => false,
     };
     
     if !location_ok {
+        trace!("task rejected from busy-but-flexible block at {}: location constraint {:?}", block.start, block.location_constraint);
         return false;
     }
 
-    // Device requirement must not be Computer
-    if task.min_device() == DeviceAccess::Computer {
+    // Device requirement must stay within the configured level
+    if task.min_device() > limits.max_device {
+        trace!("task rejected from busy-but-flexible block at {}: needs device {:?}", block.start, task.min_device());
         return false;
     }
 
-    // Hands must be <= Limited
-    if task.min_hands() > busy_flex_max_hands() {
+    // Hands must stay within the configured level
+    if task.min_hands() > limits.max_hands {
+        trace!("task rejected from busy-but-flexible block at {}: needs hands {:?}", block.start, task.min_hands());
         return false;
     }
 
-    // Eyes must be <= Limited
-    if task.min_eyes() > busy_flex_max_eyes() {
+    // Eyes must stay within the configured level
+    if task.min_eyes() > limits.max_eyes {
+        trace!("task rejected from busy-but-flexible block at {}: needs eyes {:?}", block.start, task.min_eyes());
         return false;
     }
 
@@ -162,11 +209,13 @@ fn check_location_requirements(
 ) -> bool {
     // Check block's location constraint
     if !block.location_constraint.matches(current_location) {
+        trace!("task rejected from block at {}: location constraint {:?} not met", block.start, block.location_constraint);
         return false;
     }
 
     // If task requires location, must have one
     if task.requires_location() && current_location.is_none() {
+        trace!("task rejected from block at {}: task requires a known location", block.start);
         return false;
     }
 
@@ -174,46 +223,219 @@ fn check_location_requirements(
 }
 
 /// Check capability requirements
-fn check_capability_requirements(
+pub(crate) fn check_capability_requirements(
     task: &impl SchedulableTask,
     block: &TimeBlock,
 ) -> bool {
     // Hands
     if block.capabilities.hands < task.min_hands() {
+        trace!(
+            "task rejected from block at {}: hands mismatch (block {:?}, task needs {:?})",
+            block.start, block.capabilities.hands, task.min_hands()
+        );
         return false;
     }
 
     // Eyes
     if block.capabilities.eyes < task.min_eyes() {
+        trace!(
+            "task rejected from block at {}: eyes mismatch (block {:?}, task needs {:?})",
+            block.start, block.capabilities.eyes, task.min_eyes()
+        );
         return false;
     }
 
     // Speech
     if block.capabilities.speech < task.min_speech() {
+        trace!(
+            "task rejected from block at {}: speech mismatch (block {:?}, task needs {:?})",
+            block.start, block.capabilities.speech, task.min_speech()
+        );
         return false;
     }
 
     // Cognitive
     if block.capabilities.cognitive < task.min_cognitive() {
+        trace!(
+            "task rejected from block at {}: cognitive mismatch (block {:?}, task needs {:?})",
+            block.start, block.capabilities.cognitive, task.min_cognitive()
+        );
         return false;
     }
 
     // Device
     if block.capabilities.device < task.min_device() {
+        trace!(
+            "task rejected from block at {}: device mismatch (block {:?}, task needs {:?})",
+            block.start, block.capabilities.device, task.min_device()
+        );
         return false;
     }
 
     // Mobility
     let allowed_mobility = task.allowed_mobility();
-    if !allowed_mobility.is_empty() {
-        if !allowed_mobility.contains(&block.capabilities.mobility) {
-            return false;
-        }
+    if !allowed_mobility.is_empty() && !allowed_mobility.contains(&block.capabilities.mobility) {
+        trace!(
+            "task rejected from block at {}: mobility mismatch (block {:?}, task allows {:?})",
+            block.start, block.capabilities.mobility, allowed_mobility
+        );
+        return false;
     }
 
     true
 }
 
+/// Check the block leaves room for the task inside one of its timing windows
+fn check_timing_window(
+    task: &impl SchedulableTask,
+    block: &TimeBlock,
+    timing: Option<&OccurrenceTimingSettings>,
+) -> bool {
+    let Some(timing) = timing else {
+        return true;
+    };
+
+    let duration = Duration::minutes(task.estimated_duration_minutes() as i64);
+    if !fits_timing(block.start, block.end, duration, timing) {
+        trace!(
+            "task rejected from block at {}: outside timing window (not_before {:?}, best_before {:?})",
+            block.start, timing.not_before, timing.best_before
+        );
+        return false;
+    }
+
+    true
+}
+
+/// Whether `[start, end)` keeps `duration` inside any window of `timing`
+/// 
+/// Each rep falls back to the occurrence-wide bounds it does not override.
+fn fits_timing(
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+    duration: Duration,
+    timing: &OccurrenceTimingSettings,
+) -> bool {
+    match &timing.rep_timing_settings {
+        Some(reps) if !reps.is_empty() => reps.iter().any(|rep| {
+            let window = (rep.not_before.or(timing.not_before), rep.best_before.or(timing.best_before));
+            window_fits(start, end, duration, window)
+        }),
+        _ => window_fits(start, end, duration, (timing.not_before, timing.best_before)),
+    }
+}
+
+/// Whether `[start, end)` keeps `duration` inside the daily window on some day
+/// 
+/// Times are read in the offset of `start`. A missing bound leaves that side
+/// of the day open.
+fn window_fits(
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+    duration: Duration,
+    (not_before, best_before): (Option<NaiveTime>, Option<NaiveTime>),
+) -> bool {
+    if not_before.is_none() && best_before.is_none() {
+        return true;
+    }
+
+    let local_start = start.naive_local();
+    let local_end = end.with_timezone(start.offset()).naive_local();
+    let crosses_midnight = matches!((not_before, best_before), (Some(open), Some(close)) if open > close);
+
+    // Start a day early so a window crossing midnight into the block is seen
+    let first_day = local_start.date() - Duration::days(1);
+    first_day.iter_days()
+        .take_while(|day| *day <= local_end.date())
+        .any(|day| {
+            let open = day.and_time(not_before.unwrap_or(NaiveTime::MIN));
+            let close = match best_before {
+                Some(close) if crosses_midnight => (day + Duration::days(1)).and_time(close),
+                Some(close) => day.and_time(close),
+                None => (day + Duration::days(1)).and_time(NaiveTime::MIN),
+            };
+            local_end.min(close) - local_start.max(open) >= duration
+        })
+}
+
+// ========================================================================
+// BLOCK SCORING
+// ========================================================================
+
+/// Score awarded to Available blocks, above anything the other terms can add
+const AVAILABLE_BONUS: u32 = 400;
+/// Points per capability dimension when the block offers exactly what is needed
+const CAPABILITY_FIT_POINTS: u32 = 40;
+/// Points for a block exactly as long as the task
+const LENGTH_FIT_POINTS: u32 = 100;
+/// Points for a block already holding a task with a shared tag, below
+/// `AVAILABLE_BONUS` so clustering never outweighs availability
+const SAME_TAG_POINTS: u32 = 60;
+
+/// Rank a block for a task; None when the task cannot go there
+/// 
+/// Higher is better. The score adds up:
+/// - `AVAILABLE_BONUS` for Available blocks, so they always outrank
+///   BusyButFlexible ones
+/// - up to `CAPABILITY_FIT_POINTS` per dimension (hands, eyes, speech,
+///   cognitive, device), losing half for each level the block offers beyond
+///   the task's need, so spare capacity is kept for demanding tasks
+/// - up to `LENGTH_FIT_POINTS` in proportion to how much of the block the
+///   task fills, so less time is wasted
+/// - `SAME_TAG_POINTS` when the task shares a tag with `block_tags`, the
+///   tags of tasks already placed in the block, so related tasks cluster.
+///   Pass `&[]` to score the block on its own.
+/// 
+/// Timing windows are not considered.
+pub fn score_block_for_task(
+    task: &impl SchedulableTask,
+    block: &TimeBlock,
+    current_location: Option<&Location>,
+    block_tags: &[String],
+) -> Option<u32> {
+    if !can_schedule_task_in_block(task, block, current_location, None) {
+        return None;
+    }
+
+    let availability = match block.availability {
+        AvailabilityKind::Available => AVAILABLE_BONUS,
+        _ => 0,
+    };
+
+    let surplus = [
+        block.capabilities.hands as u32 - task.min_hands() as u32,
+        block.capabilities.eyes as u32 - task.min_eyes() as u32,
+        block.capabilities.speech as u32 - task.min_speech() as u32,
+        block.capabilities.cognitive as u32 - task.min_cognitive() as u32,
+        block.capabilities.device as u32 - task.min_device() as u32,
+    ];
+    let capability_fit: u32 = surplus.iter()
+        .map(|levels| CAPABILITY_FIT_POINTS * 2_u32.saturating_sub(*levels) / 2)
+        .sum();
+
+    let length_fit = (block_fill_ratio(task.estimated_duration_minutes(), block) * LENGTH_FIT_POINTS as f32) as u32;
+
+    let clustering = if task.tags().iter().any(|tag| block_tags.contains(tag)) {
+        SAME_TAG_POINTS
+    } else {
+        0
+    };
+
+    Some(availability + capability_fit + length_fit + clustering)
+}
+
+/// The highest-scoring block for a task, earliest first on ties
+pub fn best_block_for_task<'a>(
+    blocks: &'a [TimeBlock],
+    task: &impl SchedulableTask,
+    current_location: Option<&Location>,
+) -> Option<&'a TimeBlock> {
+    blocks.iter()
+        .filter_map(|block| score_block_for_task(task, block, current_location, &[]).map(|score| (score, block)))
+        .max_by_key(|(score, block)| (*score, Reverse(block.start)))
+        .map(|(_, block)| block)
+}
+
 // ========================================================================
 // CANDIDATE SLOT FINDING
 // ========================================================================
@@ -233,7 +455,7 @@ where
     let candidates = vec![];
 
     for block in blocks {
-        if can_schedule_task_in_block(task, block, current_location) {
+        if can_schedule_task_in_block(task, block, current_location, None) {
             // For v1, return the whole block
             // In future versions, could slice the block into smaller candidates
             
@@ -249,6 +471,69 @@ where
     candidates
 }
 
+/// Find candidate slots of the task's duration, stepping through each block
+/// 
+/// For every block the task can be scheduled in, yields windows of
+/// `estimated_duration_minutes` starting at the block start and every
+/// `step_minutes` after, as long as the window ends within the block.
+/// With `timing`, each slot must also lie entirely within a timing window.
+/// Slots come out in block order, so sorted blocks give chronological slots.
+/// A non-positive step is treated as one minute.
+pub fn find_candidate_slots_stepped(
+    blocks: &[TimeBlock],
+    task: &impl SchedulableTask,
+    current_location: Option<&Location>,
+    timing: Option<&OccurrenceTimingSettings>,
+    step_minutes: i64,
+) -> Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    let duration = Duration::minutes(task.estimated_duration_minutes() as i64);
+    let step = Duration::minutes(step_minutes.max(1));
+
+    blocks.iter()
+        .filter(|block| can_schedule_task_in_block(task, block, current_location, timing))
+        .flat_map(|block| {
+            std::iter::successors(Some(block.start), move |start| Some(*start + step))
+                .take_while(move |start| *start + duration <= block.end)
+                .map(move |start| (start, start + duration))
+        })
+        .filter(|(start, end)| timing.is_none_or(|timing| fits_timing(*start, *end, duration, timing)))
+        .collect()
+}
+
+/// Like `find_candidate_slots_stepped`, for one rep of an occurrence
+/// 
+/// Only slots lying entirely within `rep_window` are kept, so each rep of a
+/// multi-rep task lands in its own part of the day. Get the windows from
+/// `TaskOccurrence::rep_windows`.
+pub fn find_rep_slots_stepped(
+    blocks: &[TimeBlock],
+    task: &impl SchedulableTask,
+    current_location: Option<&Location>,
+    (window_start, window_end): (DateTime<FixedOffset>, DateTime<FixedOffset>),
+    step_minutes: i64,
+) -> Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    find_candidate_slots_stepped(blocks, task, current_location, None, step_minutes)
+        .into_iter()
+        .filter(|(start, end)| window_start <= *start && *end <= window_end)
+        .collect()
+}
+
+// ========================================================================
+// BLOCK FILL
+// ========================================================================
+
+/// Fraction of a block taken up by a task of the given duration
+/// 
+/// Clamped to 1.0 for tasks longer than the block; an empty block counts as full.
+/// Lets the day planner show e.g. "fills 50% of your lunch".
+pub fn block_fill_ratio(task_duration_minutes: u32, block: &TimeBlock) -> f32 {
+    let block_minutes = (block.end - block.start).num_minutes();
+    if block_minutes <= 0 {
+        return 1.0;
+    }
+    (task_duration_minutes as f32 / block_minutes as f32).min(1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,8 +541,9 @@ mod tests {
         AvailabilityKind, AvailabilityLevel, CapabilitySet, DeviceAccess, 
         LocationConstraint, Mobility, UnavailableReason,
     };
+    use crate::domain::entities::task::RepTimingSettings;
     use crate::domain::entities::user::{Location, GeoCoordinates};
-    use chrono::{FixedOffset, TimeZone};
+    use chrono::{FixedOffset, TimeZone, Timelike};
 
     // Test task implementation
     struct FakeTask {
@@ -269,6 +555,7 @@ mod tests {
         min_cognitive: AvailabilityLevel,
         min_device: DeviceAccess,
         allowed_mobility: Vec<Mobility>,
+        tags: Vec<String>,
     }
 
     impl SchedulableTask for FakeTask {
@@ -303,6 +590,10 @@ mod tests {
         fn allowed_mobility(&self) -> Vec<Mobility> {
             self.allowed_mobility.clone()
         }
+
+        fn tags(&self) -> &[String] {
+            &self.tags
+        }
     }
 
     impl FakeTask {
@@ -316,6 +607,7 @@ mod tests {
                 min_cognitive: AvailabilityLevel::None,
                 min_device: DeviceAccess::None,
                 allowed_mobility: vec![],
+                tags: vec![],
             }
         }
     }
@@ -351,7 +643,7 @@ mod tests {
             60,
         );
 
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -364,7 +656,7 @@ mod tests {
             60,
         );
 
-        assert!(can_schedule_task_in_block(&task, &block, None));
+        assert!(can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -377,7 +669,7 @@ mod tests {
             60,
         );
 
-        assert!(can_schedule_task_in_block(&task, &block, None));
+        assert!(can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -390,7 +682,21 @@ mod tests {
             60,
         );
 
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
+    }
+
+    #[test]
+    fn test_micro_task_threshold_follows_config() {
+        let task = FakeTask::simple(15);
+        let busy = make_block(AvailabilityKind::BusyButFlexible, CapabilitySet::free(), LocationConstraint::Any, 60);
+
+        // The default configuration allows 15 minutes
+        let default = BusyFlexLimits::from_levels(15, 1, 1, 1);
+        assert!(can_schedule_task_in_block_with(&task, &busy, None, None, &default));
+
+        // SCHEDULE_BUSY_FLEX_MAX_MINUTES=10 turns it away
+        let stricter = BusyFlexLimits::from_levels(10, 1, 1, 1);
+        assert!(!can_schedule_task_in_block_with(&task, &busy, None, None, &stricter));
     }
 
     #[test]
@@ -405,7 +711,7 @@ mod tests {
             60,
         );
 
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -420,7 +726,7 @@ mod tests {
             60,
         );
 
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -435,7 +741,7 @@ mod tests {
             60,
         );
 
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -450,7 +756,7 @@ mod tests {
             60,
         );
 
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -467,7 +773,7 @@ mod tests {
             LocationConstraint::Any,
             60,
         );
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
 
         // Block with Full hands should accept
         let block = make_block(
@@ -476,7 +782,7 @@ mod tests {
             LocationConstraint::Any,
             60,
         );
-        assert!(can_schedule_task_in_block(&task, &block, None));
+        assert!(can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -493,7 +799,7 @@ mod tests {
             LocationConstraint::Any,
             60,
         );
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
 
         // Block with Computer should accept
         let block = make_block(
@@ -502,7 +808,7 @@ mod tests {
             LocationConstraint::Any,
             60,
         );
-        assert!(can_schedule_task_in_block(&task, &block, None));
+        assert!(can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -524,8 +830,8 @@ mod tests {
             LocationConstraint::MustBeKnown,
             60,
         );
-        assert!(!can_schedule_task_in_block(&task, &block, None));
-        assert!(can_schedule_task_in_block(&task, &block, Some(&location)));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
+        assert!(can_schedule_task_in_block(&task, &block, Some(&location), None));
 
         // MustBeUnknown should reject Some
         let block = make_block(
@@ -534,8 +840,15 @@ mod tests {
             LocationConstraint::MustBeUnknown,
             60,
         );
-        assert!(can_schedule_task_in_block(&task, &block, None));
-        assert!(!can_schedule_task_in_block(&task, &block, Some(&location)));
+        assert!(can_schedule_task_in_block(&task, &block, None, None));
+        assert!(!can_schedule_task_in_block(&task, &block, Some(&location), None));
+
+        // MustBeNear accepts a nearby location, but never during busy-but-flexible
+        let near = LocationConstraint::MustBeNear { center: coords, radius_meters: 5_000.0 };
+        let block = make_block(AvailabilityKind::Available, CapabilitySet::free(), near.clone(), 60);
+        assert!(can_schedule_task_in_block(&task, &block, Some(&location), None));
+        let block = make_block(AvailabilityKind::BusyButFlexible, CapabilitySet::free(), near, 60);
+        assert!(!can_schedule_task_in_block(&task, &block, Some(&location), None));
     }
 
     #[test]
@@ -552,7 +865,7 @@ mod tests {
             LocationConstraint::Any,
             60,
         );
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
 
         // Block with Stationary should accept
         let block = make_block(
@@ -561,7 +874,7 @@ mod tests {
             LocationConstraint::Any,
             60,
         );
-        assert!(can_schedule_task_in_block(&task, &block, None));
+        assert!(can_schedule_task_in_block(&task, &block, None, None));
     }
 
     #[test]
@@ -575,7 +888,7 @@ mod tests {
             LocationConstraint::Any,
             20,
         );
-        assert!(!can_schedule_task_in_block(&task, &block, None));
+        assert!(!can_schedule_task_in_block(&task, &block, None, None));
 
         // Block long enough (60 minutes)
         let block = make_block(
@@ -584,6 +897,222 @@ mod tests {
             LocationConstraint::Any,
             60,
         );
-        assert!(can_schedule_task_in_block(&task, &block, None));
+        assert!(can_schedule_task_in_block(&task, &block, None, None));
+    }
+
+    fn timing(not_before: Option<u32>, best_before: Option<u32>) -> OccurrenceTimingSettings {
+        OccurrenceTimingSettings {
+            duration: None,
+            not_before: not_before.map(|hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap()),
+            best_before: best_before.map(|hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap()),
+            rep_timing_settings: None,
+        }
+    }
+
+    /// Available block from `start_hour`, lasting `hours`, in the test offset
+    fn block_at(start_hour: i64, hours: i64) -> TimeBlock {
+        let mut block = make_block(AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any, hours * 60);
+        let shift = chrono::Duration::hours(start_hour - 9);
+        block.start += shift;
+        block.end += shift;
+        block
+    }
+
+    #[test]
+    fn test_timing_window_rejects_block_before_not_before() {
+        let task = FakeTask::simple(30);
+        let after_six = timing(Some(18), None);
+
+        // 09:00-12:00 ends long before 18:00
+        assert!(!can_schedule_task_in_block(&task, &block_at(9, 3), None, Some(&after_six)));
+        // 17:00-19:00 leaves an hour after 18:00
+        assert!(can_schedule_task_in_block(&task, &block_at(17, 2), None, Some(&after_six)));
+        // 17:00-18:15 leaves only 15 minutes
+        let mut short = block_at(17, 1);
+        short.end += chrono::Duration::minutes(15);
+        assert!(!can_schedule_task_in_block(&task, &short, None, Some(&after_six)));
+
+        // best_before closes the window
+        let morning_only = timing(None, Some(10));
+        assert!(can_schedule_task_in_block(&task, &block_at(9, 3), None, Some(&morning_only)));
+        assert!(!can_schedule_task_in_block(&task, &block_at(10, 3), None, Some(&morning_only)));
+
+        // No timing means no window check
+        assert!(can_schedule_task_in_block(&task, &block_at(9, 3), None, None));
+    }
+
+    #[test]
+    fn test_timing_window_crossing_midnight_and_per_rep() {
+        let task = FakeTask::simple(30);
+
+        // 23:00-01:00 reaches into the early hours of the next day
+        let late = timing(Some(23), Some(1));
+        assert!(can_schedule_task_in_block(&task, &block_at(0, 2), None, Some(&late)));
+        assert!(can_schedule_task_in_block(&task, &block_at(22, 2), None, Some(&late)));
+        assert!(!can_schedule_task_in_block(&task, &block_at(9, 3), None, Some(&late)));
+
+        // Fitting one rep's window is enough
+        let mut twice = timing(None, None);
+        let rep = |rep_index, window: OccurrenceTimingSettings| RepTimingSettings {
+            rep_index,
+            not_before: window.not_before,
+            best_before: window.best_before,
+        };
+        twice.rep_timing_settings = Some(vec![
+            rep(0, timing(Some(7), Some(8))),
+            rep(1, timing(Some(19), Some(20))),
+        ]);
+        assert!(can_schedule_task_in_block(&task, &block_at(18, 3), None, Some(&twice)));
+        assert!(!can_schedule_task_in_block(&task, &block_at(9, 3), None, Some(&twice)));
+
+        // Stepped slots are clipped to the window too
+        let slots = find_candidate_slots_stepped(&[block_at(17, 3)], &task, None, Some(&timing(Some(18), Some(19))), 30);
+        let hours: Vec<(u32, u32)> = slots.iter().map(|(start, _)| (start.hour(), start.minute())).collect();
+        assert_eq!(hours, vec![(18, 0), (18, 30)]);
+    }
+
+    #[test]
+    fn test_find_candidate_slots_stepped() {
+        let task = FakeTask::simple(30);
+        let block = make_block(AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any, 60);
+
+        let starts: Vec<u32> = find_candidate_slots_stepped(std::slice::from_ref(&block), &task, None, None, 15)
+            .into_iter()
+            .map(|(start, end)| {
+                assert_eq!(end - start, chrono::Duration::minutes(30));
+                start.minute()
+            })
+            .collect();
+        assert_eq!(starts, vec![0, 15, 30]);
+
+        // Blocks the task cannot use yield nothing
+        let asleep = make_block(
+            AvailabilityKind::Unavailable(UnavailableReason::Sleep),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            60,
+        );
+        assert!(find_candidate_slots_stepped(&[asleep], &task, None, None, 15).is_empty());
+    }
+
+    #[test]
+    fn test_available_block_outranks_busy_flex_for_micro_task() {
+        let task = FakeTask::simple(10);
+        let busy = make_block(AvailabilityKind::BusyButFlexible, CapabilitySet::free(), LocationConstraint::Any, 10);
+        let available = make_block(AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any, 120);
+
+        // The busy block fits the task exactly, yet loses
+        let busy_score = score_block_for_task(&task, &busy, None, &[]).unwrap();
+        let available_score = score_block_for_task(&task, &available, None, &[]).unwrap();
+        assert!(available_score > busy_score);
+
+        let blocks = [busy, available.clone()];
+        assert_eq!(best_block_for_task(&blocks, &task, None), Some(&available));
+    }
+
+    #[test]
+    fn test_block_score_prefers_tight_fit() {
+        let mut task = FakeTask::simple(30);
+        task.min_device = DeviceAccess::PhoneOnly;
+
+        let mut phone = CapabilitySet::free();
+        phone.device = DeviceAccess::PhoneOnly;
+        let phone_block = make_block(AvailabilityKind::Available, phone, LocationConstraint::Any, 60);
+        let computer_block = make_block(AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any, 60);
+        assert!(score_block_for_task(&task, &phone_block, None, &[]) > score_block_for_task(&task, &computer_block, None, &[]));
+
+        let snug = make_block(AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any, 30);
+        let long = make_block(AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any, 240);
+        assert!(score_block_for_task(&task, &snug, None, &[]) > score_block_for_task(&task, &long, None, &[]));
+
+        // Unschedulable blocks get no score
+        let asleep = make_block(
+            AvailabilityKind::Unavailable(UnavailableReason::Sleep),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            60,
+        );
+        assert_eq!(score_block_for_task(&task, &asleep, None, &[]), None);
+        assert_eq!(best_block_for_task(&[asleep], &task, None), None);
+    }
+
+    #[test]
+    fn test_block_score_favours_shared_tags() {
+        let mut task = FakeTask::simple(30);
+        task.tags = vec!["errands".to_string()];
+        let block = make_block(AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any, 60);
+
+        let alone = score_block_for_task(&task, &block, None, &[]).unwrap();
+        let with_errand = score_block_for_task(&task, &block, None, &["errands".to_string()]).unwrap();
+        let with_other = score_block_for_task(&task, &block, None, &["work".to_string()]).unwrap();
+        assert_eq!(with_errand, alone + SAME_TAG_POINTS);
+        assert_eq!(with_other, alone);
+
+        // A soft preference: a busy block holding an errand still loses
+        let busy = make_block(AvailabilityKind::BusyButFlexible, CapabilitySet::free(), LocationConstraint::Any, 15);
+        task.duration_minutes = 10;
+        let busy_with_errand = score_block_for_task(&task, &busy, None, &["errands".to_string()]).unwrap();
+        assert!(busy_with_errand < score_block_for_task(&task, &block, None, &[]).unwrap());
+    }
+
+    #[test]
+    fn test_block_fill_ratio() {
+        let block = make_block(AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any, 60);
+
+        assert_eq!(block_fill_ratio(30, &block), 0.5);
+        assert_eq!(block_fill_ratio(60, &block), 1.0);
+        assert_eq!(block_fill_ratio(90, &block), 1.0);
+    }
+
+    #[cfg(feature = "log")]
+    mod logging {
+        use super::*;
+        use std::sync::{Mutex, Once};
+
+        /// Records every message; installed once for the whole test binary
+        struct CapturingLogger;
+
+        static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+        static INSTALL: Once = Once::new();
+
+        impl log::Log for CapturingLogger {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                MESSAGES.lock().unwrap().push(record.args().to_string());
+            }
+
+            fn flush(&self) {}
+        }
+
+        fn install() {
+            INSTALL.call_once(|| {
+                log::set_logger(&CapturingLogger).unwrap();
+                log::set_max_level(log::LevelFilter::Trace);
+            });
+        }
+
+        #[test]
+        fn test_rejected_computer_task_traces_device_mismatch() {
+            install();
+
+            let mut task = FakeTask::simple(10);
+            task.min_device = DeviceAccess::Computer;
+
+            let mut caps = CapabilitySet::free();
+            caps.device = DeviceAccess::PhoneOnly;
+            let block = make_block(AvailabilityKind::Available, caps, LocationConstraint::Any, 60);
+
+            assert!(!can_schedule_task_in_block(&task, &block, None, None));
+
+            let messages = MESSAGES.lock().unwrap();
+            assert!(
+                messages.iter().any(|m| m.contains("device mismatch") && m.contains("PhoneOnly")),
+                "no device mismatch trace in {:?}",
+                *messages
+            );
+        }
     }
 }
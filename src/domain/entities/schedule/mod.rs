@@ -11,6 +11,7 @@
 //! - **Location Constraints**: Require or restrict tasks based on user location
 //! - **Priority-Based Conflict Resolution**: Handle overlapping rules intelligently
 //! - **Overnight Support**: Handle rules that span midnight
+//! - **Location Conflicts**: Flag placed tasks that need two places at once
 //! 
 //! ## Example Usage
 //! 
@@ -62,6 +63,13 @@ pub mod expansion;
 /// Task matching: determine if tasks fit in time blocks
 pub mod matching;
 
+/// Placements: tasks at concrete times, and conflicts between them
+pub mod placement;
+
+/// Sharing: JSON import/export of whole templates
+#[cfg(feature = "json")]
+mod sharing;
+
 // Integration tests
 #[cfg(test)]
 mod tests;
@@ -74,6 +82,8 @@ mod tests;
 pub use types::{
     AvailabilityKind,
     AvailabilityLevel,
+    BusyFlexLimits,
+    CapabilityError,
     CapabilitySet,
     DeviceAccess,
     LocationConstraint,
@@ -86,10 +96,17 @@ pub use types::{
 };
 
 // Template types
-pub use template::{RecurringRule, ScheduleTemplate};
+pub use template::{DateOverride, OverrideBlock, RecurringRule, RuleConflict, ScheduleTemplate};
 
 // Expansion
-pub use expansion::{expand_template, TimeBlock};
+pub use expansion::{coalesce_blocks, expand_template, expand_template_tz, expand_template_with_mode, expand_template_with_report, find_gaps, free_gaps, resolve_block_winner, total_available_minutes, BlockContender, ConflictResolution, ExpansionMode, ResolutionReason, TimeBlock};
 
 // Matching
-pub use matching::{can_schedule_task_in_block, find_candidate_slots, SchedulableTask};
+pub use matching::{best_block_for_task, block_fill_ratio, can_schedule_task_in_block, can_schedule_task_in_block_with, find_candidate_slots, find_candidate_slots_stepped, find_rep_slots_stepped, score_block_for_task, SchedulableTask};
+
+// Placement
+pub use placement::{detect_location_conflicts, Placement};
+
+// Sharing
+#[cfg(feature = "json")]
+pub use sharing::ScheduleTemplateError;
@@ -0,0 +1,122 @@
+use chrono::{DateTime, FixedOffset};
+use crate::domain::entities::user::Location;
+
+// ========================================================================
+// PLACEMENT
+// ========================================================================
+
+/// A task placed at a concrete time
+/// 
+/// `location` is where the task has to be done, or None for location-free
+/// tasks that can happen anywhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placement {
+    pub start: DateTime<FixedOffset>,
+    pub end: DateTime<FixedOffset>,
+    pub location: Option<Location>,
+}
+
+impl Placement {
+    /// Check if the two time windows share any time (touching ends do not)
+    pub fn overlaps(&self, other: &Placement) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+// ========================================================================
+// CONFLICT DETECTION
+// ========================================================================
+
+/// Find pairs of placements that would require being in two places at once
+/// 
+/// Returns index pairs `(i, j)` with `i < j` for every two placements whose
+/// windows overlap and whose required locations differ. Location-free
+/// placements never conflict.
+pub fn detect_location_conflicts(placements: &[Placement]) -> Vec<(usize, usize)> {
+    let mut conflicts = vec![];
+
+    for (i, first) in placements.iter().enumerate() {
+        let Some(first_location) = &first.location else {
+            continue;
+        };
+
+        for (j, second) in placements.iter().enumerate().skip(i + 1) {
+            let Some(second_location) = &second.location else {
+                continue;
+            };
+
+            if first_location != second_location && first.overlaps(second) {
+                conflicts.push((i, j));
+            }
+        }
+    }
+
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::user::GeoCoordinates;
+    use chrono::TimeZone;
+
+    fn location(name: &str, latitude: f64) -> Location {
+        Location::new(
+            Some(name.to_string()),
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(latitude, 2.35).unwrap(),
+        ).unwrap()
+    }
+
+    fn placement(start_hour: u32, end_hour: u32, location: Option<Location>) -> Placement {
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        Placement {
+            start: tz.with_ymd_and_hms(2026, 2, 10, start_hour, 0, 0).unwrap(),
+            end: tz.with_ymd_and_hms(2026, 2, 10, end_hour, 0, 0).unwrap(),
+            location,
+        }
+    }
+
+    #[test]
+    fn test_overlapping_different_locations_conflict() {
+        let placements = vec![
+            placement(9, 11, Some(location("Office", 48.87))),
+            placement(10, 12, Some(location("Gym", 48.85))),
+        ];
+
+        assert_eq!(detect_location_conflicts(&placements), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_overlapping_same_location_does_not_conflict() {
+        let office = location("Office", 48.87);
+        let placements = vec![
+            placement(9, 11, Some(office.clone())),
+            placement(10, 12, Some(office)),
+        ];
+
+        assert!(detect_location_conflicts(&placements).is_empty());
+    }
+
+    #[test]
+    fn test_non_overlapping_times_do_not_conflict() {
+        let placements = vec![
+            placement(9, 10, Some(location("Office", 48.87))),
+            placement(10, 11, Some(location("Gym", 48.85))),
+        ];
+
+        assert!(detect_location_conflicts(&placements).is_empty());
+    }
+
+    #[test]
+    fn test_location_free_placements_never_conflict() {
+        let placements = vec![
+            placement(9, 11, Some(location("Office", 48.87))),
+            placement(9, 11, None),
+            placement(10, 12, Some(location("Gym", 48.85))),
+        ];
+
+        assert_eq!(detect_location_conflicts(&placements), vec![(0, 2)]);
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+use super::template::ScheduleTemplate;
+
+// ========================================================================
+// ERRORS
+// ========================================================================
+
+/// Why a shared schedule template file could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleTemplateError {
+    /// The file is not JSON shaped like a schedule template
+    Malformed(String),
+    /// The file parsed, but describes a template the app could not have built
+    Invalid(String),
+}
+
+impl fmt::Display for ScheduleTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleTemplateError::Malformed(reason) => {
+                write!(f, "Invalid schedule template file: {}", reason)
+            }
+            ScheduleTemplateError::Invalid(reason) => {
+                write!(f, "Invalid schedule template: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScheduleTemplateError {}
+
+// ========================================================================
+// IMPORT / EXPORT
+// ========================================================================
+
+impl ScheduleTemplate {
+    /// The whole template as pretty-printed JSON, for sharing
+    /// 
+    /// # Panics
+    /// 
+    /// Never in practice: a template holds only plain data (no maps, no
+    /// custom serializers), which `serde_json` always encodes.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("schedule templates only hold serializable values")
+    }
+
+    /// Read a template written by `to_json`
+    ///
+    /// The parsed template goes through `revalidated`, so a hand-edited file
+    /// fails here rather than producing a template the app could not have
+    /// built. Errors name the offending rule or override by position.
+    pub fn from_json(json: &str) -> Result<ScheduleTemplate, ScheduleTemplateError> {
+        let file: ScheduleTemplate = serde_json::from_str(json)
+            .map_err(|e| ScheduleTemplateError::Malformed(e.to_string()))?;
+        file.revalidated().map_err(ScheduleTemplateError::Invalid)
+    }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::schedule::template::{DateOverride, OverrideBlock, RecurringRule};
+    use crate::domain::entities::schedule::types::{AvailabilityKind, CapabilitySet, DeviceAccess, LocationConstraint, Mobility};
+    use chrono::{NaiveDate, NaiveTime, Weekday};
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    fn work_week_with_lunch() -> ScheduleTemplate {
+        let weekdays = vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+        let work = RecurringRule::new(
+            weekdays.clone(), time(9), time(17),
+            AvailabilityKind::BusyButFlexible, CapabilitySet::free(), LocationConstraint::Any,
+            Some("Work".to_string()), 0,
+        ).unwrap()
+        .with_week_cadence(2, NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+        .unwrap();
+        let lunch = RecurringRule::new(
+            weekdays, time(12), time(13),
+            AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any,
+            Some("Lunch".to_string()), 10,
+        ).unwrap()
+        .with_validity(NaiveDate::from_ymd_opt(2026, 1, 1), None)
+        .unwrap();
+        let holiday = DateOverride::new(NaiveDate::from_ymd_opt(2026, 5, 1).unwrap(), AvailabilityKind::Available, Some("Holiday".to_string()))
+            .with_blocks(vec![OverrideBlock::new(time(19), time(0), AvailabilityKind::BusyButFlexible, None).unwrap()])
+            .unwrap();
+
+        ScheduleTemplate::new("Work Week".to_string(), "America/New_York".to_string(), vec![work, lunch])
+            .unwrap()
+            .with_date_overrides(vec![holiday])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let template = work_week_with_lunch();
+        assert_eq!(ScheduleTemplate::from_json(&template.to_json()), Ok(template));
+    }
+
+    #[test]
+    fn test_tampered_files_are_rejected() {
+        let tamper = |edit: &dyn Fn(&mut serde_json::Value)| {
+            let mut json: serde_json::Value = serde_json::from_str(&work_week_with_lunch().to_json()).unwrap();
+            edit(&mut json);
+            match ScheduleTemplate::from_json(&json.to_string()) {
+                Err(ScheduleTemplateError::Invalid(reason)) => Err(reason),
+                other => other.map_err(|e| panic!("expected a validation error, got {:?}", e)),
+            }
+        };
+
+        // An override block that ends before it starts
+        let result = tamper(&|json| json["date_overrides"][0]["blocks"][0]["end"] = "18:00:00".into());
+        assert!(result.unwrap_err().starts_with("Override for 2026-05-01: Override block must end after it starts"));
+
+        // A rule with no days
+        let result = tamper(&|json| json["rules"][1]["days"] = serde_json::json!([]));
+        assert!(result.unwrap_err().starts_with("Rule 2:"));
+
+        // A validity window that ends before it starts
+        let result = tamper(&|json| json["rules"][1]["valid_until"] = "2025-12-31".into());
+        assert!(result.unwrap_err().starts_with("Rule 2: Rule validity ends"));
+
+        // A rule that starts and ends at the same time
+        let result = tamper(&|json| json["rules"][1]["end"] = "12:00:00".into());
+        assert!(result.unwrap_err().starts_with("Rule 2: RecurringRule must not start and end at the same time"));
+
+        // A week cadence of zero
+        let result = tamper(&|json| json["rules"][0]["week_cadence"][0] = 0.into());
+        assert!(result.unwrap_err().starts_with("Rule 1: Week cadence"));
+
+        // Capabilities no one has while driving
+        let result = tamper(&|json| {
+            json["rules"][0]["capabilities"]["mobility"] = serde_json::to_value(Mobility::Driving).unwrap();
+            json["rules"][0]["capabilities"]["device"] = serde_json::to_value(DeviceAccess::Computer).unwrap();
+        });
+        assert!(result.unwrap_err().starts_with("Rule 1:"));
+
+        // A blank name, and a file that is not a template at all
+        assert!(tamper(&|json| json["name"] = " ".into()).is_err());
+        assert!(matches!(ScheduleTemplate::from_json("[]"), Err(ScheduleTemplateError::Malformed(_))));
+    }
+}
@@ -1,4 +1,10 @@
-use chrono::{NaiveTime, Weekday};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use chrono::{Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike, Weekday};
+use crate::domain::entities::user::Location;
+use super::expansion::expand_template;
+use super::matching::SchedulableTask;
 use super::types::{AvailabilityKind, CapabilitySet, LocationConstraint};
 
 // ========================================================================
@@ -8,10 +14,12 @@ use super::types::{AvailabilityKind, CapabilitySet, LocationConstraint};
 /// Represents a recurring time block in a weekly schedule template
 /// 
 /// # Overnight Rules
-/// If `end <= start`, the rule spans midnight into the next day.
+/// If `end < start`, the rule spans midnight into the next day; `new`
+/// rejects `end == start`.
 /// For example, a rule with start=23:00 and end=07:00 runs from 11 PM
 /// through midnight into 7 AM the next day.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RecurringRule {
     /// Days of the week this rule applies to
     pub days: Vec<Weekday>,
@@ -19,7 +27,8 @@ pub struct RecurringRule {
     /// Start time (local time-of-day)
     pub start: NaiveTime,
     
-    /// End time (local time-of-day, can be <= start for overnight rules)
+    /// End time (local time-of-day, before start for overnight rules; never
+    /// equal to start)
     pub end: NaiveTime,
     
     /// Availability status during this period
@@ -36,15 +45,114 @@ pub struct RecurringRule {
     
     /// Priority for conflict resolution (higher wins)
     pub priority: i16,
+
+    /// Apply only every N weeks, counted from the (Monday-based) week
+    /// containing the reference date, in the template's local time; None
+    /// applies every week
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub week_cadence: Option<(u8, NaiveDate)>,
+
+    /// First local date the rule can start on (None = no lower bound)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub valid_from: Option<NaiveDate>,
+
+    /// Last local date the rule can start on, inclusive (None = no upper bound)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub valid_until: Option<NaiveDate>,
 }
 
 impl RecurringRule {
     /// Check if this rule represents an overnight period
     pub fn is_overnight(&self) -> bool {
-        self.end <= self.start
+        self.end < self.start
+    }
+
+    /// Check if this rule starts on the given weekday
+    pub fn applies_on_weekday(&self, weekday: Weekday) -> bool {
+        self.days.contains(&weekday)
+    }
+
+    /// Check if the week containing `date` is one the rule's cadence applies to
+    /// 
+    /// Always true without a cadence, and never true with a cadence of 0
+    /// (which `with_week_cadence` rejects). Weeks run Monday to Sunday, and
+    /// an occurrence belongs to the week of the local day it starts on.
+    pub fn applies_in_week(&self, date: NaiveDate) -> bool {
+        let Some((every_n_weeks, reference)) = self.week_cadence else {
+            return true;
+        };
+        if every_n_weeks == 0 {
+            return false;
+        }
+        let monday = |day: NaiveDate| day - Duration::days(day.weekday().num_days_from_monday() as i64);
+        let weeks = (monday(date) - monday(reference)).num_days() / 7;
+        weeks.rem_euclid(every_n_weeks as i64) == 0
+    }
+
+    /// Check if `date` falls within the rule's validity window
+    pub fn is_valid_on(&self, date: NaiveDate) -> bool {
+        self.valid_from.is_none_or(|from| from <= date)
+            && self.valid_until.is_none_or(|until| date <= until)
+    }
+
+    /// Check if this rule applies on every matching weekday, with neither a
+    /// week cadence nor a validity window
+    pub fn is_always_active(&self) -> bool {
+        self.week_cadence.is_none() && self.valid_from.is_none() && self.valid_until.is_none()
+    }
+
+    /// Check if this rule starts on the given calendar date
+    pub fn starts_on_date(&self, date: NaiveDate) -> bool {
+        self.applies_on_weekday(date.weekday()) && self.applies_in_week(date) && self.is_valid_on(date)
+    }
+
+    /// Check if any part of this rule falls on the given calendar date
+    /// 
+    /// True when the rule starts on that date, or when an overnight rule
+    /// started the day before and spills past midnight into this date. An
+    /// overnight rule ending exactly at midnight has no spill.
+    pub fn applies_on_date(&self, date: NaiveDate) -> bool {
+        if self.starts_on_date(date) {
+            return true;
+        }
+        self.is_overnight()
+            && self.end > NaiveTime::MIN
+            && date.pred_opt().is_some_and(|previous| self.starts_on_date(previous))
+    }
+
+    /// Restrict the rule to every `every_n_weeks`-th week, aligned on the
+    /// week containing the local date `reference` (e.g. 2 for an
+    /// alternating shift)
+    pub fn with_week_cadence(mut self, every_n_weeks: u8, reference: NaiveDate) -> Result<Self, String> {
+        if every_n_weeks == 0 {
+            return Err("Week cadence must be at least 1".to_string());
+        }
+        self.week_cadence = Some((every_n_weeks, reference));
+        Ok(self)
+    }
+
+    /// Limit the rule to start dates within `[valid_from, valid_until]`
+    /// (e.g. summer hours); either bound may be open
+    /// 
+    /// An overnight rule starting on `valid_until` still runs into the next
+    /// morning.
+    pub fn with_validity(mut self, valid_from: Option<NaiveDate>, valid_until: Option<NaiveDate>) -> Result<Self, String> {
+        if let (Some(from), Some(until)) = (valid_from, valid_until) {
+            if until < from {
+                return Err(format!("Rule validity ends ({}) before it starts ({})", until, from));
+            }
+        }
+        self.valid_from = valid_from;
+        self.valid_until = valid_until;
+        Ok(self)
     }
 
     /// Create a new recurring rule with validation
+    /// 
+    /// An `end` before `start` makes an overnight rule. Equal times are
+    /// rejected: they could mean either nothing or a full day. The capability
+    /// set must be one a person can have (see `CapabilitySet::validate`).
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         days: Vec<Weekday>,
         start: NaiveTime,
@@ -58,6 +166,20 @@ impl RecurringRule {
         if days.is_empty() {
             return Err("RecurringRule must have at least one day".to_string());
         }
+        if start == end {
+            return Err(format!("RecurringRule must not start and end at the same time ({})", start));
+        }
+        capabilities.validate().map_err(|e| e.to_string())?;
+        if let LocationConstraint::MustBeNear { radius_meters, .. } = location_constraint {
+            if !radius_meters.is_finite() || radius_meters < 0.0 {
+                return Err(format!("MustBeNear radius must be a non-negative distance, got {}", radius_meters));
+            }
+        }
+        if let LocationConstraint::MustBeWithin { radius_meters, .. } = location_constraint {
+            if !radius_meters.is_finite() || radius_meters < 0.0 {
+                return Err(format!("MustBeWithin radius must be a non-negative distance, got {}", radius_meters));
+            }
+        }
 
         Ok(Self {
             days,
@@ -68,8 +190,176 @@ impl RecurringRule {
             location_constraint,
             label,
             priority,
+            week_cadence: None,
+            valid_from: None,
+            valid_until: None,
         })
     }
+
+    /// Create a rule covering every day of the week except `excluded_days`
+    /// 
+    /// Sugar over `new` for patterns like "every day 9-17 except weekends".
+    /// Fails if every weekday is excluded.
+    #[allow(clippy::too_many_arguments)]
+    pub fn daily_except(
+        excluded_days: &[Weekday],
+        start: NaiveTime,
+        end: NaiveTime,
+        availability: AvailabilityKind,
+        capabilities: CapabilitySet,
+        location_constraint: LocationConstraint,
+        label: Option<String>,
+        priority: i16,
+    ) -> Result<Self, String> {
+        let days = [
+            Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu,
+            Weekday::Fri, Weekday::Sat, Weekday::Sun,
+        ]
+        .into_iter()
+        .filter(|day| !excluded_days.contains(day))
+        .collect();
+
+        Self::new(days, start, end, availability, capabilities, location_constraint, label, priority)
+    }
+
+    /// Run the rule through `new` and its builder steps again
+    /// 
+    /// For rules that did not come from them (deserialized or hand-edited).
+    pub fn revalidated(&self) -> Result<Self, String> {
+        let mut rule = Self::new(
+            self.days.clone(),
+            self.start,
+            self.end,
+            self.availability.clone(),
+            self.capabilities.clone(),
+            self.location_constraint.clone(),
+            self.label.clone(),
+            self.priority,
+        )?
+        .with_validity(self.valid_from, self.valid_until)?;
+        if let Some((every_n_weeks, reference)) = self.week_cadence {
+            rule = rule.with_week_cadence(every_n_weeks, reference)?;
+        }
+        Ok(rule)
+    }
+}
+
+// ========================================================================
+// DATE OVERRIDE
+// ========================================================================
+
+/// Replaces the weekly pattern for one whole calendar day (e.g., a holiday)
+/// 
+/// The day runs from local midnight to the next local midnight in the
+/// template's timezone. Within it, recurring rules are ignored and the
+/// override alone defines availability: `availability` covers the day,
+/// except where one of its `blocks` says otherwise.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateOverride {
+    pub date: NaiveDate,
+    pub availability: AvailabilityKind,
+    pub label: Option<String>,
+    /// Time windows with their own availability, sorted and non-overlapping
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub blocks: Vec<OverrideBlock>,
+}
+
+impl DateOverride {
+    pub fn new(date: NaiveDate, availability: AvailabilityKind, label: Option<String>) -> Self {
+        Self { date, availability, label, blocks: Vec::new() }
+    }
+
+    /// Attach time blocks, replacing any existing ones
+    /// 
+    /// Fails if two blocks overlap.
+    pub fn with_blocks(mut self, mut blocks: Vec<OverrideBlock>) -> Result<Self, String> {
+        blocks.sort_by_key(|block| block.start);
+        if let Some(pair) = blocks.windows(2).find(|pair| pair[1].offsets().0 < pair[0].offsets().1) {
+            return Err(format!(
+                "Override blocks on {} overlap: {}-{} and {}-{}",
+                self.date, pair[0].start, pair[0].end, pair[1].start, pair[1].end,
+            ));
+        }
+        self.blocks = blocks;
+        Ok(self)
+    }
+
+    /// Run the override and each of its blocks through their constructors again
+    pub fn revalidated(&self) -> Result<Self, String> {
+        let blocks = self.blocks.iter()
+            .map(|block| OverrideBlock::new(block.start, block.end, block.availability.clone(), block.label.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(self.date, self.availability.clone(), self.label.clone()).with_blocks(blocks)
+    }
+}
+
+/// A time window within a date override (e.g., "available 19:00-22:00")
+/// 
+/// Unlike recurring rules, a block stays within its day: an `end` of
+/// midnight means the end of the day.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverrideBlock {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub availability: AvailabilityKind,
+    pub label: Option<String>,
+}
+
+impl OverrideBlock {
+    /// Create a block with validation
+    pub fn new(
+        start: NaiveTime,
+        end: NaiveTime,
+        availability: AvailabilityKind,
+        label: Option<String>,
+    ) -> Result<Self, String> {
+        let block = Self { start, end, availability, label };
+        let (start_offset, end_offset) = block.offsets();
+        if start_offset >= end_offset {
+            return Err(format!("Override block must end after it starts ({}-{})", start, end));
+        }
+        Ok(block)
+    }
+
+    /// Start and end as offsets from midnight, with an `end` of midnight
+    /// read as the end of the day
+    pub(crate) fn offsets(&self) -> (Duration, Duration) {
+        let end = if self.end == NaiveTime::MIN {
+            Duration::days(1)
+        } else {
+            self.end - NaiveTime::MIN
+        };
+        (self.start - NaiveTime::MIN, end)
+    }
+}
+
+// ========================================================================
+// RULE CONFLICT
+// ========================================================================
+
+/// Two equal-priority rules that overlap on the same weekdays
+///
+/// Expansion still resolves the overlap with its tie-breaker, but the result
+/// depends on availability rather than on anything the user chose, so the UI
+/// reports it as ambiguous configuration.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RuleConflict {
+    /// Positions in the `rules` vector that was checked, lower one first
+    ///
+    /// Only meaningful against that exact vector: reordering, removing or
+    /// reloading rules invalidates them. Callers that persist rules should
+    /// map them to their stored rule IDs before keeping them around.
+    pub rules: (usize, usize),
+    pub first_label: Option<String>,
+    pub second_label: Option<String>,
+    /// Days on which the overlap starts, Monday first
+    pub days: Vec<Weekday>,
+    pub start: NaiveTime,
+    /// Midnight when the overlap runs to the end of the day
+    pub end: NaiveTime,
 }
 
 // ========================================================================
@@ -85,6 +375,7 @@ impl RecurringRule {
 /// This entity does not contain persistence IDs (id, user_id).
 /// Those are infrastructure concerns managed by repositories.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScheduleTemplate {
     pub name: String,
     
@@ -93,6 +384,10 @@ pub struct ScheduleTemplate {
     
     /// Recurring rules that define the schedule
     pub rules: Vec<RecurringRule>,
+    
+    /// Specific dates that replace the weekly pattern (at most one per date)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub date_overrides: Vec<DateOverride>,
 }
 
 impl ScheduleTemplate {
@@ -110,18 +405,212 @@ impl ScheduleTemplate {
             return Err("Timezone cannot be empty".to_string());
         }
 
+        for (i, rule) in rules.iter().enumerate() {
+            if rules[..i].contains(rule) {
+                debug!("schedule template {:?} contains duplicate rule {:?}", name.trim(), rule.label);
+            }
+        }
+
         Ok(Self {
             name: name.trim().to_string(),
             timezone,
             rules,
+            date_overrides: Vec::new(),
         })
     }
+
+    /// Attach date overrides, replacing any existing ones
+    /// 
+    /// Fails if two overrides target the same date.
+    pub fn with_date_overrides(mut self, mut overrides: Vec<DateOverride>) -> Result<Self, String> {
+        overrides.sort_by_key(|o| o.date);
+        if let Some(pair) = overrides.windows(2).find(|pair| pair[0].date == pair[1].date) {
+            return Err(format!("Schedule template has more than one override for {}", pair[0].date));
+        }
+        self.date_overrides = overrides;
+        Ok(self)
+    }
+
+    /// Rebuild the whole template through its validating constructors
+    /// 
+    /// Deserialized templates skip them, so anything read from outside (a
+    /// shared file, an export bundle) goes through here before use. Errors
+    /// name the offending rule by position or the override by date.
+    pub fn revalidated(&self) -> Result<Self, String> {
+        let rules = self.rules.iter()
+            .enumerate()
+            .map(|(i, rule)| rule.revalidated().map_err(|e| format!("Rule {}: {}", i + 1, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let overrides = self.date_overrides.iter()
+            .map(|date_override| {
+                date_override.revalidated().map_err(|e| format!("Override for {}: {}", date_override.date, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(self.name.clone(), self.timezone.clone(), rules)?.with_date_overrides(overrides)
+    }
+
+    /// Advisory check that a week of this template has room for `tasks`
+    /// 
+    /// Compares the total minutes of one expanded week (starting on `week_start`)
+    /// that are not Unavailable and whose location constraint accepts `location`
+    /// against the summed task durations. Each task counts once, so pass one
+    /// entry per weekly occurrence. Placement specifics (capabilities, block
+    /// boundaries, micro-task rules) are ignored, so `true` does not guarantee
+    /// that every task can actually be placed.
+    pub fn has_capacity_for<T: SchedulableTask>(
+        &self,
+        tasks: &[T],
+        week_start: Weekday,
+        location: Option<&Location>,
+    ) -> bool {
+        // Any week works for a weekly template; Jan 1, 2024 is a Monday
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let start = utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+            + Duration::days(week_start.num_days_from_monday() as i64);
+        let end = start + Duration::days(7);
+
+        let available_minutes: i64 = expand_template(self, start, end)
+            .iter()
+            .filter(|block| !matches!(block.availability, AvailabilityKind::Unavailable(_)))
+            .filter(|block| block.location_constraint.matches(location))
+            .map(|block| (block.end - block.start).num_minutes())
+            .sum();
+        let required_minutes: i64 = tasks.iter()
+            .map(|task| task.estimated_duration_minutes() as i64)
+            .sum();
+
+        required_minutes <= available_minutes
+    }
+
+    /// Fingerprint of the template's whole content, for cache keys
+    /// 
+    /// Any change to the name, timezone, rules or date overrides changes the
+    /// hash (barring collisions). Computed from the `Debug` representation,
+    /// since coordinates are floats and cannot derive `Hash`, so the value is
+    /// only stable within one build and must not be persisted.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        format!("{:?}", self).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Find rules that never produce a block because strictly-higher-priority
+    /// rules always cover them
+    /// 
+    /// Returns indices into `rules` (repositories map them to rule IDs), so the
+    /// UI can warn about dead configuration. Equal priorities never shadow each
+    /// other since the tie-breaker depends on availability.
+    pub fn find_shadowed_rules(&self) -> Vec<usize> {
+        let spans: Vec<Vec<(u32, u32)>> = self.rules.iter().map(Self::weekly_spans).collect();
+
+        (0..self.rules.len())
+            .filter(|&i| {
+                let mut higher: Vec<(u32, u32)> = self.rules.iter()
+                    .zip(&spans)
+                    // A rule with a cadence or validity window skips some days, so it never fully covers
+                    .filter(|(rule, _)| rule.priority > self.rules[i].priority && rule.is_always_active())
+                    .flat_map(|(_, rule_spans)| rule_spans.iter().copied())
+                    .collect();
+                higher.sort_unstable();
+
+                // Merge overlapping or touching spans
+                let mut merged: Vec<(u32, u32)> = Vec::new();
+                for (start, end) in higher {
+                    match merged.last_mut() {
+                        Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                        _ => merged.push((start, end)),
+                    }
+                }
+
+                spans[i].iter().all(|&(start, end)| {
+                    merged.iter().any(|&(m_start, m_end)| m_start <= start && end <= m_end)
+                })
+            })
+            .collect()
+    }
+
+    /// Find pairs of equal-priority rules that overlap in time
+    ///
+    /// Works on the weekly pattern only, without expanding. Each overlapping
+    /// time-of-day interval yields one conflict listing every weekday it
+    /// occurs on; overnight rules are compared per side of midnight. Week
+    /// cadences and validity windows are ignored, so rules on alternating
+    /// weeks or in different seasons may be reported even if they never meet.
+    /// Conflicts refer to rules by their position in `self.rules`.
+    pub fn find_conflicts(&self) -> Vec<RuleConflict> {
+        const DAY: u32 = 24 * 3600;
+        let spans: Vec<Vec<(u32, u32)>> = self.rules.iter().map(Self::weekly_spans).collect();
+        let time = |seconds: u32| NaiveTime::from_num_seconds_from_midnight_opt(seconds % DAY, 0)
+            .unwrap_or(NaiveTime::MIN);
+
+        let mut conflicts = Vec::new();
+        for i in 0..self.rules.len() {
+            for j in (i + 1)..self.rules.len() {
+                if self.rules[i].priority != self.rules[j].priority {
+                    continue;
+                }
+
+                // Group overlaps by time of day so each interval is reported once
+                let mut overlaps: BTreeMap<(u32, u32), Vec<Weekday>> = BTreeMap::new();
+                for &(a_start, a_end) in &spans[i] {
+                    for &(b_start, b_end) in &spans[j] {
+                        let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+                        if start < end {
+                            let day = start / DAY;
+                            let weekday = Weekday::try_from(day as u8).unwrap_or(Weekday::Mon);
+                            overlaps.entry((start - day * DAY, end - day * DAY))
+                                .or_default()
+                                .push(weekday);
+                        }
+                    }
+                }
+
+                for ((start, end), mut days) in overlaps {
+                    days.sort_by_key(|day| day.num_days_from_monday());
+                    days.dedup();
+                    conflicts.push(RuleConflict {
+                        rules: (i, j),
+                        first_label: self.rules[i].label.clone(),
+                        second_label: self.rules[j].label.clone(),
+                        days,
+                        start: time(start),
+                        end: time(end),
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// A rule's spans in seconds since Monday midnight, overnight parts
+    /// split at midnight (Sunday night wraps to Monday morning)
+    fn weekly_spans(rule: &RecurringRule) -> Vec<(u32, u32)> {
+        const DAY: u32 = 24 * 3600;
+        let start = rule.start.num_seconds_from_midnight();
+        let end = rule.end.num_seconds_from_midnight();
+
+        let mut spans = Vec::new();
+        for day in &rule.days {
+            let offset = day.num_days_from_monday() * DAY;
+            if rule.is_overnight() {
+                spans.push((offset + start, offset + DAY));
+                let next_offset = (offset + DAY) % (7 * DAY);
+                if end > 0 {
+                    spans.push((next_offset, next_offset + end));
+                }
+            } else {
+                spans.push((offset + start, offset + end));
+            }
+        }
+        spans
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::entities::schedule::types::{AvailabilityKind, CapabilitySet, LocationConstraint};
+    use crate::domain::entities::schedule::types::{AvailabilityKind, CapabilitySet, LocationConstraint, UnavailableReason};
 
     #[test]
     fn test_recurring_rule_is_overnight() {
@@ -148,6 +637,79 @@ mod tests {
             0,
         ).unwrap();
         assert!(rule_overnight.is_overnight());
+
+        let zero_length = RecurringRule::new(
+            vec![Weekday::Mon],
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            None,
+            0,
+        );
+        assert!(zero_length.is_err());
+    }
+
+    #[test]
+    fn test_recurring_rule_rejects_impossible_capabilities() {
+        use crate::domain::entities::schedule::types::{AvailabilityLevel, DeviceAccess, Mobility};
+
+        // Built as a struct literal, bypassing CapabilitySet::try_new
+        let driving_at_a_computer = CapabilitySet {
+            hands: AvailabilityLevel::Limited,
+            eyes: AvailabilityLevel::Limited,
+            speech: AvailabilityLevel::Full,
+            cognitive: AvailabilityLevel::Full,
+            device: DeviceAccess::Computer,
+            mobility: Mobility::Driving,
+        };
+        let rule = RecurringRule::new(
+            vec![Weekday::Mon],
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            AvailabilityKind::BusyButFlexible,
+            driving_at_a_computer,
+            LocationConstraint::Any,
+            None,
+            0,
+        );
+        assert!(rule.is_err());
+    }
+
+    #[test]
+    fn test_applies_on_date() {
+        let weekdays = RecurringRule::new(
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            None,
+            0,
+        ).unwrap();
+        // 2024-01-02 is a Tuesday, 2024-01-06 a Saturday
+        assert!(weekdays.applies_on_date(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()));
+        assert!(!weekdays.applies_on_date(NaiveDate::from_ymd_opt(2024, 1, 6).unwrap()));
+
+        let friday_night = RecurringRule::new(
+            vec![Weekday::Fri],
+            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(2, 0, 0).unwrap(),
+            AvailabilityKind::Unavailable(UnavailableReason::Sleep),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            None,
+            0,
+        ).unwrap();
+        assert!(friday_night.applies_on_date(NaiveDate::from_ymd_opt(2024, 1, 5).unwrap()));
+        assert!(friday_night.applies_on_date(NaiveDate::from_ymd_opt(2024, 1, 6).unwrap()));
+        assert!(!friday_night.applies_on_date(NaiveDate::from_ymd_opt(2024, 1, 7).unwrap()));
+
+        // Ending exactly at midnight leaves nothing on the next day
+        let until_midnight = RecurringRule { end: NaiveTime::MIN, ..friday_night };
+        assert!(!until_midnight.applies_on_date(NaiveDate::from_ymd_opt(2024, 1, 6).unwrap()));
     }
 
     #[test]
@@ -165,6 +727,22 @@ mod tests {
         );
         assert!(result.is_err());
 
+        // Negative MustBeNear radius should fail
+        let result = RecurringRule::new(
+            vec![Weekday::Mon],
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::MustBeNear {
+                center: crate::domain::entities::user::GeoCoordinates::new(48.8566, 2.3522).unwrap(),
+                radius_meters: -1.0,
+            },
+            None,
+            0,
+        );
+        assert!(result.is_err());
+
         // Valid rule should succeed
         let result = RecurringRule::new(
             vec![Weekday::Mon, Weekday::Tue],
@@ -177,6 +755,13 @@ mod tests {
             5,
         );
         assert!(result.is_ok());
+
+        // Validity must not end before it starts
+        let date = |month, day| NaiveDate::from_ymd_opt(2026, month, day);
+        let rule = result.unwrap();
+        assert!(rule.clone().with_validity(date(8, 31), date(6, 1)).is_err());
+        assert!(rule.clone().with_validity(date(6, 1), date(6, 1)).is_ok());
+        assert!(rule.with_validity(None, date(6, 1)).is_ok());
     }
 
     #[test]
@@ -205,4 +790,293 @@ mod tests {
         );
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_override_block_validation() {
+        let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+        let block = |start, end| OverrideBlock::new(start, end, AvailabilityKind::Available, None);
+
+        assert!(block(time(10), time(9)).is_err());
+        assert!(block(time(10), time(10)).is_err());
+        // Midnight ends the day
+        assert!(block(time(22), NaiveTime::MIN).is_ok());
+        assert!(block(NaiveTime::MIN, NaiveTime::MIN).is_ok());
+
+        let day = DateOverride::new(
+            NaiveDate::from_ymd_opt(2026, 2, 14).unwrap(),
+            AvailabilityKind::Unavailable(UnavailableReason::Other("Off".to_string())),
+            None,
+        );
+        assert!(day.clone()
+            .with_blocks(vec![block(time(20), NaiveTime::MIN).unwrap(), block(time(12), time(21)).unwrap()])
+            .is_err());
+
+        // Touching blocks are fine and come back sorted
+        let day = day
+            .with_blocks(vec![block(time(20), NaiveTime::MIN).unwrap(), block(time(12), time(20)).unwrap()])
+            .unwrap();
+        assert_eq!(day.blocks[0].start, time(12));
+        assert_eq!(day.blocks[1].start, time(20));
+    }
+
+    #[test]
+    fn test_daily_except_weekends_expands_to_weekdays() {
+        use crate::domain::entities::schedule::expansion::expand_template;
+        use chrono::{FixedOffset, TimeZone};
+
+        let rule = RecurringRule::daily_except(
+            &[Weekday::Sat, Weekday::Sun],
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Work".to_string()),
+            0,
+        ).unwrap();
+        assert_eq!(
+            rule.days,
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
+        );
+
+        let template = ScheduleTemplate::new(
+            "Weekdays".to_string(),
+            "UTC".to_string(),
+            vec![rule],
+        ).unwrap();
+
+        // Mon Feb 9 to Mon Feb 16, 2026
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let start = utc.with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap();
+        let end = utc.with_ymd_and_hms(2026, 2, 16, 0, 0, 0).unwrap();
+        let blocks = expand_template(&template, start, end);
+
+        let days: Vec<Weekday> = blocks.iter().map(|b| b.start.weekday()).collect();
+        assert_eq!(
+            days,
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
+        );
+    }
+
+    #[test]
+    fn test_daily_except_all_days_fails() {
+        let all_days = [
+            Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu,
+            Weekday::Fri, Weekday::Sat, Weekday::Sun,
+        ];
+        let result = RecurringRule::daily_except(
+            &all_days,
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            None,
+            0,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_has_capacity_for() {
+        use crate::domain::entities::task::{OccurrenceTimingSettings, PeriodicityBuilder, Task};
+
+        let task = |minutes| {
+            let periodicity = PeriodicityBuilder::new()
+                .daily(1)
+                .with_occurrence_settings(OccurrenceTimingSettings {
+                    duration: Some(minutes),
+                    not_before: None,
+                    best_before: None,
+                    rep_timing_settings: None,
+                })
+                .build()
+                .unwrap();
+            Task::new("Task".to_string(), periodicity).unwrap()
+        };
+
+        // Two free hours a week; the rest of Monday is explicitly unavailable
+        let rules = vec![
+            RecurringRule::new(
+                vec![Weekday::Mon],
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+                AvailabilityKind::Available,
+                CapabilitySet::free(),
+                LocationConstraint::Any,
+                None,
+                1,
+            ).unwrap(),
+            RecurringRule::new(
+                vec![Weekday::Mon],
+                NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+                AvailabilityKind::Unavailable(UnavailableReason::Work),
+                CapabilitySet::free(),
+                LocationConstraint::Any,
+                None,
+                0,
+            ).unwrap(),
+        ];
+        let template = ScheduleTemplate::new("Busy".to_string(), "UTC".to_string(), rules).unwrap();
+
+        assert!(!template.has_capacity_for(&[task(120), task(60)], Weekday::Mon, None));
+        assert!(template.has_capacity_for(&[task(60)], Weekday::Mon, None));
+        assert!(template.has_capacity_for(&[task(60), task(60)], Weekday::Sun, None));
+    }
+
+    #[test]
+    fn test_find_shadowed_rules() {
+        let rule = |start, end, availability, priority| RecurringRule::new(
+            vec![Weekday::Mon, Weekday::Tue],
+            NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+            availability,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            None,
+            priority,
+        ).unwrap();
+
+        let template = ScheduleTemplate::new(
+            "Meetings".to_string(),
+            "UTC".to_string(),
+            vec![
+                rule(9, 10, AvailabilityKind::Available, 0),
+                rule(9, 10, AvailabilityKind::Unavailable(UnavailableReason::Appointment), 5),
+                // Only partly covered by the meeting
+                rule(8, 12, AvailabilityKind::BusyButFlexible, 1),
+            ],
+        ).unwrap();
+        assert_eq!(template.find_shadowed_rules(), vec![0]);
+
+        // A rule on an extra day is not fully covered
+        let mut rules = template.rules.clone();
+        rules[0].days.push(Weekday::Wed);
+        let template = ScheduleTemplate::new("Meetings".to_string(), "UTC".to_string(), rules).unwrap();
+        assert!(template.find_shadowed_rules().is_empty());
+    }
+
+    #[test]
+    fn test_find_shadowed_rules_overnight() {
+        // Sunday 22:00 to Monday 06:00, covered by two separate higher rules
+        let rule = |days, start, end, priority| RecurringRule::new(
+            days,
+            NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+            AvailabilityKind::Unavailable(UnavailableReason::Sleep),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            None,
+            priority,
+        ).unwrap();
+
+        let template = ScheduleTemplate::new(
+            "Nights".to_string(),
+            "UTC".to_string(),
+            vec![
+                rule(vec![Weekday::Sun], 22, 6, 0),
+                rule(vec![Weekday::Sun], 21, 0, 1),
+                rule(vec![Weekday::Mon], 0, 7, 1),
+            ],
+        ).unwrap();
+        assert_eq!(template.find_shadowed_rules(), vec![0]);
+    }
+
+    #[test]
+    fn test_content_hash_tracks_changes() {
+        let rule = RecurringRule::new(
+            vec![Weekday::Mon],
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            None,
+            0,
+        ).unwrap();
+        let template = ScheduleTemplate::new("Week".to_string(), "UTC".to_string(), vec![rule]).unwrap();
+
+        assert_eq!(template.content_hash(), template.clone().content_hash());
+
+        let mut changed = template.clone();
+        changed.rules[0].end = NaiveTime::from_hms_opt(18, 0, 0).unwrap();
+        assert_ne!(template.content_hash(), changed.content_hash());
+    }
+
+    #[test]
+    fn test_find_conflicts_clean_template() {
+        let template = ScheduleTemplate::new(
+            "Work week".to_string(),
+            "UTC".to_string(),
+            vec![
+                RecurringRule::new(
+                    vec![Weekday::Mon, Weekday::Tue],
+                    NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                    NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+                    AvailabilityKind::Available,
+                    CapabilitySet::free(),
+                    LocationConstraint::Any,
+                    Some("Morning".to_string()),
+                    0,
+                ).unwrap(),
+                // Touching but not overlapping
+                RecurringRule::new(
+                    vec![Weekday::Mon, Weekday::Tue],
+                    NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+                    NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+                    AvailabilityKind::Unavailable(UnavailableReason::Other("Lunch".to_string())),
+                    CapabilitySet::free(),
+                    LocationConstraint::Any,
+                    Some("Lunch".to_string()),
+                    0,
+                ).unwrap(),
+                // Overlaps, but at a higher priority
+                RecurringRule::new(
+                    vec![Weekday::Mon],
+                    NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                    NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+                    AvailabilityKind::Unavailable(UnavailableReason::Appointment),
+                    CapabilitySet::free(),
+                    LocationConstraint::Any,
+                    Some("Stand-up".to_string()),
+                    5,
+                ).unwrap(),
+            ],
+        ).unwrap();
+
+        assert!(template.find_conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_find_conflicts_equal_priority_overlap() {
+        let rule = |days, start, end, label: &str| RecurringRule::new(
+            days,
+            NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some(label.to_string()),
+            1,
+        ).unwrap();
+
+        let template = ScheduleTemplate::new(
+            "Tuesdays".to_string(),
+            "UTC".to_string(),
+            vec![
+                rule(vec![Weekday::Tue], 9, 11, "Deep work"),
+                rule(vec![Weekday::Tue, Weekday::Wed], 10, 12, "Errands"),
+            ],
+        ).unwrap();
+
+        assert_eq!(template.find_conflicts(), vec![RuleConflict {
+            rules: (0, 1),
+            first_label: Some("Deep work".to_string()),
+            second_label: Some("Errands".to_string()),
+            days: vec![Weekday::Tue],
+            start: NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+        }]);
+    }
 }
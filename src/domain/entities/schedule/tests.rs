@@ -4,16 +4,20 @@
 #[cfg(test)]
 mod integration_tests {
     use crate::domain::entities::schedule::{
-        expansion::expand_template,
-        matching::{can_schedule_task_in_block, SchedulableTask},
-        template::{RecurringRule, ScheduleTemplate},
+        expansion::{
+            expand_template, expand_template_tz, expand_template_with_report, ConflictResolution,
+            ResolutionReason,
+        },
+        matching::{can_schedule_task_in_block, find_candidate_slots_stepped, find_rep_slots_stepped, SchedulableTask},
+        template::{DateOverride, OverrideBlock, RecurringRule, ScheduleTemplate},
         types::{
             AvailabilityKind, AvailabilityLevel, CapabilitySet, DeviceAccess,
             LocationConstraint, Mobility, UnavailableReason,
         },
     };
+    use crate::domain::entities::task::{OccurrenceTimingSettings, RepTimingSettings, TaskOccurrence};
     use crate::domain::entities::user::{GeoCoordinates, Location};
-    use chrono::{FixedOffset, NaiveTime, TimeZone, Timelike, Weekday};
+    use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike, Weekday};
 
     // ========================================================================
     // TEST HELPERS
@@ -167,14 +171,14 @@ mod integration_tests {
 
         // Test that micro tasks can be scheduled during work hours
         let micro_task = TestTask::new_micro();
-        assert!(can_schedule_task_in_block(&micro_task, &blocks[0], None));
+        assert!(can_schedule_task_in_block(&micro_task, &blocks[0], None, None));
 
         // Test that computer tasks can be scheduled during lunch
         let computer_task = TestTask::new_computer_task(30);
-        assert!(can_schedule_task_in_block(&computer_task, &blocks[1], None));
+        assert!(can_schedule_task_in_block(&computer_task, &blocks[1], None, None));
 
         // Test that computer tasks cannot be scheduled during work hours (busy-but-flexible)
-        assert!(!can_schedule_task_in_block(&computer_task, &blocks[0], None));
+        assert!(!can_schedule_task_in_block(&computer_task, &blocks[0], None, None));
     }
 
     // ========================================================================
@@ -232,10 +236,78 @@ mod integration_tests {
         // No tasks should be schedulable during sleep
         let task = TestTask::new_simple(10);
         for block in &blocks {
-            assert!(!can_schedule_task_in_block(&task, block, None));
+            assert!(!can_schedule_task_in_block(&task, block, None, None));
         }
     }
 
+    #[test]
+    fn test_overnight_sleep_across_spring_forward() {
+        let every_day = vec![
+            Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed,
+            Weekday::Thu, Weekday::Fri, Weekday::Sat,
+        ];
+        let sleep_rule = RecurringRule::new(
+            every_day,
+            NaiveTime::from_hms_opt(23, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            AvailabilityKind::Unavailable(UnavailableReason::Sleep),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Sleep".to_string()),
+            0,
+        )
+        .unwrap();
+        // Falls inside the 02:00-03:00 gap on the night of the change
+        let night_feed = RecurringRule::new(
+            vec![Weekday::Sun],
+            NaiveTime::from_hms_opt(2, 30, 0).unwrap(),
+            NaiveTime::from_hms_opt(4, 0, 0).unwrap(),
+            AvailabilityKind::Available,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Night feed".to_string()),
+            1,
+        )
+        .unwrap();
+
+        let template = ScheduleTemplate::new(
+            "Sleep Schedule".to_string(),
+            "Europe/Paris".to_string(),
+            vec![sleep_rule.clone()],
+        )
+        .unwrap();
+
+        // Paris springs forward at 02:00 on Sunday 2026-03-29
+        let winter = FixedOffset::east_opt(3600).unwrap();
+        let summer = FixedOffset::east_opt(2 * 3600).unwrap();
+        let start = winter.with_ymd_and_hms(2026, 3, 28, 12, 0, 0).unwrap();
+        let end = summer.with_ymd_and_hms(2026, 3, 29, 12, 0, 0).unwrap();
+
+        let blocks = expand_template_tz(&template, start, end, chrono_tz::Europe::Paris);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].start, winter.with_ymd_and_hms(2026, 3, 28, 23, 0, 0).unwrap());
+        assert_eq!(blocks[0].end, summer.with_ymd_and_hms(2026, 3, 29, 7, 0, 0).unwrap());
+        assert_eq!(blocks[0].end - blocks[0].start, chrono::Duration::hours(7));
+
+        // The same wall-clock rule in a zone without DST lasts 8 hours
+        let blocks = expand_template_tz(&template, start, end, chrono_tz::UTC);
+        let sleep_block = blocks.iter().find(|b| b.end - b.start == chrono::Duration::hours(8));
+        assert!(sleep_block.is_some());
+
+        // A rule starting in the skipped hour starts once the clocks have moved
+        let template = ScheduleTemplate::new(
+            "Sleep Schedule".to_string(),
+            "Europe/Paris".to_string(),
+            vec![sleep_rule, night_feed],
+        )
+        .unwrap();
+        let blocks = expand_template_tz(&template, start, end, chrono_tz::Europe::Paris);
+        let feed = blocks.iter().find(|b| b.label.as_deref() == Some("Night feed")).unwrap();
+        assert_eq!(feed.start, summer.with_ymd_and_hms(2026, 3, 29, 3, 30, 0).unwrap());
+        assert_eq!(feed.end, summer.with_ymd_and_hms(2026, 3, 29, 4, 0, 0).unwrap());
+        assert_eq!(blocks.len(), 3);
+    }
+
     // ========================================================================
     // SCENARIO 3: Commute Schedule with Limited Capabilities
     // ========================================================================
@@ -284,11 +356,11 @@ mod integration_tests {
             allowed_mobility: vec![Mobility::InTransit],
         };
 
-        assert!(can_schedule_task_in_block(&phone_task, &blocks[0], None));
+        assert!(can_schedule_task_in_block(&phone_task, &blocks[0], None, None));
 
         // Computer task should not work
         let computer_task = TestTask::new_computer_task(10);
-        assert!(!can_schedule_task_in_block(&computer_task, &blocks[0], None));
+        assert!(!can_schedule_task_in_block(&computer_task, &blocks[0], None, None));
     }
 
     // ========================================================================
@@ -345,13 +417,13 @@ mod integration_tests {
         let task = TestTask::new_simple(30);
 
         // Should work at home
-        assert!(can_schedule_task_in_block(&task, &blocks[0], Some(&home)));
+        assert!(can_schedule_task_in_block(&task, &blocks[0], Some(&home), None));
 
         // Should work at work
-        assert!(can_schedule_task_in_block(&task, &blocks[0], Some(&work)));
+        assert!(can_schedule_task_in_block(&task, &blocks[0], Some(&work), None));
 
         // Should not work at unknown location
-        assert!(!can_schedule_task_in_block(&task, &blocks[0], None));
+        assert!(!can_schedule_task_in_block(&task, &blocks[0], None, None));
 
         // Should not work at different location
         let coords_other = GeoCoordinates::new(51.5074, -0.1278).unwrap();
@@ -362,7 +434,7 @@ mod integration_tests {
             coords_other,
         )
         .unwrap();
-        assert!(!can_schedule_task_in_block(&task, &blocks[0], Some(&other)));
+        assert!(!can_schedule_task_in_block(&task, &blocks[0], Some(&other), None));
     }
 
     // ========================================================================
@@ -526,4 +598,193 @@ mod integration_tests {
             ));
         }
     }
+
+    #[test]
+    fn test_same_priority_tie_break_is_reported() {
+        let rule = |start, end, availability, label: &str, priority| RecurringRule::new(
+            vec![Weekday::Tue],
+            NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+            availability,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some(label.to_string()),
+            priority,
+        )
+        .unwrap();
+
+        let template = ScheduleTemplate::new(
+            "Tie Break Report".to_string(),
+            "America/New_York".to_string(),
+            vec![
+                rule(9, 12, AvailabilityKind::Available, "Focus", 5),
+                rule(10, 11, AvailabilityKind::Unavailable(UnavailableReason::Work), "Meeting", 5),
+                rule(14, 16, AvailabilityKind::Available, "Errands", 1),
+            ],
+        )
+        .unwrap();
+
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let (blocks, report) = expand_template_with_report(
+            &template,
+            tz.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap(),
+        );
+
+        assert_eq!(blocks, expand_template(
+            &template,
+            tz.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap(),
+        ));
+        assert_eq!(report, vec![ConflictResolution {
+            start: tz.with_ymd_and_hms(2026, 2, 10, 10, 0, 0).unwrap(),
+            end: tz.with_ymd_and_hms(2026, 2, 10, 11, 0, 0).unwrap(),
+            rules: vec![1, 0],
+            winner: 1,
+            reason: ResolutionReason::Restrictiveness,
+        }]);
+    }
+
+    #[test]
+    fn test_date_override_beats_recurring_rules() {
+        let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+        let work = RecurringRule::new(
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            time(9),
+            time(17),
+            AvailabilityKind::Unavailable(UnavailableReason::Work),
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some("Work".to_string()),
+            // Even the strongest rule loses to an override
+            i16::MAX,
+        )
+        .unwrap();
+        let day_off = |date| DateOverride::new(
+            date,
+            AvailabilityKind::Unavailable(UnavailableReason::Other("Day off".to_string())),
+            Some("Day off".to_string()),
+        );
+
+        // 2026-02-10 is a Tuesday
+        let tuesday = NaiveDate::from_ymd_opt(2026, 2, 10).unwrap();
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let start = tz.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap();
+        let end = tz.with_ymd_and_hms(2026, 2, 11, 0, 0, 0).unwrap();
+
+        // All-day override: no trace of the 9-5 Work block
+        let template = ScheduleTemplate::new(
+            "Day Off".to_string(),
+            "America/New_York".to_string(),
+            vec![work.clone()],
+        )
+        .unwrap()
+        .with_date_overrides(vec![day_off(tuesday)])
+        .unwrap();
+
+        let blocks = expand_template(&template, start, end);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!((blocks[0].start, blocks[0].end), (start, end));
+        assert_eq!(blocks[0].label, Some("Day off".to_string()));
+        assert!(matches!(blocks[0].availability, AvailabilityKind::Unavailable(_)));
+
+        // Override with its own blocks: only those break up the day
+        let template = ScheduleTemplate::new(
+            "Evening Free".to_string(),
+            "America/New_York".to_string(),
+            vec![work],
+        )
+        .unwrap()
+        .with_date_overrides(vec![day_off(tuesday)
+            .with_blocks(vec![OverrideBlock::new(
+                time(19),
+                time(22),
+                AvailabilityKind::Available,
+                Some("Dinner out".to_string()),
+            )
+            .unwrap()])
+            .unwrap()])
+        .unwrap();
+
+        let blocks = expand_template(&template, start, end);
+        let spans: Vec<_> = blocks.iter()
+            .map(|b| (b.start.hour(), b.end.hour(), b.label.clone().unwrap()))
+            .collect();
+        assert_eq!(spans, vec![
+            (0, 19, "Day off".to_string()),
+            (19, 22, "Dinner out".to_string()),
+            (22, 0, "Day off".to_string()),
+        ]);
+        assert_eq!(blocks[1].availability, AvailabilityKind::Available);
+    }
+
+    // ========================================================================
+    // SCENARIO 7: Reps Within Their Own Windows
+    // ========================================================================
+
+    #[test]
+    fn test_three_daily_reps_land_morning_noon_evening() {
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let rule = |start, end, availability, label: &str, priority| RecurringRule::new(
+            vec![Weekday::Tue],
+            time(start),
+            time(end),
+            availability,
+            CapabilitySet::free(),
+            LocationConstraint::Any,
+            Some(label.to_string()),
+            priority,
+        )
+        .unwrap();
+        let template = ScheduleTemplate::new(
+            "Home day".to_string(),
+            "Europe/Paris".to_string(),
+            vec![
+                rule(6, 22, AvailabilityKind::Available, "Home", 0),
+                rule(12, 13, AvailabilityKind::Unavailable(UnavailableReason::Other("Lunch".to_string())), "Lunch", 10),
+            ],
+        )
+        .unwrap();
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let day_start = paris.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap();
+        let blocks = expand_template(&template, day_start, day_start + chrono::Duration::days(1));
+
+        // Medication three times a day
+        let rep = |rep_index, open, close| RepTimingSettings {
+            rep_index,
+            not_before: Some(time(open)),
+            best_before: Some(time(close)),
+        };
+        let timing = OccurrenceTimingSettings {
+            duration: Some(20),
+            not_before: None,
+            best_before: None,
+            rep_timing_settings: Some(vec![rep(0, 7, 9), rep(1, 12, 14), rep(2, 18, 20)]),
+        };
+        let task = TestTask::new_simple(20);
+        let occurrence = TaskOccurrence::new(
+            day_start.with_timezone(&chrono::Utc),
+            (day_start + chrono::Duration::days(1)).with_timezone(&chrono::Utc),
+            3,
+        )
+        .unwrap();
+
+        let windows = occurrence.rep_windows(Some(&timing), paris);
+        let slots: Vec<Vec<_>> = windows.iter()
+            .map(|window| find_rep_slots_stepped(&blocks, &task, None, *window, 30))
+            .collect();
+        let hours = |rep: usize| -> Vec<(u32, u32)> {
+            slots[rep].iter().map(|(start, _)| (start.hour(), start.minute())).collect()
+        };
+
+        assert_eq!(hours(0), vec![(7, 0), (7, 30), (8, 0), (8, 30)]);
+        // Lunch takes the first hour of the noon window
+        assert_eq!(hours(1), vec![(13, 0), (13, 30)]);
+        assert_eq!(hours(2), vec![(18, 0), (18, 30), (19, 0), (19, 30)]);
+
+        // Matching on the whole timing settings accepts any rep's window
+        let any_rep = find_candidate_slots_stepped(&blocks, &task, None, Some(&timing), 30);
+        assert_eq!(any_rep.len(), 10);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::domain::entities::user::Location;
+use crate::domain::entities::user::{GeoCoordinates, Location};
 use crate::config;
 
 // ========================================================================
@@ -7,6 +7,7 @@ use crate::config;
 
 /// Represents the availability status during a time period
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AvailabilityKind {
     /// User is not available for tasks
     Unavailable(UnavailableReason),
@@ -18,6 +19,7 @@ pub enum AvailabilityKind {
 
 /// Reason for unavailability (for logging/display purposes)
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnavailableReason {
     Sleep,
     Work,
@@ -31,7 +33,8 @@ pub enum UnavailableReason {
 // ========================================================================
 
 /// Represents the level of availability for a capability (hands, eyes, etc.)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AvailabilityLevel {
     None = 0,
     Limited = 1,
@@ -39,23 +42,57 @@ pub enum AvailabilityLevel {
 }
 
 /// Device access level
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeviceAccess {
     None = 0,
     PhoneOnly = 1,
-    Computer = 2,
+    Tablet = 2,
+    Computer = 3,
 }
 
 /// Mobility status
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// 
+/// Deliberately not `Ord`: unlike levels, where "more" is always "more
+/// capable", a derived ordering here would read naturally in either
+/// direction (`intersect` wants the most restrictive state, matching wants
+/// the most capable one). Use `allows` for "at least this mobility" checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mobility {
     Stationary,
     InTransit,
     Driving,
 }
 
+impl Mobility {
+    /// How much the state restricts what can be done: 0 for Stationary
+    /// (most capable) up to 2 for Driving
+    fn restriction(self) -> u8 {
+        match self {
+            Mobility::Stationary => 0,
+            Mobility::InTransit => 1,
+            Mobility::Driving => 2,
+        }
+    }
+
+    /// Check if being in this state leaves room for a task that can still be
+    /// done while in `required`
+    /// 
+    /// A task that works while driving also works in transit or at a desk,
+    /// so `Stationary.allows(Driving)` holds but `Driving.allows(Stationary)`
+    /// does not.
+    pub fn allows(&self, required: Mobility) -> bool {
+        self.restriction() <= required.restriction()
+    }
+}
+
 /// Represents the full set of capabilities available during a time period
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// 
+/// Fields serialize in declaration order; keep it stable, exported data and
+/// stored rules depend on it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CapabilitySet {
     pub hands: AvailabilityLevel,
     pub eyes: AvailabilityLevel,
@@ -66,6 +103,76 @@ pub struct CapabilitySet {
 }
 
 impl CapabilitySet {
+    /// Create a capability set, rejecting physically impossible combinations
+    /// 
+    /// # Compatibility Matrix
+    /// - Driving: phone at most, hands and eyes below Full
+    /// - Stationary and InTransit: no restrictions
+    /// 
+    /// The presets below are known-valid and skip this check.
+    pub fn try_new(
+        hands: AvailabilityLevel,
+        eyes: AvailabilityLevel,
+        speech: AvailabilityLevel,
+        cognitive: AvailabilityLevel,
+        device: DeviceAccess,
+        mobility: Mobility,
+    ) -> Result<Self, CapabilityError> {
+        let capabilities = Self { hands, eyes, speech, cognitive, device, mobility };
+        capabilities.validate()?;
+        Ok(capabilities)
+    }
+
+    /// Check the set against the mobility/capability compatibility matrix
+    pub fn validate(&self) -> Result<(), CapabilityError> {
+        if self.mobility == Mobility::Driving {
+            if self.device > DeviceAccess::PhoneOnly {
+                return Err(CapabilityError::DeviceNotAllowed {
+                    mobility: self.mobility,
+                    device: self.device,
+                });
+            }
+            for (capability, level) in [("hands", self.hands), ("eyes", self.eyes)] {
+                if level == AvailabilityLevel::Full {
+                    return Err(CapabilityError::LevelNotAllowed {
+                        mobility: self.mobility,
+                        capability: capability.to_string(),
+                        level,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The more restrictive of two sets, dimension by dimension
+    /// 
+    /// Levels and device take the minimum; mobility takes the more
+    /// restrictive state (Driving > InTransit > Stationary).
+    pub fn intersect(&self, other: &CapabilitySet) -> CapabilitySet {
+        CapabilitySet {
+            hands: self.hands.min(other.hands),
+            eyes: self.eyes.min(other.eyes),
+            speech: self.speech.min(other.speech),
+            cognitive: self.cognitive.min(other.cognitive),
+            device: self.device.min(other.device),
+            mobility: if other.mobility.restriction() > self.mobility.restriction() {
+                other.mobility
+            } else {
+                self.mobility
+            },
+        }
+    }
+
+    /// Normalized form used for hashing and deduplication
+    /// 
+    /// Every field is currently significant, so this is a plain copy; it is
+    /// the single place to drop or round fields that should not tell two
+    /// sets apart.
+    pub fn canonical(&self) -> CapabilitySet {
+        self.clone()
+    }
+
     /// Maximum capabilities - user is free and at a computer
     pub fn free() -> Self {
         Self {
@@ -101,14 +208,67 @@ impl CapabilitySet {
             mobility: Mobility::InTransit,
         }
     }
+
+    /// Walking - limited hands/eyes, full speech and cognitive, phone available
+    pub fn walking() -> Self {
+        Self {
+            hands: AvailabilityLevel::Limited,
+            eyes: AvailabilityLevel::Limited,
+            speech: AvailabilityLevel::Full,
+            cognitive: AvailabilityLevel::Full,
+            device: DeviceAccess::PhoneOnly,
+            mobility: Mobility::InTransit,
+        }
+    }
+
+    /// Cycling - hands unavailable, limited eyes and cognitive, no device
+    pub fn cycling() -> Self {
+        Self {
+            hands: AvailabilityLevel::None,
+            eyes: AvailabilityLevel::Limited,
+            speech: AvailabilityLevel::Full,
+            cognitive: AvailabilityLevel::Limited,
+            device: DeviceAccess::None,
+            mobility: Mobility::InTransit,
+        }
+    }
+}
+
+/// Error for capability combinations that cannot happen together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapabilityError {
+    /// Device access unusable in this mobility state
+    DeviceNotAllowed { mobility: Mobility, device: DeviceAccess },
+    /// Availability level unreachable in this mobility state
+    LevelNotAllowed {
+        mobility: Mobility,
+        capability: String,
+        level: AvailabilityLevel,
+    },
+}
+
+impl std::fmt::Display for CapabilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CapabilityError::DeviceNotAllowed { mobility, device } => {
+                write!(f, "Device access {:?} is not possible while {:?}", device, mobility)
+            }
+            CapabilityError::LevelNotAllowed { mobility, capability, level } => {
+                write!(f, "{:?} {} availability is not possible while {:?}", level, capability, mobility)
+            }
+        }
+    }
 }
 
+impl std::error::Error for CapabilityError {}
+
 // ========================================================================
 // LOCATION CONSTRAINTS
 // ========================================================================
 
 /// Constraint on location for a time period
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LocationConstraint {
     /// Any location is acceptable (or location doesn't matter)
     Any,
@@ -118,6 +278,17 @@ pub enum LocationConstraint {
     MustBeUnknown,
     /// Must be in one of the specified locations
     MustBeOneOf(Vec<Location>),
+    /// Must be within `radius_meters` of `center`, whatever the location's name
+    MustBeNear {
+        center: GeoCoordinates,
+        radius_meters: f64,
+    },
+    /// Must be within `radius_meters` of one of the saved locations, so a
+    /// GPS reading near "Home" counts as being at home
+    MustBeWithin {
+        locations: Vec<Location>,
+        radius_meters: f64,
+    },
 }
 
 impl LocationConstraint {
@@ -134,6 +305,14 @@ impl LocationConstraint {
                     false
                 }
             }
+            LocationConstraint::MustBeNear { center, radius_meters } => {
+                current_location.is_some_and(|loc| center.distance_to(loc.geoloc()) <= *radius_meters)
+            }
+            LocationConstraint::MustBeWithin { locations, radius_meters } => {
+                current_location.is_some_and(|loc| {
+                    locations.iter().any(|saved| saved.geoloc().distance_to(loc.geoloc()) <= *radius_meters)
+                })
+            }
         }
     }
 }
@@ -142,36 +321,72 @@ impl LocationConstraint {
 // CONSTANTS
 // ========================================================================
 
+/// What a task may need to fit a BusyButFlexible period (a "micro task")
+/// 
+/// `configured()` reads the `SCHEDULE_BUSY_FLEX_*` settings; build one with
+/// `from_levels` to match against other limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusyFlexLimits {
+    pub max_minutes: u32,
+    pub max_hands: AvailabilityLevel,
+    pub max_eyes: AvailabilityLevel,
+    pub max_device: DeviceAccess,
+}
+
+impl BusyFlexLimits {
+    /// Limits from numeric levels, read the same way as the configuration
+    /// (see `config` for the level numbers)
+    pub fn from_levels(max_minutes: u32, hands_level: u8, eyes_level: u8, device_level: u8) -> Self {
+        let availability = |level| match level {
+            0 => AvailabilityLevel::None,
+            1 => AvailabilityLevel::Limited,
+            _ => AvailabilityLevel::Full,
+        };
+        // Tablet came later, so it takes the next free level: 2 keeps
+        // meaning Computer for existing settings
+        let device = match device_level {
+            0 => DeviceAccess::None,
+            1 => DeviceAccess::PhoneOnly,
+            3 => DeviceAccess::Tablet,
+            _ => DeviceAccess::Computer,
+        };
+        Self {
+            max_minutes,
+            max_hands: availability(hands_level),
+            max_eyes: availability(eyes_level),
+            max_device: device,
+        }
+    }
+
+    /// Limits from the application configuration
+    pub fn configured() -> Self {
+        Self::from_levels(
+            config::schedule_busy_flex_max_minutes(),
+            config::schedule_busy_flex_max_hands_level(),
+            config::schedule_busy_flex_max_eyes_level(),
+            config::schedule_busy_flex_max_device_level(),
+        )
+    }
+}
+
 /// Maximum task duration (in minutes) allowed during BusyButFlexible periods
 pub fn busy_flex_max_minutes() -> u32 {
-    config::schedule_busy_flex_max_minutes()
+    BusyFlexLimits::configured().max_minutes
 }
 
 /// Maximum hands level allowed during BusyButFlexible periods
 pub fn busy_flex_max_hands() -> AvailabilityLevel {
-    match config::schedule_busy_flex_max_hands_level() {
-        0 => AvailabilityLevel::None,
-        1 => AvailabilityLevel::Limited,
-        _ => AvailabilityLevel::Full,
-    }
+    BusyFlexLimits::configured().max_hands
 }
 
 /// Maximum eyes level allowed during BusyButFlexible periods
 pub fn busy_flex_max_eyes() -> AvailabilityLevel {
-    match config::schedule_busy_flex_max_eyes_level() {
-        0 => AvailabilityLevel::None,
-        1 => AvailabilityLevel::Limited,
-        _ => AvailabilityLevel::Full,
-    }
+    BusyFlexLimits::configured().max_eyes
 }
 
 /// Maximum device required for BusyButFlexible periods
 pub fn busy_flex_max_device() -> DeviceAccess {
-    match config::schedule_busy_flex_max_device_level() {
-        0 => DeviceAccess::None,
-        1 => DeviceAccess::PhoneOnly,
-        _ => DeviceAccess::Computer,
-    }
+    BusyFlexLimits::configured().max_device
 }
 
 #[cfg(test)]
@@ -185,10 +400,39 @@ mod tests {
         assert!(AvailabilityLevel::Limited < AvailabilityLevel::Full);
     }
 
+    #[test]
+    fn test_mobility_allows() {
+        use Mobility::{Driving, InTransit, Stationary};
+
+        // Each state allows itself and anything more restrictive
+        for (state, allowed) in [
+            (Stationary, [true, true, true]),
+            (InTransit, [false, true, true]),
+            (Driving, [false, false, true]),
+        ] {
+            for (required, expected) in [Stationary, InTransit, Driving].into_iter().zip(allowed) {
+                assert_eq!(state.allows(required), expected, "{:?}.allows({:?})", state, required);
+            }
+        }
+    }
+
     #[test]
     fn test_device_access_ordering() {
         assert!(DeviceAccess::None < DeviceAccess::PhoneOnly);
         assert!(DeviceAccess::PhoneOnly < DeviceAccess::Computer);
+        assert!(DeviceAccess::PhoneOnly < DeviceAccess::Tablet);
+        assert!(DeviceAccess::Tablet < DeviceAccess::Computer);
+    }
+
+    #[test]
+    fn test_busy_flex_device_levels_keep_their_meaning() {
+        let device = |level| BusyFlexLimits::from_levels(15, 1, 1, level).max_device;
+        assert_eq!(device(0), DeviceAccess::None);
+        assert_eq!(device(1), DeviceAccess::PhoneOnly);
+        // Level 2 meant Computer before Tablet existed, and still does
+        assert_eq!(device(2), DeviceAccess::Computer);
+        assert_eq!(device(3), DeviceAccess::Tablet);
+        assert_eq!(device(9), DeviceAccess::Computer);
     }
 
     #[test]
@@ -206,6 +450,93 @@ mod tests {
         assert_eq!(transit.device, DeviceAccess::PhoneOnly);
     }
 
+    #[test]
+    fn test_capability_set_try_new_rejects_driving_with_computer() {
+        use AvailabilityLevel::{Full, Limited, None};
+
+        let result = CapabilitySet::try_new(None, None, Full, Limited, DeviceAccess::Computer, Mobility::Driving);
+        assert_eq!(
+            result,
+            Err(CapabilityError::DeviceNotAllowed { mobility: Mobility::Driving, device: DeviceAccess::Computer })
+        );
+
+        let result = CapabilitySet::try_new(None, None, Full, Limited, DeviceAccess::Tablet, Mobility::Driving);
+        assert!(matches!(result, Err(CapabilityError::DeviceNotAllowed { .. })));
+
+        let result = CapabilitySet::try_new(Full, None, Full, Limited, DeviceAccess::None, Mobility::Driving);
+        assert!(matches!(result, Err(CapabilityError::LevelNotAllowed { .. })));
+
+        // The same capabilities are fine when stationary
+        assert!(CapabilitySet::try_new(Full, Full, Full, Limited, DeviceAccess::Computer, Mobility::Stationary).is_ok());
+    }
+
+    #[test]
+    fn test_capability_presets_are_valid() {
+        for preset in [
+            CapabilitySet::free(),
+            CapabilitySet::driving(),
+            CapabilitySet::in_transit(),
+            CapabilitySet::walking(),
+            CapabilitySet::cycling(),
+        ] {
+            assert_eq!(preset.validate(), Ok(()), "{:?}", preset);
+        }
+    }
+
+    #[test]
+    fn test_capability_intersect() {
+        assert_eq!(CapabilitySet::free().intersect(&CapabilitySet::driving()), CapabilitySet::driving());
+        assert_eq!(CapabilitySet::driving().intersect(&CapabilitySet::free()), CapabilitySet::driving());
+
+        // Each dimension is taken independently
+        let mixed = CapabilitySet::walking().intersect(&CapabilitySet::cycling());
+        assert_eq!(mixed.hands, AvailabilityLevel::None);
+        assert_eq!(mixed.cognitive, AvailabilityLevel::Limited);
+        assert_eq!(mixed.device, DeviceAccess::None);
+        assert_eq!(mixed.mobility, Mobility::InTransit);
+    }
+
+    #[test]
+    fn test_capability_set_hash_and_canonical() {
+        use std::collections::HashSet;
+
+        // Walking and in-transit are built separately but are the same set
+        let sets: HashSet<CapabilitySet> = [
+            CapabilitySet::walking(),
+            CapabilitySet::in_transit(),
+            CapabilitySet::in_transit().canonical(),
+            CapabilitySet::driving(),
+        ].into_iter().collect();
+        assert_eq!(sets.len(), 2);
+        assert_eq!(CapabilitySet::cycling().canonical(), CapabilitySet::cycling());
+    }
+
+    #[test]
+    fn test_walking_and_cycling_presets() {
+        assert_eq!(
+            CapabilitySet::walking(),
+            CapabilitySet {
+                hands: AvailabilityLevel::Limited,
+                eyes: AvailabilityLevel::Limited,
+                speech: AvailabilityLevel::Full,
+                cognitive: AvailabilityLevel::Full,
+                device: DeviceAccess::PhoneOnly,
+                mobility: Mobility::InTransit,
+            }
+        );
+        assert_eq!(
+            CapabilitySet::cycling(),
+            CapabilitySet {
+                hands: AvailabilityLevel::None,
+                eyes: AvailabilityLevel::Limited,
+                speech: AvailabilityLevel::Full,
+                cognitive: AvailabilityLevel::Limited,
+                device: DeviceAccess::None,
+                mobility: Mobility::InTransit,
+            }
+        );
+    }
+
     #[test]
     fn test_location_constraint_any() {
         let constraint = LocationConstraint::Any;
@@ -288,4 +619,53 @@ mod tests {
         ).unwrap();
         assert!(!constraint.matches(Some(&other)));
     }
+
+    #[test]
+    fn test_location_constraint_must_be_within() {
+        let home = Location::new(
+            Some("Home".to_string()),
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(48.8566, 2.3522).unwrap(),
+        ).unwrap();
+        let constraint = LocationConstraint::MustBeWithin { locations: vec![home], radius_meters: 100.0 };
+        let reading = |latitude| Location::new(
+            None,
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(latitude, 2.3522).unwrap(),
+        ).unwrap();
+
+        // ~50 m north of home
+        assert!(constraint.matches(Some(&reading(48.85705))));
+        // ~2 km north of home
+        assert!(!constraint.matches(Some(&reading(48.8746))));
+        assert!(!constraint.matches(None));
+    }
+
+    #[test]
+    fn test_location_constraint_must_be_near() {
+        let office = GeoCoordinates::new(48.8566, 2.3522).unwrap();
+        let constraint = LocationConstraint::MustBeNear { center: office, radius_meters: 5_000.0 };
+        
+        // ~2 km north, with a different name and spelling of the city
+        let nearby = Location::new(
+            Some("Café".to_string()),
+            "Paris 18e".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(48.8746, 2.3522).unwrap(),
+        ).unwrap();
+        assert!(constraint.matches(Some(&nearby)));
+        
+        // ~50 km north
+        let far = Location::new(
+            None,
+            "Chantilly".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(49.3062, 2.3522).unwrap(),
+        ).unwrap();
+        assert!(!constraint.matches(Some(&far)));
+        
+        assert!(!constraint.matches(None));
+    }
 }
@@ -11,7 +11,9 @@ pub use periodicity::{
     // Constraints
     DayConstraint,
     WeekConstraint,
+    WeekCountingMode,
     MonthConstraint,
+    MonthAnchor,
     YearConstraint,
     MonthWeekPosition,
     NthWeekdayOfMonth,
@@ -19,15 +21,18 @@ pub use periodicity::{
     // Timing settings
     OccurrenceTimingSettings,
     RepTimingSettings,
+    OccurrenceIter,
     
     // Builder and validation
     PeriodicityBuilder,
     ValidationError as PeriodicityValidationError,
 };
 
+#[allow(clippy::module_inception)]
 pub mod task;
 pub use task::{
     Task,
+    TaskBuilder,
     TaskStatus,
     TaskPriority,
     TaskValidationError,
@@ -37,7 +42,14 @@ pub mod task_occurrence;
 pub use task_occurrence::{
     TaskOccurrence,
     TaskOccurrenceValidationError,
+    SkipPolicy,
+    completion_heatmap,
+    completion_rate,
+    current_streak,
+    current_streak_at,
+    occurrences_to_csv,
+    upcoming_reminders,
 };
 
 pub mod occurrence_rep;
-pub use occurrence_rep::OccurenceRep;
\ No newline at end of file
+pub use occurrence_rep::{OccurenceRep, RepState};
\ No newline at end of file
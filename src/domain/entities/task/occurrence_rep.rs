@@ -1,7 +1,25 @@
 use chrono::{DateTime, Utc};
+use crate::domain::clock::{Clock, SystemClock};
 use crate::domain::entities::task::TaskOccurrenceValidationError;
 use crate::config;
 
+// ========================================================================
+// REP STATE
+// ========================================================================
+
+/// Where a single repetition stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RepState {
+    /// Not done yet
+    #[default]
+    Pending,
+    /// Done
+    Completed,
+    /// Intentionally not done (distinct from "not done yet")
+    Skipped,
+}
+
 // ========================================================================
 // REPETITION OCCURRENCE - A single rep within a TaskOccurrence
 // ========================================================================
@@ -10,20 +28,29 @@ use crate::config;
 /// 
 /// For a task "Exercise 3 times daily", each of the 3 reps is a OccurenceRep.
 /// Each rep can be completed independently and have its own notes.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Its weight sets how much it counts towards the occurrence's progress.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OccurenceRep {
     /// Index of this repetition (0-based: 0 = first rep, 1 = second rep, etc.)
     rep_index: u8,
     
-    /// Whether this specific repetition is completed
-    completed: bool,
+    /// Whether this specific repetition is pending, completed or skipped
+    state: RepState,
     
     /// When this repetition was completed
     completed_at: Option<DateTime<Utc>>,
     
+    /// Why this repetition was skipped, if a reason was given
+    skip_reason: Option<String>,
+    
     /// Optional notes specific to this repetition
     /// Example: "Did push-ups" vs "Did squats" for different reps
     notes: Option<String>,
+    
+    /// Relative importance for progress (e.g., main set 3.0 vs warmup 1.0)
+    /// Always positive; 1.0 by default so all reps count equally
+    weight: f32,
 }
 
 impl OccurenceRep {
@@ -35,9 +62,11 @@ impl OccurenceRep {
     pub fn new(rep_index: u8) -> Self {
         Self {
             rep_index,
-            completed: false,
+            state: RepState::Pending,
             completed_at: None,
+            skip_reason: None,
             notes: None,
+            weight: 1.0,
         }
     }
 
@@ -47,32 +76,91 @@ impl OccurenceRep {
         self.rep_index
     }
 
+    pub fn state(&self) -> RepState {
+        self.state
+    }
+
     pub fn is_completed(&self) -> bool {
-        self.completed
+        self.state == RepState::Completed
+    }
+
+    pub fn is_skipped(&self) -> bool {
+        self.state == RepState::Skipped
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.state == RepState::Pending
     }
 
     pub fn completed_at(&self) -> Option<DateTime<Utc>> {
         self.completed_at
     }
 
+    pub fn skip_reason(&self) -> Option<&str> {
+        self.skip_reason.as_deref()
+    }
+
     pub fn notes(&self) -> Option<&str> {
         self.notes.as_deref()
     }
 
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
     // ── BEHAVIORS ───────────────────────────────────────────
 
     pub fn mark_complete(&mut self) {
-        if !self.completed {
-            self.completed = true;
-            self.completed_at = Some(Utc::now());
+        self.mark_complete_with(&SystemClock);
+    }
+
+    /// Mark complete as of `clock`'s current time
+    pub fn mark_complete_with(&mut self, clock: &dyn Clock) {
+        self.mark_complete_at(clock.now());
+    }
+
+    /// Mark complete as of `at`; a rep already completed keeps its time
+    pub fn mark_complete_at(&mut self, at: DateTime<Utc>) {
+        if self.state != RepState::Completed {
+            self.state = RepState::Completed;
+            self.completed_at = Some(at);
+            self.skip_reason = None;
         }
     }
 
+    /// Reset to pending, from either completed or skipped
     pub fn mark_incomplete(&mut self) {
-        if self.completed {
-            self.completed = false;
-            self.completed_at = None;
+        self.state = RepState::Pending;
+        self.completed_at = None;
+        self.skip_reason = None;
+    }
+
+    /// Record that this repetition was intentionally skipped
+    pub fn mark_skipped(&mut self, reason: Option<String>) -> Result<(), TaskOccurrenceValidationError> {
+        let reason = reason.map(|r| r.trim().to_string());
+        if let Some(ref r) = reason {
+            if r.len() > Self::max_notes_length() {
+                return Err(TaskOccurrenceValidationError::NotesTooLong {
+                    max: Self::max_notes_length(),
+                    actual: r.len(),
+                });
+            }
+        }
+        self.state = RepState::Skipped;
+        self.completed_at = None;
+        self.skip_reason = reason;
+        Ok(())
+    }
+
+    /// Set the relative importance of this repetition (must be positive)
+    pub fn set_weight(&mut self, weight: f32) -> Result<(), TaskOccurrenceValidationError> {
+        if !weight.is_finite() || weight <= 0.0 {
+            return Err(TaskOccurrenceValidationError::InvalidWeight {
+                reason: format!("weight must be a positive number, got {}", weight),
+            });
         }
+        self.weight = weight;
+        Ok(())
     }
 
     pub fn set_notes(&mut self, notes: Option<String>) -> Result<(), TaskOccurrenceValidationError> {
@@ -1,8 +1,11 @@
-use chrono::{DateTime, Utc, Weekday, Month, TimeZone};
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc, Weekday, Month, TimeZone};
 use super::{
     DayConstraint, MonthConstraint, MonthWeekPosition, Periodicity, PeriodicityConstraints,
     SpecialPattern, WeekConstraint, YearConstraint, CustomDates, UniqueDate,
-    RepetitionUnit, OccurrenceTimingSettings, NthWeekdayOfMonth,
+    RepetitionUnit, OccurrenceTimingSettings, RepTimingSettings, NthWeekdayOfMonth, MonthAnchor,
+    WeekCountingMode,
 };
 use super::validation;
 
@@ -39,6 +42,15 @@ pub struct PeriodicityBuilder {
     timeframe: Option<(DateTime<Utc>, DateTime<Utc>)>,
     special_pattern: Option<SpecialPattern>,
     reference_date: Option<DateTime<Utc>>,
+    month_anchor: Option<MonthAnchor>,
+    exclusions: BTreeSet<NaiveDate>,
+    max_occurrences: Option<u32>,
+    year_start: Option<Month>,
+    week_counting: WeekCountingMode,
+    /// Set by `first_occurrence_on` to check the anchor in `build()`
+    check_anchor: bool,
+    /// First invalid setter input, surfaced by `build()`
+    deferred_error: Option<validation::ValidationError>,
 }
 
 impl Default for PeriodicityBuilder {
@@ -61,7 +73,27 @@ impl PeriodicityBuilder {
             timeframe: None,
             special_pattern: None,
             reference_date: None,
+            month_anchor: None,
+            exclusions: BTreeSet::new(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: WeekCountingMode::default(),
+            check_anchor: false,
+            deferred_error: None,
+        }
+    }
+    
+    /// Converts 1-indexed setter input to 0-indexed values, recording an
+    /// error for `build()` if any value is 0
+    fn zero_indexed(&mut self, field: &str, values: Vec<u8>) -> Vec<u8> {
+        if self.deferred_error.is_none() && values.contains(&0) {
+            self.deferred_error = Some(validation::ValidationError::InvalidValue {
+                field: field.into(),
+                value: format!("{:?}", values),
+                reason: "Values are 1-indexed and must be at least 1".into(),
+            });
         }
+        values.into_iter().map(|v| v.saturating_sub(1)).collect()
     }
     
     // ────────────────────────────────────────────────────────
@@ -118,18 +150,23 @@ impl PeriodicityBuilder {
         self
     }
     
+    /// Occurs on every nth given weekday, counted from the reference date
+    /// (e.g., `every_nth_weekday(Weekday::Tue, 2)` for every other Tuesday)
+    pub fn every_nth_weekday(mut self, weekday: Weekday, n: u8) -> Self {
+        self.day_constraint = Some(DayConstraint::EveryNthWeekday { weekday, n });
+        self
+    }
+    
     /// Occurs on specific days of the month (1-31)
     pub fn on_month_days(mut self, days: Vec<u8>) -> Self {
-        // Convert 1-indexed to 0-indexed
-        let zero_indexed: Vec<u8> = days.into_iter().map(|d| d.saturating_sub(1)).collect();
+        let zero_indexed = self.zero_indexed("SpecificDaysMonthFromFirst", days);
         self.day_constraint = Some(DayConstraint::SpecificDaysMonthFromFirst(zero_indexed));
         self
     }
     
     /// Occurs on specific days from end of month (1 = last day, 2 = second-to-last, etc.)
     pub fn on_month_days_from_end(mut self, days: Vec<u8>) -> Self {
-        // Convert 1-indexed to 0-indexed
-        let zero_indexed: Vec<u8> = days.into_iter().map(|d| d.saturating_sub(1)).collect();
+        let zero_indexed = self.zero_indexed("SpecificDaysMonthFromLast", days);
         self.day_constraint = Some(DayConstraint::SpecificDaysMonthFromLast(zero_indexed));
         self
     }
@@ -141,6 +178,37 @@ impl PeriodicityBuilder {
         self
     }
     
+    /// Occurs on the last given weekday of the month (e.g., last Friday)
+    /// Stacks with other nth-weekday patterns
+    pub fn on_last_weekday(self, weekday: Weekday) -> Self {
+        self.push_nth_weekday(NthWeekdayOfMonth::last(weekday))
+    }
+    
+    /// Occurs on the nth given weekday counted from the end of the month
+    /// (1 = last, 2 = second-to-last, etc.)
+    /// Stacks with other nth-weekday patterns
+    pub fn on_nth_weekday_from_end(mut self, weekday: Weekday, n: u8) -> Self {
+        let index = self.zero_indexed("SpecificNthWeekdaysMonth", vec![n])[0];
+        self.push_nth_weekday(NthWeekdayOfMonth {
+            weekday,
+            position: MonthWeekPosition::FromLast(index),
+        })
+    }
+    
+    /// Adds to an existing nth-weekday constraint, or replaces any other
+    /// day constraint
+    fn push_nth_weekday(mut self, pattern: NthWeekdayOfMonth) -> Self {
+        match &mut self.day_constraint {
+            Some(DayConstraint::SpecificNthWeekdaysMonth(patterns)) => {
+                if !patterns.contains(&pattern) {
+                    patterns.push(pattern);
+                }
+            }
+            _ => self.day_constraint = Some(DayConstraint::SpecificNthWeekdaysMonth(vec![pattern])),
+        }
+        self
+    }
+    
     // ────────────────────────────────────────────────────────
     // WEEK CONSTRAINT SETTERS
     // ────────────────────────────────────────────────────────
@@ -159,20 +227,24 @@ impl PeriodicityBuilder {
     
     /// Occurs on specific weeks of the month from start (1-5)
     pub fn on_weeks_of_month(mut self, weeks: Vec<u8>) -> Self {
-        // Convert 1-indexed to 0-indexed
-        let zero_indexed: Vec<u8> = weeks.into_iter().map(|w| w.saturating_sub(1)).collect();
+        let zero_indexed = self.zero_indexed("SpecificWeeksOfMonthFromFirst", weeks);
         self.week_constraint = Some(WeekConstraint::SpecificWeeksOfMonthFromFirst(zero_indexed));
         self
     }
     
     /// Occurs on specific weeks from end of month (1 = last week, 2 = second-to-last, etc.)
     pub fn on_weeks_of_month_from_end(mut self, weeks: Vec<u8>) -> Self {
-        // Convert 1-indexed to 0-indexed
-        let zero_indexed: Vec<u8> = weeks.into_iter().map(|w| w.saturating_sub(1)).collect();
+        let zero_indexed = self.zero_indexed("SpecificWeeksOfMonthFromLast", weeks);
         self.week_constraint = Some(WeekConstraint::SpecificWeeksOfMonthFromLast(zero_indexed));
         self
     }
     
+    /// Chooses how weeks of the month are numbered (default: aligned to week start)
+    pub fn count_weeks_by(mut self, mode: WeekCountingMode) -> Self {
+        self.week_counting = mode;
+        self
+    }
+    
     // ────────────────────────────────────────────────────────
     // MONTH CONSTRAINT SETTERS
     // ────────────────────────────────────────────────────────
@@ -189,6 +261,12 @@ impl PeriodicityBuilder {
         self
     }
     
+    /// Pins an `every_n_months` cadence to a specific day of each matching month
+    pub fn anchored_to(mut self, anchor: MonthAnchor) -> Self {
+        self.month_anchor = Some(anchor);
+        self
+    }
+    
     /// Occurs in specific months
     pub fn in_months(mut self, months: Vec<Month>) -> Self {
         self.month_constraint = Some(MonthConstraint::SpecificMonths(months));
@@ -217,6 +295,13 @@ impl PeriodicityBuilder {
         self
     }
     
+    /// Counts years as fiscal years starting in `month` (e.g., April),
+    /// labelled by the calendar year they start in
+    pub fn fiscal_year_starting(mut self, month: Month) -> Self {
+        self.year_start = Some(month);
+        self
+    }
+    
     // ────────────────────────────────────────────────────────
     // SPECIAL PATTERN SETTERS
     // ────────────────────────────────────────────────────────
@@ -260,6 +345,24 @@ impl PeriodicityBuilder {
         self
     }
     
+    // ────────────────────────────────────────────────────────
+    // EXCLUSIONS
+    // ────────────────────────────────────────────────────────
+    
+    /// Excludes specific calendar days (e.g., holidays); adds to any
+    /// previously excluded days
+    pub fn except_dates(mut self, dates: Vec<NaiveDate>) -> Self {
+        self.exclusions.extend(dates);
+        self
+    }
+    
+    /// Stops the recurrence after `n` occurrences, counted from the
+    /// reference date (or timeframe start), one per `rep_unit` period
+    pub fn limit_occurrences(mut self, n: u32) -> Self {
+        self.max_occurrences = Some(n);
+        self
+    }
+    
     // ────────────────────────────────────────────────────────
     // REFERENCE DATE
     // ────────────────────────────────────────────────────────
@@ -277,6 +380,18 @@ impl PeriodicityBuilder {
         self
     }
     
+    /// Anchors EveryN* counters on the task's first occurrence
+    ///
+    /// Same as `with_reference_date`, except `build()` also rejects a date
+    /// that does not itself satisfy the EveryN* pattern (e.g. a Wednesday
+    /// for "every other Tuesday"), and EveryNWeeks weeks start on the
+    /// date's weekday rather than the user's week start.
+    pub fn first_occurrence_on(mut self, date: DateTime<Utc>) -> Self {
+        self.reference_date = Some(date);
+        self.check_anchor = true;
+        self
+    }
+    
     // ────────────────────────────────────────────────────────
     // OCCURRENCE TIMING SETTINGS
     // ────────────────────────────────────────────────────────
@@ -309,12 +424,50 @@ impl PeriodicityBuilder {
         self
     }
     
+    /// Repeats daily at fixed times (e.g., a reminder at 08:00 and 20:00)
+    ///
+    /// Sets `daily(times.len())` and pins each repetition to its time, in the
+    /// given order, by setting its `not_before` and `best_before` to that time.
+    /// Other occurrence settings (e.g., duration) are kept. More than 255
+    /// times (the most `rep_per_unit` can hold) fail in `build()`.
+    pub fn at_times(mut self, times: Vec<NaiveTime>) -> Self {
+        if self.deferred_error.is_none() && times.len() > u8::MAX as usize {
+            self.deferred_error = Some(validation::ValidationError::InvalidValue {
+                field: "at_times".into(),
+                value: times.len().to_string(),
+                reason: format!("At most {} times per day", u8::MAX),
+            });
+        }
+        let rep_timing_settings = times.iter()
+            .zip(0..=u8::MAX)
+            .map(|(&time, rep_index)| RepTimingSettings {
+                rep_index,
+                not_before: Some(time),
+                best_before: Some(time),
+            })
+            .collect();
+        
+        let settings = self.occurrence_settings.get_or_insert(OccurrenceTimingSettings {
+            duration: None,
+            not_before: None,
+            best_before: None,
+            rep_timing_settings: None,
+        });
+        settings.rep_timing_settings = Some(rep_timing_settings);
+        
+        self.daily(u8::try_from(times.len()).unwrap_or(u8::MAX))
+    }
+    
     // ────────────────────────────────────────────────────────
     // BUILD
     // ────────────────────────────────────────────────────────
     
     /// Builds and validates the Periodicity instance
     pub fn build(self) -> Result<Periodicity, validation::ValidationError> {
+        if let Some(error) = self.deferred_error {
+            return Err(error);
+        }
+        
         let periodicity = Periodicity {
             rep_unit: self.rep_unit.unwrap_or(RepetitionUnit::None),
             rep_per_unit: self.rep_per_unit,
@@ -328,11 +481,27 @@ impl PeriodicityBuilder {
             timeframe: self.timeframe,
             special_pattern: self.special_pattern,
             reference_date: self.reference_date,
+            reference_is_first_occurrence: self.check_anchor,
+            month_anchor: self.month_anchor,
+            exclusions: self.exclusions,
+            max_occurrences: self.max_occurrences,
+            year_start: self.year_start,
+            week_counting: self.week_counting,
         };
         
         // Validate before returning
         periodicity.validate()?;
         
+        if let (true, Some(date)) = (self.check_anchor, periodicity.reference_date) {
+            if !periodicity.satisfies_base_frequency(&date) {
+                return Err(validation::ValidationError::InvalidValue {
+                    field: "first_occurrence".into(),
+                    value: date.date_naive().to_string(),
+                    reason: "First occurrence does not match the repetition pattern".into(),
+                });
+            }
+        }
+        
         Ok(periodicity)
     }
 }
@@ -460,7 +629,7 @@ impl NthWeekdayOfMonth {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{Utc, Weekday, Month};
+    use chrono::{Datelike, Utc, Weekday, Month};
     
     #[test]
     fn test_builder_daily_on_weekdays() {
@@ -513,4 +682,162 @@ mod tests {
         assert_eq!(periodicity.rep_unit, RepetitionUnit::None);
         assert!(periodicity.special_pattern.is_some());
     }
+    
+    #[test]
+    fn test_last_weekday_helpers_stack() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_last_weekday(Weekday::Fri)
+            .on_nth_weekday_from_end(Weekday::Fri, 2)
+            .build()
+            .unwrap();
+        
+        assert_eq!(
+            periodicity.constraints.day_constraint,
+            Some(DayConstraint::SpecificNthWeekdaysMonth(vec![
+                NthWeekdayOfMonth::last(Weekday::Fri),
+                NthWeekdayOfMonth::second_last(Weekday::Fri),
+            ]))
+        );
+        
+        // January 2026: Fridays 23rd and 30th, Saturday 31st
+        let jan = |day| Utc.with_ymd_and_hms(2026, 1, day, 12, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&jan(30), Weekday::Mon));
+        assert!(periodicity.matches_constraints(&jan(23), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&jan(31), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&jan(16), Weekday::Mon));
+    }
+    
+    #[test]
+    fn test_zero_indexed_inputs_are_rejected() {
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![0, 15])
+            .build();
+        assert!(matches!(result, Err(validation::ValidationError::InvalidValue { .. })));
+        
+        assert!(PeriodicityBuilder::new().daily(1).on_month_days_from_end(vec![0]).build().is_err());
+        assert!(PeriodicityBuilder::new().daily(1).on_weeks_of_month(vec![1, 0]).build().is_err());
+        assert!(PeriodicityBuilder::new().daily(1).on_weeks_of_month_from_end(vec![0]).build().is_err());
+        assert!(PeriodicityBuilder::new().daily(1).on_nth_weekday_from_end(Weekday::Fri, 0).build().is_err());
+        
+        // A later valid setter does not clear the error
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![0])
+            .on_month_days(vec![1])
+            .build();
+        assert!(result.is_err());
+    }
+    
+    #[test]
+    fn test_first_occurrence_anchors_weekly_pattern() {
+        // Wednesday 2026-01-07, every other week
+        let first = Utc.with_ymd_and_hms(2026, 1, 7, 9, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .weekly(1)
+            .every_n_weeks(2)
+            .first_occurrence_on(first)
+            .build()
+            .unwrap();
+        
+        assert_eq!(periodicity.reference_date, Some(first));
+        let jan = |day| Utc.with_ymd_and_hms(2026, 1, day, 12, 0, 0).unwrap();
+        
+        // Weeks run Wednesday to Tuesday, even with Monday as week start
+        assert!(periodicity.matches_constraints(&jan(13), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&jan(14), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&jan(19), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&jan(20), Weekday::Mon));
+        assert!(periodicity.matches_constraints(&jan(21), Weekday::Mon));
+        
+        // Each active week starts on a Wednesday
+        let days = periodicity.occurrences_between(&jan(1), &Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap(), Weekday::Mon);
+        let week_starts: Vec<_> = days.iter()
+            .filter(|day| !days.contains(&(**day - chrono::Duration::days(1))))
+            .map(|day| day.date_naive())
+            .collect();
+        assert_eq!(week_starts, vec![
+            NaiveDate::from_ymd_opt(2026, 1, 7).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 21).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 2, 4).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 2, 18).unwrap(),
+        ]);
+        assert!(week_starts.iter().all(|day| day.weekday() == Weekday::Wed));
+        assert_eq!(periodicity.next_occurrence_after(&jan(13), Weekday::Mon), Some(Utc.with_ymd_and_hms(2026, 1, 21, 0, 0, 0).unwrap()));
+    }
+    
+    #[test]
+    fn test_mid_week_reference_date_keeps_week_start() {
+        // Thursday 2026-01-08 as a plain reference: weeks still run Monday to Sunday
+        let reference = Utc.with_ymd_and_hms(2026, 1, 8, 9, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .weekly(1)
+            .every_n_weeks(2)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        
+        assert!(!periodicity.reference_is_first_occurrence);
+        let jan = |day| Utc.with_ymd_and_hms(2026, 1, day, 12, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&jan(11), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&jan(12), Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&jan(18), Weekday::Mon));
+        assert!(periodicity.matches_constraints(&jan(19), Weekday::Mon));
+        
+        // With a Sunday week start, the active week moves with it
+        assert!(periodicity.matches_constraints(&jan(10), Weekday::Sun));
+        assert!(!periodicity.matches_constraints(&jan(11), Weekday::Sun));
+        assert!(periodicity.matches_constraints(&jan(18), Weekday::Sun));
+    }
+    
+    #[test]
+    fn test_first_occurrence_must_match_pattern() {
+        let wednesday = Utc.with_ymd_and_hms(2026, 1, 7, 9, 0, 0).unwrap();
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 2)
+            .first_occurrence_on(wednesday)
+            .build();
+        assert!(matches!(result, Err(validation::ValidationError::InvalidValue { .. })));
+        
+        // The plain reference date setter does not check
+        assert!(PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 2)
+            .with_reference_date(wednesday)
+            .build()
+            .is_ok());
+    }
+    
+    #[test]
+    fn test_at_times_pins_each_rep() {
+        let morning = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+        let evening = NaiveTime::from_hms_opt(20, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .at_times(vec![morning, evening])
+            .build()
+            .unwrap();
+        
+        assert_eq!(periodicity.rep_unit, RepetitionUnit::Day);
+        assert_eq!(periodicity.rep_per_unit, Some(2));
+        assert_eq!(
+            periodicity.occurrence_settings.unwrap().rep_timing_settings,
+            Some(vec![
+                RepTimingSettings { rep_index: 0, not_before: Some(morning), best_before: Some(morning) },
+                RepTimingSettings { rep_index: 1, not_before: Some(evening), best_before: Some(evening) },
+            ])
+        );
+    }
+    
+    #[test]
+    fn test_at_times_rejects_more_than_255_times() {
+        let every_minute: Vec<NaiveTime> = (0..256)
+            .map(|minute| NaiveTime::from_hms_opt(minute / 60, minute % 60, 0).unwrap())
+            .collect();
+        let result = PeriodicityBuilder::new()
+            .at_times(every_minute)
+            .build();
+        assert!(matches!(result, Err(validation::ValidationError::InvalidValue { field, .. }) if field == "at_times"));
+    }
 }
@@ -0,0 +1,292 @@
+use chrono::{Month, NaiveTime, Weekday};
+use super::{
+    DayConstraint, MonthConstraint, OccurrenceTimingSettings, Periodicity,
+    PeriodicityConstraints, RepetitionUnit,
+};
+use super::validation::ValidationError;
+
+// ========================================================================
+// CRON PARSING
+// Translate 5-field cron expressions into daily periodicities
+// ========================================================================
+
+impl Periodicity {
+    /// Parses a 5-field cron expression (minute hour day-of-month month day-of-week)
+    ///
+    /// # Supported Syntax
+    /// - minute and hour must be single numbers; they become `not_before`
+    /// - day-of-month, month and day-of-week accept `*`, numbers, comma lists
+    ///   and inclusive ranges (`1-5`)
+    /// - day-of-week uses 0-7, where both 0 and 7 are Sunday
+    ///
+    /// Steps (`*/2`), names (`MON`, `JAN`) and the `?`/`L`/`W`/`#` extensions are
+    /// rejected. Restricting both day-of-month and day-of-week is also rejected,
+    /// since cron ORs those fields while constraints are always ANDed.
+    ///
+    /// # Example
+    /// ```
+    /// use tsadaash::domain::Periodicity;
+    ///
+    /// let weekdays_at_nine = Periodicity::from_cron("0 9 * * 1-5").unwrap();
+    /// # assert!(weekdays_at_nine.occurrence_settings.is_some());
+    /// ```
+    pub fn from_cron(expr: &str) -> Result<Periodicity, ValidationError> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(ValidationError::InvalidValue {
+                field: "cron".into(),
+                value: expr.to_string(),
+                reason: format!("Expected 5 fields, found {}", fields.len()),
+            });
+        }
+
+        let minute = parse_single(fields[0], "cron.minute", 0, 59)?;
+        let hour = parse_single(fields[1], "cron.hour", 0, 23)?;
+        let days_of_month = parse_list(fields[2], "cron.day_of_month", 1, 31)?;
+        let months = parse_list(fields[3], "cron.month", 1, 12)?;
+        let days_of_week = parse_list(fields[4], "cron.day_of_week", 0, 7)?;
+
+        let day_constraint = match (days_of_month, days_of_week) {
+            (Some(_), Some(_)) => {
+                return Err(ValidationError::ConflictingConstraints {
+                    constraint1: "cron.day_of_month".into(),
+                    constraint2: "cron.day_of_week".into(),
+                    reason: "Cron matches either field when both are set, which cannot be expressed".into(),
+                });
+            }
+            (Some(days), None) => Some(DayConstraint::SpecificDaysMonthFromFirst(
+                days.into_iter().map(|d| (d - 1) as u8).collect(),
+            )),
+            (None, Some(days)) => {
+                let mut weekdays: Vec<Weekday> = Vec::new();
+                for day in days {
+                    let weekday = cron_weekday(day);
+                    if !weekdays.contains(&weekday) {
+                        weekdays.push(weekday);
+                    }
+                }
+                Some(DayConstraint::SpecificDaysWeek(weekdays))
+            }
+            (None, None) => Some(DayConstraint::EveryDay),
+        };
+
+        let month_constraint = months.map(|months| {
+            MonthConstraint::SpecificMonths(
+                months.into_iter()
+                    .map(|m| Month::try_from(m as u8).expect("month range already checked"))
+                    .collect(),
+            )
+        });
+
+        let periodicity = Periodicity {
+            rep_unit: RepetitionUnit::Day,
+            rep_per_unit: Some(1),
+            occurrence_settings: Some(OccurrenceTimingSettings {
+                duration: None,
+                not_before: NaiveTime::from_hms_opt(hour, minute, 0),
+                best_before: None,
+                rep_timing_settings: None,
+            }),
+            constraints: PeriodicityConstraints {
+                day_constraint,
+                month_constraint,
+                ..Default::default()
+            },
+            timeframe: None,
+            special_pattern: None,
+            reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
+        };
+
+        periodicity.validate()?;
+
+        Ok(periodicity)
+    }
+}
+
+// ── FIELD PARSERS ────────────────────────────────────────
+
+/// Parses a field that must hold exactly one number
+fn parse_single(field: &str, name: &str, min: u32, max: u32) -> Result<u32, ValidationError> {
+    if field == "*" || field.contains([',', '-', '/']) {
+        return Err(ValidationError::InvalidValue {
+            field: name.into(),
+            value: field.to_string(),
+            reason: "Only a single number is supported for this field".into(),
+        });
+    }
+    parse_number(field, name, min, max)
+}
+
+/// Parses `*` (None) or a comma list of numbers and ranges, sorted and deduplicated
+fn parse_list(field: &str, name: &str, min: u32, max: u32) -> Result<Option<Vec<u32>>, ValidationError> {
+    if field == "*" {
+        return Ok(None);
+    }
+
+    let mut values = Vec::new();
+    for part in field.split(',') {
+        if part.contains('/') {
+            return Err(ValidationError::InvalidValue {
+                field: name.into(),
+                value: field.to_string(),
+                reason: "Step values are not supported".into(),
+            });
+        }
+
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let start = parse_number(start, name, min, max)?;
+                let end = parse_number(end, name, min, max)?;
+                if start > end {
+                    return Err(ValidationError::InvalidValue {
+                        field: name.into(),
+                        value: part.to_string(),
+                        reason: "Range start must not exceed range end".into(),
+                    });
+                }
+                values.extend(start..=end);
+            }
+            None => values.push(parse_number(part, name, min, max)?),
+        }
+    }
+
+    values.sort_unstable();
+    values.dedup();
+    Ok(Some(values))
+}
+
+fn parse_number(value: &str, name: &str, min: u32, max: u32) -> Result<u32, ValidationError> {
+    let number: u32 = value.parse().map_err(|_| ValidationError::InvalidValue {
+        field: name.into(),
+        value: value.to_string(),
+        reason: "Expected a number".into(),
+    })?;
+
+    if !(min..=max).contains(&number) {
+        return Err(ValidationError::OutOfRange {
+            field: name.into(),
+            value: number.to_string(),
+            min: min.to_string(),
+            max: max.to_string(),
+        });
+    }
+
+    Ok(number)
+}
+
+/// Maps cron day-of-week numbers (0 and 7 = Sunday) to chrono weekdays
+fn cron_weekday(day: u32) -> Weekday {
+    match day {
+        1 => Weekday::Mon,
+        2 => Weekday::Tue,
+        3 => Weekday::Wed,
+        4 => Weekday::Thu,
+        5 => Weekday::Fri,
+        6 => Weekday::Sat,
+        _ => Weekday::Sun,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cron_weekdays_at_nine() {
+        let periodicity = Periodicity::from_cron("0 9 * * 1-5").unwrap();
+
+        assert_eq!(periodicity.rep_unit, RepetitionUnit::Day);
+        assert_eq!(periodicity.rep_per_unit, Some(1));
+        assert_eq!(
+            periodicity.constraints.day_constraint,
+            Some(DayConstraint::SpecificDaysWeek(vec![
+                Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri,
+            ]))
+        );
+        assert_eq!(periodicity.constraints.month_constraint, None);
+        assert_eq!(
+            periodicity.occurrence_settings.unwrap().not_before,
+            NaiveTime::from_hms_opt(9, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_cron_month_days_and_months() {
+        let periodicity = Periodicity::from_cron("30 7 1,15 1,6 *").unwrap();
+
+        assert_eq!(
+            periodicity.constraints.day_constraint,
+            Some(DayConstraint::SpecificDaysMonthFromFirst(vec![0, 14]))
+        );
+        assert_eq!(
+            periodicity.constraints.month_constraint,
+            Some(MonthConstraint::SpecificMonths(vec![Month::January, Month::June]))
+        );
+        assert_eq!(
+            periodicity.occurrence_settings.unwrap().not_before,
+            NaiveTime::from_hms_opt(7, 30, 0)
+        );
+    }
+
+    #[test]
+    fn test_cron_sunday_aliases() {
+        let periodicity = Periodicity::from_cron("0 8 * * 0,7").unwrap();
+        assert_eq!(
+            periodicity.constraints.day_constraint,
+            Some(DayConstraint::SpecificDaysWeek(vec![Weekday::Sun]))
+        );
+    }
+
+    #[test]
+    fn test_cron_every_day() {
+        let periodicity = Periodicity::from_cron("0 6 * * *").unwrap();
+        assert_eq!(periodicity.constraints.day_constraint, Some(DayConstraint::EveryDay));
+    }
+
+    #[test]
+    fn test_cron_rejects_unsupported_syntax() {
+        // Steps
+        assert!(matches!(
+            Periodicity::from_cron("*/15 9 * * *"),
+            Err(ValidationError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            Periodicity::from_cron("0 9 */2 * *"),
+            Err(ValidationError::InvalidValue { .. })
+        ));
+        // Names
+        assert!(matches!(
+            Periodicity::from_cron("0 9 * * MON"),
+            Err(ValidationError::InvalidValue { .. })
+        ));
+        // Both day fields restricted
+        assert!(matches!(
+            Periodicity::from_cron("0 9 1 * 1"),
+            Err(ValidationError::ConflictingConstraints { .. })
+        ));
+        // Wrong field count
+        assert!(Periodicity::from_cron("0 9 * *").is_err());
+    }
+
+    #[test]
+    fn test_cron_rejects_out_of_range() {
+        assert!(matches!(
+            Periodicity::from_cron("0 24 * * *"),
+            Err(ValidationError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            Periodicity::from_cron("0 9 32 * *"),
+            Err(ValidationError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            Periodicity::from_cron("0 9 * 13 *"),
+            Err(ValidationError::OutOfRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,364 @@
+use chrono::Weekday;
+use super::{
+    DayConstraint, MonthAnchor, MonthConstraint, MonthWeekPosition, NthWeekdayOfMonth, Periodicity,
+    RepetitionUnit, SpecialPattern, WeekConstraint, YearConstraint,
+};
+
+// ========================================================================
+// HUMAN-READABLE DESCRIPTION
+// Deterministic natural-language summaries for UI display
+// ========================================================================
+
+impl Periodicity {
+    /// Describes this periodicity in plain English
+    ///
+    /// Composes the repetition frequency, each present constraint (day, week,
+    /// month, year) and the timeframe. Special patterns are described on their
+    /// own. Output depends only on the configuration, so it is safe to
+    /// snapshot-test.
+    ///
+    /// # Examples
+    /// ```
+    /// use tsadaash::domain::PeriodicityBuilder;
+    /// use chrono::{Month, Weekday};
+    ///
+    /// let periodicity = PeriodicityBuilder::new()
+    ///     .daily(3)
+    ///     .on_weekdays(vec![Weekday::Mon])
+    ///     .in_months(vec![Month::January, Month::February])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(periodicity.describe(), "3 times per day on Mondays in January and February");
+    /// ```
+    pub fn describe(&self) -> String {
+        if let Some(pattern) = &self.special_pattern {
+            return match pattern {
+                SpecialPattern::Unique(unique) => {
+                    format!("Once, on {}.", unique.date.format("%Y-%m-%d"))
+                }
+                SpecialPattern::Custom(custom) => {
+                    let dates: Vec<String> = custom.dates.iter()
+                        .map(|d| d.format("%Y-%m-%d").to_string())
+                        .collect();
+                    format!("On {}.", join_list(&dates))
+                }
+            };
+        }
+
+        let mut parts = vec![describe_frequency(self.rep_unit, self.rep_per_unit)];
+
+        let constraints = &self.constraints;
+        if let Some(day) = &constraints.day_constraint {
+            parts.extend(describe_day(day));
+        }
+        if let Some(week) = &constraints.week_constraint {
+            parts.extend(describe_week(week));
+        }
+        if let Some(month) = &constraints.month_constraint {
+            parts.extend(describe_month(month));
+        }
+        if let Some(anchor) = self.month_anchor {
+            parts.push(match anchor {
+                MonthAnchor::DayOfMonth => "on the same day of the month".to_string(),
+                MonthAnchor::LastDay => "on the last day".to_string(),
+                MonthAnchor::FirstDay => "on the first day".to_string(),
+            });
+        }
+        if let Some(year) = &constraints.year_constraint {
+            parts.extend(describe_year(year));
+        }
+
+        if !self.exclusions.is_empty() {
+            let days: Vec<String> = self.exclusions.iter().map(|d| d.to_string()).collect();
+            parts.push(format!("except {}", join_list(&days)));
+        }
+
+        if let Some(limit) = self.max_occurrences {
+            let noun = if limit == 1 { "occurrence" } else { "occurrences" };
+            parts.push(format!("for {} {}", limit, noun));
+        }
+
+        if let Some((start, end)) = &self.timeframe {
+            parts.push(format!(
+                "from {} until {}",
+                start.format("%Y-%m-%d"),
+                end.format("%Y-%m-%d"),
+            ));
+        }
+
+        parts.join(" ")
+    }
+}
+
+// ── PART DESCRIBERS ──────────────────────────────────────
+
+fn describe_frequency(unit: RepetitionUnit, count: Option<u8>) -> String {
+    let unit = match unit {
+        RepetitionUnit::Day => "day",
+        RepetitionUnit::Week => "week",
+        RepetitionUnit::Month => "month",
+        RepetitionUnit::Year => "year",
+        RepetitionUnit::None => return "Once".to_string(),
+    };
+
+    match count.unwrap_or(1) {
+        1 => format!("Once per {}", unit),
+        2 => format!("Twice per {}", unit),
+        n => format!("{} times per {}", n, unit),
+    }
+}
+
+/// Returns None for "every X" constraints, which add nothing to the sentence
+fn describe_day(constraint: &DayConstraint) -> Option<String> {
+    match constraint {
+        DayConstraint::EveryDay => None,
+        DayConstraint::EveryNDays(n) => Some(every_n(*n as u32, "day")),
+        DayConstraint::SpecificDaysWeek(weekdays) => {
+            let mut sorted = weekdays.clone();
+            sorted.sort_by_key(|d| d.num_days_from_monday());
+            sorted.dedup();
+
+            let weekday_set = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+            if sorted == weekday_set {
+                return Some("on weekdays".to_string());
+            }
+            if sorted == [Weekday::Sat, Weekday::Sun] {
+                return Some("on weekends".to_string());
+            }
+
+            let names: Vec<String> = sorted.iter().map(|d| format!("{}s", weekday_name(*d))).collect();
+            Some(format!("on {}", join_list(&names)))
+        }
+        DayConstraint::EveryNthWeekday { weekday, n } => {
+            let every = match n {
+                1 => "every".to_string(),
+                2 => "every other".to_string(),
+                n => format!("every {}", ordinal(*n as u32)),
+            };
+            Some(format!("{} {}", every, weekday_name(*weekday)))
+        }
+        DayConstraint::SpecificDaysMonthFromFirst(days) => {
+            let mut sorted = days.clone();
+            sorted.sort_unstable();
+            let names: Vec<String> = sorted.iter().map(|d| ordinal(*d as u32 + 1)).collect();
+            Some(format!("on the {} of the month", join_list(&names)))
+        }
+        DayConstraint::SpecificDaysMonthFromLast(days) => {
+            let mut sorted = days.clone();
+            sorted.sort_unstable();
+            let names: Vec<String> = sorted.iter().map(|d| from_last(*d)).collect();
+            let noun = if sorted.len() == 1 { "day" } else { "days" };
+            Some(format!("on the {} {} of the month", join_list(&names), noun))
+        }
+        DayConstraint::SpecificNthWeekdaysMonth(patterns) => {
+            let names: Vec<String> = patterns.iter().map(describe_nth_weekday).collect();
+            Some(format!("on the {} of the month", join_list(&names)))
+        }
+    }
+}
+
+fn describe_week(constraint: &WeekConstraint) -> Option<String> {
+    let (weeks, to_name): (&Vec<u8>, fn(u8) -> String) = match constraint {
+        WeekConstraint::EveryWeek => return None,
+        WeekConstraint::EveryNWeeks(n) => return Some(every_n(*n as u32, "week")),
+        WeekConstraint::SpecificWeeksOfMonthFromFirst(weeks) => (weeks, |w| ordinal(w as u32 + 1)),
+        WeekConstraint::SpecificWeeksOfMonthFromLast(weeks) => (weeks, from_last),
+    };
+
+    let mut sorted = weeks.clone();
+    sorted.sort_unstable();
+    let names: Vec<String> = sorted.into_iter().map(to_name).collect();
+    let noun = if names.len() == 1 { "week" } else { "weeks" };
+    Some(format!("in the {} {} of the month", join_list(&names), noun))
+}
+
+fn describe_month(constraint: &MonthConstraint) -> Option<String> {
+    match constraint {
+        MonthConstraint::EveryMonth => None,
+        MonthConstraint::EveryNMonths(n) => Some(every_n(*n as u32, "month")),
+        MonthConstraint::SpecificMonths(months) => {
+            let mut sorted = months.clone();
+            sorted.sort_by_key(|m| m.number_from_month());
+            let names: Vec<String> = sorted.iter().map(|m| m.name().to_string()).collect();
+            Some(format!("in {}", join_list(&names)))
+        }
+    }
+}
+
+fn describe_year(constraint: &YearConstraint) -> Option<String> {
+    match constraint {
+        YearConstraint::EveryYear => None,
+        YearConstraint::EveryNYears(n) => Some(every_n(*n as u32, "year")),
+        YearConstraint::SpecificYears(years) => {
+            let mut sorted = years.clone();
+            sorted.sort_unstable();
+            let names: Vec<String> = sorted.iter().map(|y| y.to_string()).collect();
+            Some(format!("in {}", join_list(&names)))
+        }
+    }
+}
+
+fn describe_nth_weekday(pattern: &NthWeekdayOfMonth) -> String {
+    let position = match pattern.position {
+        MonthWeekPosition::FromFirst(n) => ordinal(n as u32 + 1),
+        MonthWeekPosition::FromLast(n) => from_last(n),
+    };
+    format!("{} {}", position, weekday_name(pattern.weekday))
+}
+
+// ── WORDING HELPERS ──────────────────────────────────────
+
+fn every_n(n: u32, unit: &str) -> String {
+    if n == 1 {
+        format!("every {}", unit)
+    } else {
+        format!("every {} {}s", n, unit)
+    }
+}
+
+/// 0 = "last", 1 = "2nd-to-last", ...
+fn from_last(n: u8) -> String {
+    if n == 0 {
+        "last".to_string()
+    } else {
+        format!("{}-to-last", ordinal(n as u32 + 1))
+    }
+}
+
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+/// Joins items as "a", "a and b" or "a, b and c"
+fn join_list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [single] => single.clone(),
+        [init @ .., last] => format!("{} and {}", init.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::task::periodicity::PeriodicityBuilder;
+    use chrono::{Month, TimeZone, Utc};
+
+    #[test]
+    fn test_describe_daily_on_weekdays() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Fri, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu])
+            .build()
+            .unwrap();
+
+        assert_eq!(periodicity.describe(), "Once per day on weekdays");
+    }
+
+    #[test]
+    fn test_describe_specific_weekdays_and_months() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(3)
+            .on_weekdays(vec![Weekday::Wed, Weekday::Mon])
+            .in_months(vec![Month::February, Month::January])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            periodicity.describe(),
+            "3 times per day on Mondays and Wednesdays in January and February"
+        );
+    }
+
+    #[test]
+    fn test_describe_unique_date() {
+        let date = Utc.with_ymd_and_hms(2026, 12, 25, 0, 0, 0).unwrap();
+        let periodicity = Periodicity::unique(date).unwrap();
+
+        assert_eq!(periodicity.describe(), "Once, on 2026-12-25.");
+    }
+
+    #[test]
+    fn test_describe_nth_weekday_with_timeframe() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .monthly(1)
+            .on_nth_weekdays(vec![
+                NthWeekdayOfMonth::first(Weekday::Mon),
+                NthWeekdayOfMonth::last(Weekday::Fri),
+            ])
+            .between(start, end)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            periodicity.describe(),
+            "Once per month on the 1st Monday and last Friday of the month from 2026-01-01 until 2027-01-01"
+        );
+    }
+
+    #[test]
+    fn test_describe_month_anchor() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_months(2)
+            .anchored_to(MonthAnchor::LastDay)
+            .build()
+            .unwrap();
+
+        assert_eq!(periodicity.describe(), "Once per day every 2 months on the last day");
+    }
+
+    #[test]
+    fn test_describe_every_other_weekday() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 2)
+            .build()
+            .unwrap();
+
+        assert_eq!(periodicity.describe(), "Once per day every other Tuesday");
+    }
+
+    #[test]
+    fn test_describe_occurrence_limit() {
+        let periodicity = PeriodicityBuilder::new()
+            .weekly(1)
+            .limit_occurrences(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(periodicity.describe(), "Once per week for 10 occurrences");
+    }
+
+    #[test]
+    fn test_ordinal_suffixes() {
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(2), "2nd");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(22), "22nd");
+        assert_eq!(ordinal(31), "31st");
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, NaiveDate, Utc, Weekday};
+use super::Periodicity;
+
+// ========================================================================
+// OCCURRENCE ITERATOR
+// Lazily walk the days on which a periodicity occurs
+// ========================================================================
+
+/// Lazy iterator over the days (as midnight UTC) on which a periodicity occurs
+///
+/// Created by `Periodicity::occurrences_from`. Each step searches forward from
+/// the day after the previous occurrence, within the same bounded horizon as
+/// `next_occurrence_after`, so an open-ended periodicity can be consumed with
+/// `.take(n)` without picking an end date.
+///
+/// Stops at the timeframe end, once `max_occurrences` is reached, or when no
+/// further occurrence exists within the search horizon.
+#[derive(Debug, Clone)]
+pub struct OccurrenceIter<'a> {
+    periodicity: &'a Periodicity,
+    week_start: Weekday,
+    /// First day the next search starts from; None once exhausted
+    cursor: Option<NaiveDate>,
+    /// Day after the last occurrence allowed by `max_occurrences`
+    cutoff: Option<NaiveDate>,
+}
+
+impl Periodicity {
+    /// Iterates over occurrences from the calendar day of `start` onwards
+    ///
+    /// Applies constraints, exclusions, the timeframe and `max_occurrences`,
+    /// exactly like `occurrences_between` without an end bound.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{TimeZone, Utc, Weekday};
+    /// use tsadaash::domain::Periodicity;
+    ///
+    /// let periodicity = Periodicity::daily().unwrap();
+    /// let start = Utc.with_ymd_and_hms(2026, 1, 1, 9, 0, 0).unwrap();
+    /// let next_five: Vec<_> = periodicity.occurrences_from(&start, Weekday::Mon).take(5).collect();
+    /// # assert_eq!(next_five.len(), 5);
+    /// ```
+    pub fn occurrences_from(&self, start: &DateTime<Utc>, week_start: Weekday) -> OccurrenceIter<'_> {
+        OccurrenceIter {
+            periodicity: self,
+            week_start,
+            cursor: Some(start.date_naive()),
+            cutoff: self.occurrence_limit_end(start, week_start),
+        }
+    }
+}
+
+impl Iterator for OccurrenceIter<'_> {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.cursor.take()?;
+        let mut end = self.periodicity.search_horizon_end(first);
+        if let Some(cutoff) = self.cutoff {
+            end = end.min(cutoff);
+        }
+
+        let day = first.iter_days()
+            .take_while(|day| *day < end)
+            .find(|day| self.periodicity.occurs_on_day(*day, self.week_start))?;
+
+        self.cursor = day.succ_opt();
+        Some(Periodicity::day_start(day))
+    }
+}
@@ -4,6 +4,9 @@
 // ========================================================================
 
 mod types;
+mod cron;
+mod describe;
+mod iter;
 pub mod builder;
 pub mod validation;
 
@@ -24,7 +27,9 @@ pub use types::{
     
     // Other constraints
     WeekConstraint,
+    WeekCountingMode,
     MonthConstraint,
+    MonthAnchor,
     YearConstraint,
     
     // Occurrence timing
@@ -32,6 +37,9 @@ pub use types::{
     RepTimingSettings,
 };
 
+// Re-export the occurrence iterator
+pub use iter::OccurrenceIter;
+
 // Re-export builder
 pub use builder::PeriodicityBuilder;
 
@@ -1,4 +1,6 @@
-use chrono::{DateTime, NaiveTime, Datelike, Month, NaiveDate, Utc, Weekday};
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, NaiveTime, Datelike, Month, Months, NaiveDate, Utc, Weekday};
 use super::validation::{ValidationError, validate_periodicity};
 
 // ========================================================================
@@ -8,6 +10,7 @@ use super::validation::{ValidationError, validate_periodicity};
 
 /// Defines the time unit for task repetition
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RepetitionUnit {
     /// Task repeats multiple times per day
     Day,
@@ -28,6 +31,7 @@ pub enum RepetitionUnit {
 
 /// Specifies which week of the month for day constraints
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MonthWeekPosition {
     /// Week counting from the start (0-4: first to fifth week)
     FromFirst(u8),
@@ -55,6 +59,7 @@ impl MonthWeekPosition {
 /// Combines weekday with week-of-month for complex day patterns
 /// Example: "First Monday", "Last Friday", "Third Wednesday"
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NthWeekdayOfMonth {
     pub weekday: Weekday,
     pub position: MonthWeekPosition,
@@ -62,6 +67,7 @@ pub struct NthWeekdayOfMonth {
 
 /// Constraints that filter which days a task can occur on
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DayConstraint {
     // ── SIMPLE PATTERNS ──────────────────────────────────────
     
@@ -78,6 +84,11 @@ pub enum DayConstraint {
     /// Must contain 1-7 unique weekdays
     SpecificDaysWeek(Vec<Weekday>),
     
+    /// Every nth occurrence of a weekday, counted from the first such
+    /// weekday on or after the reference date (e.g., every other Tuesday)
+    /// Value range for n: 1-52
+    EveryNthWeekday { weekday: Weekday, n: u8 },
+    
     // ── MONTH DAY PATTERNS ───────────────────────────────────
     
     /// Specific days of month counting from start (0-30)
@@ -102,12 +113,18 @@ pub enum DayConstraint {
 // ========================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WeekConstraint {
     /// Every week (no filtering)
     EveryWeek,
     
     /// Every N weeks (rolling pattern)
     /// Value range: 1-52
+    /// 
+    /// Weeks follow the user's week start. When the reference date is the
+    /// task's first occurrence (`reference_is_first_occurrence`), weeks are
+    /// instead 7-day blocks starting on its day, so a task first due on a
+    /// Wednesday runs Wednesday to Tuesday.
     EveryNWeeks(u8),
     
     /// Specific weeks of month from start (0-4)
@@ -121,12 +138,26 @@ pub enum WeekConstraint {
     SpecificWeeksOfMonthFromLast(Vec<u8>),
 }
 
+/// How weeks of the month are counted for the SpecificWeeksOfMonth* constraints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeekCountingMode {
+    /// Week 0 starts on the first `week_start` of the month; days before it
+    /// belong to the previous month (see `week_of_month_from_first`)
+    #[default]
+    AlignedToWeekStart,
+    /// Plain 7-day blocks: days 1-7 are week 0, days 8-14 week 1, and so on
+    /// (from the end: the last 7 days are week 0)
+    CalendarDayBlocks,
+}
+
 // ========================================================================
 // MONTH CONSTRAINTS
 // Filter which specific months a task can occur in
 // ========================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MonthConstraint {
     /// Every month (no filtering)
     EveryMonth,
@@ -140,12 +171,30 @@ pub enum MonthConstraint {
     SpecificMonths(Vec<Month>),
 }
 
+/// Which day of the month an `EveryNMonths` cadence lands on
+/// 
+/// Only used when `Periodicity::month_anchor` is set; without an anchor,
+/// `EveryNMonths` matches every day of the selected months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MonthAnchor {
+    /// Same day-of-month as the reference date, clamped to the month's
+    /// last day (a Jan 31 anchor lands on Feb 28/29)
+    #[default]
+    DayOfMonth,
+    /// Last day of each matching month
+    LastDay,
+    /// First day of each matching month
+    FirstDay,
+}
+
 // ========================================================================
 // YEAR CONSTRAINTS
 // Filter based on year-level patterns
 // ========================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum YearConstraint {
     /// Every year (no filtering)
     EveryYear,
@@ -165,7 +214,11 @@ pub enum YearConstraint {
 // ========================================================================
 
 /// For tasks with specific dates that don't follow a regular pattern
+/// 
+/// Dates are kept as instants for ordering, but matching only compares
+/// their calendar day.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CustomDates {
     /// List of specific dates (must be non-empty and sorted)
     pub dates: Vec<DateTime<Utc>>,
@@ -187,7 +240,10 @@ impl CustomDates {
 }
 
 /// For one-time tasks occurring on a single specific date
+/// 
+/// Matching compares the calendar day only; the time-of-day is ignored.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniqueDate {
     pub date: DateTime<Utc>,
 }
@@ -200,6 +256,7 @@ pub struct UniqueDate {
 /// All specified constraints must be satisfied for a date to be valid
 /// Example: day_constraint + month_constraint = "Mondays in January"
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PeriodicityConstraints {
     pub day_constraint: Option<DayConstraint>,
     pub week_constraint: Option<WeekConstraint>,
@@ -234,6 +291,7 @@ pub struct PeriodicityConstraints {
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OccurrenceTimingSettings {
     /// Duration in minutes (1-1440, max 24 hours)
     pub duration: Option<u16>,
@@ -243,6 +301,10 @@ pub struct OccurrenceTimingSettings {
     pub not_before: Option<NaiveTime>,
     
     /// Ideal completion time for this occurrence
+    /// 
+    /// May be earlier than `not_before` for a window crossing midnight
+    /// (e.g. 23:00–01:00), which then closes on the following day. Only
+    /// allowed when the repetition unit is longer than a day.
     pub best_before: Option<NaiveTime>,
     
     /// Per-repetition timing settings
@@ -289,6 +351,7 @@ pub struct OccurrenceTimingSettings {
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepTimingSettings {
     /// Index of the repetition (0-based, must be < rep_per_unit)
     pub rep_index: u8,
@@ -329,10 +392,17 @@ pub struct RepTimingSettings {
 ///     timeframe: None,
 ///     special_pattern: None,
 ///     reference_date: None,
+///     reference_is_first_occurrence: false,
+///     month_anchor: None,
+///     exclusions: Default::default(),
+///     max_occurrences: None,
+///     year_start: None,
+///     week_counting: Default::default(),
 /// };
 /// # assert_eq!(periodicity.rep_unit, RepetitionUnit::Day);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Periodicity {
     // ── REPETITION FREQUENCY ─────────────────────────────────
     
@@ -376,10 +446,45 @@ pub struct Periodicity {
     /// 1. First TaskOccurrence date (if any exist)
     /// 2. Otherwise, uses timeframe.start_inclusive if set
     /// 3. Otherwise, uses first date being checked as fallback
+    /// 
+    /// EveryN* cadences only count forward: dates before the reference
+    /// never match.
     pub reference_date: Option<DateTime<Utc>>,
+    
+    /// Whether `reference_date` is the task's first occurrence (set by
+    /// `PeriodicityBuilder::first_occurrence_on`); EveryNWeeks weeks then
+    /// start on its weekday instead of the user's week start
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reference_is_first_occurrence: bool,
+    
+    /// Day resolution for EveryNMonths (None = any day of a matching month)
+    pub month_anchor: Option<MonthAnchor>,
+    
+    // ── EXCLUSIONS ───────────────────────────────────────────
+    
+    /// Calendar days (UTC) on which the task never occurs, even if all
+    /// constraints match (e.g., public holidays)
+    pub exclusions: BTreeSet<NaiveDate>,
+    
+    /// Stop after this many occurrences ("repeat 10 times"), counted from
+    /// the reference date. An occurrence is one `rep_unit` period (day,
+    /// week, month or year, laid end to end from the reference) with at
+    /// least one matching day. Only `occurrences_between`,
+    /// `next_occurrence_after` and `occurrences_from` apply it;
+    /// `matches_constraints` is stateless.
+    pub max_occurrences: Option<u32>,
+    
+    /// First month of the year for year constraints (None = January).
+    /// With April, Feb 2027 belongs to fiscal year 2026.
+    pub year_start: Option<Month>,
+    
+    /// How SpecificWeeksOfMonth* constraints number the weeks
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub week_counting: WeekCountingMode,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpecialPattern {
     Custom(CustomDates),
     Unique(UniqueDate),
@@ -420,15 +525,26 @@ impl Periodicity {
     /// Checks if a specific date matches this periodicity's constraints
     /// Does NOT account for timeframe - call is_within_timeframe separately
     /// 
+    /// Special patterns (Unique/Custom) match on the calendar day (UTC) of the
+    /// stored instant, so the time-of-day of `date` does not matter.
+    /// Days listed in `exclusions` never match.
+    /// 
     /// # Parameters
     /// - `date`: The date to check
     /// - `week_start`: First day of the week (from User calendar settings)
     pub fn matches_constraints(&self, date: &DateTime<Utc>, week_start: Weekday) -> bool {
+        // Excluded days never match
+        if self.exclusions.contains(&date.date_naive()) {
+            return false;
+        }
+        
         // Handle special patterns first
         if let Some(pattern) = &self.special_pattern {
             return match pattern {
-                SpecialPattern::Custom(custom) => custom.dates.contains(date),
-                SpecialPattern::Unique(unique) => unique.date == *date,
+                SpecialPattern::Custom(custom) => {
+                    custom.dates.iter().any(|d| d.date_naive() == date.date_naive())
+                }
+                SpecialPattern::Unique(unique) => unique.date.date_naive() == date.date_naive(),
             };
         }
         
@@ -468,6 +584,183 @@ impl Periodicity {
         }
     }
     
+    /// Checks whether any date can ever match this periodicity
+    /// 
+    /// `validate()` checks each constraint on its own, so combinations such as
+    /// "the 31st of February" pass validation but never fire. This scans a
+    /// bounded horizon day by day for a date that matches the constraints and
+    /// lies within the timeframe.
+    /// 
+    /// # Horizon
+    /// Starts at the timeframe start, else the reference date, else today,
+    /// and spans at least 8 years (see `search_horizon_end`).
+    pub fn is_satisfiable(&self, week_start: Weekday) -> bool {
+        if let Some(pattern) = &self.special_pattern {
+            return match pattern {
+                SpecialPattern::Custom(custom) => custom.dates.iter().any(|d| self.is_within_timeframe(d)),
+                SpecialPattern::Unique(unique) => self.is_within_timeframe(&unique.date),
+            };
+        }
+        
+        let start = match (self.timeframe, self.reference_date) {
+            (Some((start, _)), _) => start,
+            (None, Some(reference)) => reference,
+            (None, None) => Utc::now(),
+        }
+        .date_naive();
+        let end = self.search_horizon_end(start);
+        
+        start.iter_days()
+            .take_while(|day| *day < end)
+            .any(|day| self.occurs_on_day(day, week_start))
+    }
+    
+    /// Lists the days (as midnight UTC) on which this periodicity occurs,
+    /// from the calendar day of `start` up to `end` (exclusive)
+    /// 
+    /// Applies constraints, exclusions, the timeframe and `max_occurrences`.
+    /// The occurrence limit is counted from the effective reference date,
+    /// falling back to `start`, so it holds however wide the window is.
+    pub fn occurrences_between(
+        &self,
+        start: &DateTime<Utc>,
+        end: &DateTime<Utc>,
+        week_start: Weekday,
+    ) -> Vec<DateTime<Utc>> {
+        self.occurrences_from(start, week_start)
+            .take_while(|date| date < end)
+            .collect()
+    }
+    
+    /// Finds the first occurrence on a calendar day after that of `after`
+    /// 
+    /// Searches the same bounded horizon as `is_satisfiable` and returns
+    /// None once the timeframe or `max_occurrences` is exhausted.
+    pub fn next_occurrence_after(&self, after: &DateTime<Utc>, week_start: Weekday) -> Option<DateTime<Utc>> {
+        let first = after.date_naive().succ_opt()?;
+        let mut end = self.search_horizon_end(first);
+        if let Some(cutoff) = self.occurrence_limit_end(after, week_start) {
+            end = end.min(cutoff);
+        }
+        
+        first.iter_days()
+            .take_while(|day| *day < end)
+            .find(|day| self.occurs_on_day(*day, week_start))
+            .map(Self::day_start)
+    }
+    
+    /// Day after the last occurrence allowed by `max_occurrences`
+    /// 
+    /// Walks forward from the effective reference date (with `fallback` as
+    /// the last resort), counting each `rep_unit` period holding a matching
+    /// day once; the cutoff is the end of the last counted period. None when
+    /// there is no limit or it is never reached.
+    pub(super) fn occurrence_limit_end(&self, fallback: &DateTime<Utc>, week_start: Weekday) -> Option<NaiveDate> {
+        let limit = self.max_occurrences?;
+        let anchor = self.get_effective_reference_date(fallback).date_naive();
+        let end = self.search_horizon_end(anchor);
+        
+        let mut counted = 0;
+        let mut period_end: Option<NaiveDate> = None;
+        for day in anchor.iter_days().take_while(|day| *day < end) {
+            if period_end.is_some_and(|period_end| day < period_end) || !self.occurs_on_day(day, week_start) {
+                continue;
+            }
+            counted += 1;
+            period_end = self.period_end(anchor, day);
+            if counted == limit {
+                return period_end;
+            }
+        }
+        None
+    }
+    
+    /// Exclusive end of the `rep_unit` period containing `day`, periods
+    /// being laid end to end from `anchor` (`day` must not precede it)
+    fn period_end(&self, anchor: NaiveDate, day: NaiveDate) -> Option<NaiveDate> {
+        let months_per_period = match self.rep_unit {
+            RepetitionUnit::Day | RepetitionUnit::None => return day.succ_opt(),
+            RepetitionUnit::Week => {
+                let weeks = (day - anchor).num_days() / 7;
+                return anchor.checked_add_signed(chrono::Duration::days((weeks + 1) * 7));
+            }
+            RepetitionUnit::Month => 1,
+            RepetitionUnit::Year => 12,
+        };
+        
+        let months_diff = (day.year() - anchor.year()) * 12 + day.month() as i32 - anchor.month() as i32;
+        let mut start = (months_diff.max(0) as u32 / months_per_period) * months_per_period;
+        // A day of the month before the anchor's still belongs to the previous period
+        if anchor.checked_add_months(Months::new(start)).is_none_or(|period_start| period_start > day) {
+            start = start.saturating_sub(months_per_period);
+        }
+        anchor.checked_add_months(Months::new(start + months_per_period))
+    }
+    
+    /// Whether the calendar day matches the constraints and overlaps the
+    /// timeframe (which may start or end mid-day)
+    pub(super) fn occurs_on_day(&self, day: NaiveDate, week_start: Weekday) -> bool {
+        let date = Self::day_start(day);
+        let day_end = date + chrono::Duration::days(1) - chrono::Duration::seconds(1);
+        (self.is_within_timeframe(&date) || self.is_within_timeframe(&day_end))
+            && self.matches_constraints(&date, week_start)
+    }
+    
+    /// Exclusive end of the day-by-day search window starting at `start`
+    /// 
+    /// Spans 8 years (so leap days are always reached), stretched by the
+    /// EveryNYears interval and up to the last SpecificYears entry or custom
+    /// date, and cut short by the timeframe end.
+    pub(super) fn search_horizon_end(&self, start: NaiveDate) -> NaiveDate {
+        let mut horizon_years = 8;
+        if let Some(YearConstraint::EveryNYears(n)) = self.constraints.year_constraint {
+            horizon_years *= n.max(1) as i32;
+        }
+        let mut end = NaiveDate::from_ymd_opt(start.year() + horizon_years, start.month(), 1)
+            .unwrap_or(NaiveDate::MAX);
+        if let Some(YearConstraint::SpecificYears(years)) = &self.constraints.year_constraint {
+            if let Some(last_year) = years.iter().max() {
+                // A fiscal year runs into the following calendar year
+                end = end.max(NaiveDate::from_ymd_opt(last_year + 2, 1, 1).unwrap_or(NaiveDate::MAX));
+            }
+        }
+        if let Some(SpecialPattern::Custom(custom)) = &self.special_pattern {
+            if let Some(last) = custom.dates.last() {
+                end = end.max(last.date_naive() + chrono::Duration::days(1));
+            }
+        }
+        if let Some(SpecialPattern::Unique(unique)) = &self.special_pattern {
+            end = end.max(unique.date.date_naive() + chrono::Duration::days(1));
+        }
+        if let Some((_, timeframe_end)) = self.timeframe {
+            end = end.min(timeframe_end.date_naive() + chrono::Duration::days(1));
+        }
+        end
+    }
+    
+    pub(super) fn day_start(day: NaiveDate) -> DateTime<Utc> {
+        DateTime::from_naive_utc_and_offset(day.and_time(NaiveTime::MIN), Utc)
+    }
+    
+    /// Whether `date` is a valid first occurrence for the EveryN* constraints
+    ///
+    /// The EveryN* counters start at the reference date, so the anchor itself
+    /// must fall on a matching day (e.g. on the weekday of an EveryNthWeekday).
+    /// Always true when no EveryN* constraint is set.
+    pub(super) fn satisfies_base_frequency(&self, date: &DateTime<Utc>) -> bool {
+        let c = &self.constraints;
+        let has_every_n = matches!(c.day_constraint, Some(DayConstraint::EveryNDays(_) | DayConstraint::EveryNthWeekday { .. }))
+            || matches!(c.week_constraint, Some(WeekConstraint::EveryNWeeks(_)))
+            || matches!(c.month_constraint, Some(MonthConstraint::EveryNMonths(_)))
+            || matches!(c.year_constraint, Some(YearConstraint::EveryNYears(_)));
+        if !has_every_n {
+            return true;
+        }
+        
+        c.day_constraint.as_ref().is_none_or(|day| self.matches_day_constraint(date, day))
+            && c.month_constraint.as_ref().is_none_or(|month| self.matches_month_constraint(date, month))
+    }
+    
     // ── PRIVATE CONSTRAINT MATCHERS ──────────────────────────
     
     fn matches_day_constraint(&self, date: &DateTime<Utc>, constraint: &DayConstraint) -> bool {
@@ -475,12 +768,26 @@ impl Periodicity {
             DayConstraint::EveryDay => true,
             DayConstraint::EveryNDays(n) => {
                 let ref_date = self.get_effective_reference_date(date);
-                let days_diff = (*date - ref_date).num_days().abs();
-                (days_diff % (*n as i64)) == 0
+                let days_diff = (date.date_naive() - ref_date.date_naive()).num_days();
+                days_diff >= 0 && (days_diff % (*n as i64)) == 0
             }
             DayConstraint::SpecificDaysWeek(weekdays) => {
                 weekdays.contains(&date.weekday())
             }
+            DayConstraint::EveryNthWeekday { weekday, n } => {
+                if date.weekday() != *weekday {
+                    return false;
+                }
+                let ref_date = self.get_effective_reference_date(date).date_naive();
+                
+                // First matching weekday on or after the reference is occurrence 0
+                let days_forward = (weekday.num_days_from_monday() + 7
+                    - ref_date.weekday().num_days_from_monday()) % 7;
+                let first = ref_date + chrono::Duration::days(days_forward as i64);
+                
+                let days_diff = (date.date_naive() - first).num_days();
+                days_diff >= 0 && (days_diff % (7 * *n as i64)) == 0
+            }
             DayConstraint::SpecificDaysMonthFromFirst(days) => {
                 let day_of_month = date.day() - 1; // Convert to 0-indexed
                 days.contains(&(day_of_month as u8))
@@ -518,18 +825,28 @@ impl Periodicity {
             WeekConstraint::EveryNWeeks(n) => {
                 let ref_date = self.get_effective_reference_date(date);
                 
-                // Get the start of the week for both dates (respecting week_start)
-                let ref_week_start = Self::get_week_start(&ref_date, week_start);
-                let date_week_start = Self::get_week_start(date, week_start);
+                // Weeks start on the first occurrence's day, else on week_start
+                let anchor_weekday = match (self.reference_is_first_occurrence, self.reference_date) {
+                    (true, Some(first)) => first.weekday(),
+                    _ => week_start,
+                };
+                let ref_week_start = Self::get_week_start(&ref_date, anchor_weekday);
+                let date_week_start = Self::get_week_start(date, anchor_weekday);
                 
                 // Calculate weeks difference
-                let days_diff = (date_week_start - ref_week_start).num_days().abs();
+                let days_diff = (date_week_start - ref_week_start).num_days();
+                if days_diff < 0 {
+                    return false;
+                }
                 let weeks_diff = days_diff / 7;
                 
                 (weeks_diff % (*n as i64)) == 0
             }
             WeekConstraint::SpecificWeeksOfMonthFromFirst(weeks) => {
-                let week_of_month = Self::week_of_month_from_first(date, week_start);
+                let week_of_month = match self.week_counting {
+                    WeekCountingMode::AlignedToWeekStart => Self::week_of_month_from_first(date, week_start),
+                    WeekCountingMode::CalendarDayBlocks => ((date.day() - 1) / 7) as u8,
+                };
                 // 255 means invalid (belongs to different month)
                 if week_of_month == 255 {
                     return false;
@@ -537,7 +854,12 @@ impl Periodicity {
                 weeks.contains(&week_of_month)
             }
             WeekConstraint::SpecificWeeksOfMonthFromLast(weeks) => {
-                let week_of_month = Self::week_of_month_from_last(date, week_start);
+                let week_of_month = match self.week_counting {
+                    WeekCountingMode::AlignedToWeekStart => Self::week_of_month_from_last(date, week_start),
+                    WeekCountingMode::CalendarDayBlocks => {
+                        ((Self::last_day_of_month(date.date_naive()) - date.day()) / 7) as u8
+                    }
+                };
                 // 255 means invalid (belongs to different month)
                 if week_of_month == 255 {
                     return false;
@@ -552,12 +874,30 @@ impl Periodicity {
             MonthConstraint::EveryMonth => true,
             MonthConstraint::EveryNMonths(n) => {
                 let ref_date = self.get_effective_reference_date(date);
+                if date.date_naive() < ref_date.date_naive() {
+                    return false;
+                }
                 
                 // Calculate months difference
                 let years_diff = date.year() - ref_date.year();
                 let months_diff = (years_diff * 12) + (date.month() as i32 - ref_date.month() as i32);
                 
-                (months_diff.abs() % (*n as i32)) == 0
+                if months_diff < 0 || (months_diff % (*n as i32)) != 0 {
+                    return false;
+                }
+                
+                match self.month_anchor {
+                    None => true,
+                    Some(anchor) => {
+                        let last_day = Self::last_day_of_month(date.date_naive());
+                        let anchor_day = match anchor {
+                            MonthAnchor::DayOfMonth => ref_date.day().min(last_day),
+                            MonthAnchor::LastDay => last_day,
+                            MonthAnchor::FirstDay => 1,
+                        };
+                        date.day() == anchor_day
+                    }
+                }
             }
             MonthConstraint::SpecificMonths(months) => {
                 let month = Month::try_from(date.month() as u8).unwrap();
@@ -567,21 +907,25 @@ impl Periodicity {
     }
     
     fn matches_year_constraint(&self, date: &DateTime<Utc>, constraint: &YearConstraint) -> bool {
+        let year_start = self.year_start.unwrap_or(Month::January);
         match constraint {
             YearConstraint::EveryYear => true,
             YearConstraint::EveryNYears(n) => {
                 let ref_date = self.get_effective_reference_date(date);
-                let years_diff = (date.year() - ref_date.year()).abs();
-                (years_diff % (*n as i32)) == 0
+                if date.date_naive() < ref_date.date_naive() {
+                    return false;
+                }
+                let years_diff = Self::fiscal_year(date, year_start) - Self::fiscal_year(&ref_date, year_start);
+                years_diff >= 0 && (years_diff % (*n as i32)) == 0
             }
             YearConstraint::SpecificYears(years) => {
-                years.contains(&date.year())
+                years.contains(&Self::fiscal_year(date, year_start))
             }
         }
     }
     
     // ── HELPER FUNCTIONS ─────────────────────────────────────
-        /// Get the start of the week for a given date, based on week_start setting
+    /// Get the start of the week for a given date, based on week_start setting
     /// Returns a DateTime at 00:00:00 on the week_start day
     fn get_week_start(date: &DateTime<Utc>, week_start: Weekday) -> DateTime<Utc> {
         let current_weekday = date.weekday();
@@ -601,16 +945,32 @@ impl Periodicity {
             Utc
         )
     }
-        fn last_day_of_month(date: NaiveDate) -> u32 {
-        NaiveDate::from_ymd_opt(
-            date.year(),
-            date.month() + 1,
-            1,
-        )
-        .unwrap_or(NaiveDate::from_ymd_opt(date.year() + 1, 1, 1).unwrap())
-        .pred_opt()
-        .unwrap()
-        .day()
+
+    /// Year a date belongs to when years start in `year_start`, labelled by
+    /// the calendar year the fiscal year starts in
+    /// 
+    /// With April: 2027-03-31 → 2026, 2027-04-01 → 2027.
+    pub fn fiscal_year(date: &DateTime<Utc>, year_start: Month) -> i32 {
+        if date.month() < year_start.number_from_month() {
+            date.year() - 1
+        } else {
+            date.year()
+        }
+    }
+    
+    /// Returns the last day number (28-31) of the month containing `date`
+    fn last_day_of_month(date: NaiveDate) -> u32 {
+        // First day of the following month, rolling into January of next year
+        let (next_year, next_month) = if date.month() == 12 {
+            (date.year() + 1, 1)
+        } else {
+            (date.year(), date.month() + 1)
+        };
+        
+        NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|first_of_next| first_of_next.pred_opt())
+            .map(|last| last.day())
+            .expect("first of next month is always a valid date")
     }
     
     fn is_nth_weekday_from_first(date: &DateTime<Utc>, _weekday: Weekday, n: u8) -> bool {
@@ -659,7 +1019,7 @@ impl Periodicity {
             // Calculate days forward to reach week_start
             let days_forward = (week_start.num_days_from_monday() + 7 
                 - first_weekday.num_days_from_monday()) % 7;
-            1 + days_forward as u32
+            1 + days_forward
         };
         
         // If date is before first week_start, it belongs to previous month
@@ -725,7 +1085,7 @@ impl Periodicity {
                 // week_end is after last_weekday, so go back a full week
                 last_day.saturating_sub(7)
             } else {
-                last_day - days_back as u32
+                last_day - days_back
             }
         };
         
@@ -751,7 +1111,7 @@ impl Periodicity {
         } else {
             let days_forward = (week_start.num_days_from_monday() + 7 
                 - first_weekday.num_days_from_monday()) % 7;
-            1 + days_forward as u32
+            1 + days_forward
         };
         
         // Get last day of month
@@ -124,7 +124,16 @@ pub fn validate_periodicity(periodicity: &Periodicity) -> Result<(), ValidationE
     validate_timeframe(&periodicity.timeframe)?;
     
     // 6. Validate occurrence settings if present
-    validate_occurrence_settings(&periodicity.occurrence_settings, periodicity.rep_per_unit)?;
+    validate_occurrence_settings(&periodicity.occurrence_settings, periodicity.rep_per_unit, &periodicity.rep_unit)?;
+    
+    // 7. Validate month anchor usage
+    validate_month_anchor(periodicity)?;
+    
+    // 8. Validate exclusions fall inside the timeframe
+    validate_exclusions(periodicity)?;
+    
+    // 9. Validate occurrence limit
+    validate_max_occurrences(periodicity)?;
     
     Ok(())
 }
@@ -137,10 +146,10 @@ fn validate_repetition(periodicity: &Periodicity) -> Result<(), ValidationError>
     match periodicity.rep_unit {
         RepetitionUnit::None => {
             // rep_per_unit must be None
-            if periodicity.rep_per_unit.is_some() {
+            if let Some(count) = periodicity.rep_per_unit {
                 return Err(ValidationError::InvalidValue {
                     field: "rep_per_unit".into(),
-                    value: periodicity.rep_per_unit.unwrap().to_string(),
+                    value: count.to_string(),
                     reason: "Must be None when rep_unit is None".into(),
                 });
             }
@@ -235,6 +244,25 @@ fn validate_day_constraint(constraint: &DayConstraint) -> Result<(), ValidationE
             Ok(())
         }
         
+        DayConstraint::EveryNthWeekday { n, .. } => {
+            if *n == 0 {
+                return Err(ValidationError::InvalidValue {
+                    field: "EveryNthWeekday".into(),
+                    value: "0".into(),
+                    reason: "Must be at least 1".into(),
+                });
+            }
+            if *n > 52 {
+                return Err(ValidationError::OutOfRange {
+                    field: "EveryNthWeekday".into(),
+                    value: n.to_string(),
+                    min: "1".into(),
+                    max: "52".into(),
+                });
+            }
+            Ok(())
+        }
+        
         DayConstraint::SpecificDaysWeek(weekdays) => {
             if weekdays.is_empty() {
                 return Err(ValidationError::EmptyCollection {
@@ -510,7 +538,7 @@ fn validate_year_constraint(constraint: &YearConstraint) -> Result<(), Validatio
             }
             // Validate year range (1900-2200)
             for &year in years {
-                if year < 1900 || year > 2200 {
+                if !(1900..=2200).contains(&year) {
                     return Err(ValidationError::OutOfRange {
                         field: "SpecificYears".into(),
                         value: year.to_string(),
@@ -541,6 +569,23 @@ fn validate_constraint_compatibility(periodicity: &Periodicity) -> Result<(), Va
         });
     }
     
+    // Week-of-month positions restart every month, while a rolling weekday
+    // cadence counts weeks from the reference date; combined they drift
+    // in and out of phase and rarely match as intended
+    if let (
+        Some(WeekConstraint::SpecificWeeksOfMonthFromFirst(_) | WeekConstraint::SpecificWeeksOfMonthFromLast(_)),
+        Some(DayConstraint::EveryNthWeekday { n, .. }),
+    ) = (&constraints.week_constraint, &constraints.day_constraint)
+    {
+        if *n > 1 {
+            return Err(ValidationError::ConflictingConstraints {
+                constraint1: "week_constraint (week of month)".into(),
+                constraint2: "EveryNthWeekday".into(),
+                reason: "Week-of-month positions and rolling weekly cadences anchor differently".into(),
+            });
+        }
+    }
+    
     match periodicity.rep_unit {
         RepetitionUnit::None => {
             // Must have a special pattern
@@ -597,6 +642,62 @@ fn validate_constraint_compatibility(periodicity: &Periodicity) -> Result<(), Va
     }
 }
 
+// ========================================================================
+// MONTH ANCHOR VALIDATION
+// ========================================================================
+
+fn validate_month_anchor(periodicity: &Periodicity) -> Result<(), ValidationError> {
+    if let Some(anchor) = periodicity.month_anchor {
+        if !matches!(periodicity.constraints.month_constraint, Some(MonthConstraint::EveryNMonths(_))) {
+            return Err(ValidationError::InvalidValue {
+                field: "month_anchor".into(),
+                value: format!("{:?}", anchor),
+                reason: "Only applies to an EveryNMonths month constraint".into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ========================================================================
+// EXCLUSION VALIDATION
+// ========================================================================
+
+/// Exclusions outside the timeframe have no effect and usually signal a
+/// mistake (wrong year, stale holiday list), so they are rejected
+fn validate_exclusions(periodicity: &Periodicity) -> Result<(), ValidationError> {
+    if let Some((start, end)) = &periodicity.timeframe {
+        let first_day = start.date_naive();
+        let last_day = (*end - chrono::Duration::seconds(1)).date_naive();
+        
+        for day in &periodicity.exclusions {
+            if *day < first_day || *day > last_day {
+                return Err(ValidationError::InvalidValue {
+                    field: "exclusions".into(),
+                    value: day.to_string(),
+                    reason: "Exclusion date falls outside the timeframe".into(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+// ========================================================================
+// OCCURRENCE LIMIT VALIDATION
+// ========================================================================
+
+fn validate_max_occurrences(periodicity: &Periodicity) -> Result<(), ValidationError> {
+    if periodicity.max_occurrences == Some(0) {
+        return Err(ValidationError::InvalidValue {
+            field: "max_occurrences".into(),
+            value: "0".into(),
+            reason: "Must allow at least one occurrence".into(),
+        });
+    }
+    Ok(())
+}
+
 // ========================================================================
 // SPECIAL PATTERN VALIDATION
 // ========================================================================
@@ -615,10 +716,10 @@ fn validate_special_pattern(
     }
     
     // Must not have rep_per_unit
-    if periodicity.rep_per_unit.is_some() {
+    if let Some(count) = periodicity.rep_per_unit {
         return Err(ValidationError::InvalidValue {
             field: "rep_per_unit".into(),
-            value: periodicity.rep_per_unit.unwrap().to_string(),
+            value: count.to_string(),
             reason: "Must be None for special patterns".into(),
         });
     }
@@ -629,6 +730,7 @@ fn validate_special_pattern(
         || constraints.week_constraint.is_some()
         || constraints.month_constraint.is_some()
         || constraints.year_constraint.is_some()
+        || periodicity.month_anchor.is_some()
     {
         return Err(ValidationError::ConflictingConstraints {
             constraint1: "special_pattern".into(),
@@ -637,6 +739,8 @@ fn validate_special_pattern(
         });
     }
     
+    validate_max_occurrences(periodicity)?;
+    
     // Validate the pattern itself
     match pattern {
         SpecialPattern::Custom(custom) => {
@@ -680,6 +784,7 @@ fn validate_timeframe(
 pub fn validate_occurrence_settings(
     settings: &Option<OccurrenceTimingSettings>,
     rep_per_unit: Option<u8>,
+    rep_unit: &RepetitionUnit,
 ) -> Result<(), ValidationError> {
     let Some(settings) = settings else {
         return Ok(());
@@ -705,19 +810,27 @@ pub fn validate_occurrence_settings(
     }
     
     // Validate not_before < best_before if both present
+    // (a window crossing midnight is only allowed when occurrences span several days)
     if let (Some(not_before), Some(best_before)) = (settings.not_before, settings.best_before) {
-        if not_before >= best_before {
+        if not_before == best_before {
             return Err(ValidationError::InvalidValue {
                 field: "not_before/best_before".into(),
                 value: format!("{}/{}", not_before, best_before),
                 reason: "not_before must be earlier than best_before".into(),
             });
         }
+        if not_before > best_before && !allows_overnight_window(rep_unit) {
+            return Err(ValidationError::InvalidValue {
+                field: "not_before/best_before".into(),
+                value: format!("{}/{}", not_before, best_before),
+                reason: "not_before must be earlier than best_before: a daily occurrence cannot hold a window crossing midnight".into(),
+            });
+        }
     }
     
     // Validate rep_timing_settings if present
     if let Some(rep_settings) = &settings.rep_timing_settings {
-        validate_rep_timing_settings(rep_settings, rep_per_unit)?;
+        validate_rep_timing_settings(rep_settings, rep_per_unit, rep_unit)?;
     }
     
     Ok(())
@@ -726,6 +839,7 @@ pub fn validate_occurrence_settings(
 fn validate_rep_timing_settings(
     rep_settings: &Vec<RepTimingSettings>,
     rep_per_unit: Option<u8>,
+    rep_unit: &RepetitionUnit,
 ) -> Result<(), ValidationError> {
     // Must not be empty
     if rep_settings.is_empty() {
@@ -760,13 +874,14 @@ fn validate_rep_timing_settings(
             }
         }
         
-        // Validate not_before < best_before if both present
+        // Validate not_before <= best_before if both present
+        // (equal times pin the repetition to a fixed time of day)
         if let (Some(not_before), Some(best_before)) = (rep.not_before, rep.best_before) {
-            if not_before >= best_before {
+            if not_before > best_before && !allows_overnight_window(rep_unit) {
                 return Err(ValidationError::InvalidValue {
                     field: format!("rep_timing_settings[{}]", rep.rep_index),
                     value: format!("not_before={}, best_before={}", not_before, best_before),
-                    reason: "not_before must be earlier than best_before".into(),
+                    reason: "not_before must be earlier than or equal to best_before".into(),
                 });
             }
         }
@@ -775,6 +890,16 @@ fn validate_rep_timing_settings(
     Ok(())
 }
 
+/// Whether a timing window may cross midnight for this repetition unit
+/// 
+/// A daily occurrence is a single calendar day, so a window such as
+/// 23:00–01:00 would straddle two occurrences. Longer units span several
+/// days: there the window opens at `not_before` and closes at `best_before`
+/// on the following day.
+fn allows_overnight_window(rep_unit: &RepetitionUnit) -> bool {
+    !matches!(rep_unit, RepetitionUnit::Day)
+}
+
 // ========================================================================
 // UNIT TESTS
 // ========================================================================
@@ -797,6 +922,12 @@ mod tests {
                 date: Utc::now(),
             })),
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         assert!(periodicity.validate().is_err());
@@ -812,6 +943,12 @@ mod tests {
             timeframe: None,
             special_pattern: None,
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         assert!(periodicity.validate().is_err());
@@ -848,7 +985,7 @@ mod tests {
     #[test]
     fn test_validate_occurrence_settings_none() {
         // None is always valid
-        assert!(validate_occurrence_settings(&None, Some(3)).is_ok());
+        assert!(validate_occurrence_settings(&None, Some(3), &RepetitionUnit::Day).is_ok());
     }
     
     #[test]
@@ -860,7 +997,7 @@ mod tests {
             rep_timing_settings: None,
         };
         
-        assert!(validate_occurrence_settings(&Some(settings), Some(3)).is_ok());
+        assert!(validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day).is_ok());
     }
     
     #[test]
@@ -872,7 +1009,7 @@ mod tests {
             rep_timing_settings: None,
         };
         
-        let result = validate_occurrence_settings(&Some(settings), Some(3));
+        let result = validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day);
         assert!(result.is_err());
         match result.unwrap_err() {
             ValidationError::InvalidValue { field, value, .. } => {
@@ -892,7 +1029,7 @@ mod tests {
             rep_timing_settings: None,
         };
         
-        let result = validate_occurrence_settings(&Some(settings), Some(3));
+        let result = validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day);
         assert!(result.is_err());
         match result.unwrap_err() {
             ValidationError::OutOfRange { field, value, max, .. } => {
@@ -913,7 +1050,7 @@ mod tests {
             rep_timing_settings: None,
         };
         
-        let result = validate_occurrence_settings(&Some(settings), Some(3));
+        let result = validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day);
         assert!(result.is_err());
         match result.unwrap_err() {
             ValidationError::InvalidValue { field, reason, .. } => {
@@ -934,7 +1071,7 @@ mod tests {
             rep_timing_settings: None,
         };
         
-        let result = validate_occurrence_settings(&Some(settings), Some(3));
+        let result = validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day);
         assert!(result.is_err());
     }
     
@@ -947,7 +1084,7 @@ mod tests {
             rep_timing_settings: Some(vec![]), // Empty!
         };
         
-        let result = validate_occurrence_settings(&Some(settings), Some(3));
+        let result = validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day);
         assert!(result.is_err());
         match result.unwrap_err() {
             ValidationError::EmptyCollection { field, .. } => {
@@ -977,7 +1114,7 @@ mod tests {
             ]),
         };
         
-        let result = validate_occurrence_settings(&Some(settings), Some(3));
+        let result = validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day);
         assert!(result.is_err());
         match result.unwrap_err() {
             ValidationError::DuplicateValues { field, .. } => {
@@ -1002,7 +1139,7 @@ mod tests {
             ]),
         };
         
-        let result = validate_occurrence_settings(&Some(settings), Some(3));
+        let result = validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day);
         assert!(result.is_err());
         match result.unwrap_err() {
             ValidationError::OutOfRange { field, value, max, .. } => {
@@ -1039,7 +1176,7 @@ mod tests {
             ]),
         };
         
-        assert!(validate_occurrence_settings(&Some(settings), Some(3)).is_ok());
+        assert!(validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day).is_ok());
     }
     
     #[test]
@@ -1057,7 +1194,7 @@ mod tests {
             ]),
         };
         
-        let result = validate_occurrence_settings(&Some(settings), Some(3));
+        let result = validate_occurrence_settings(&Some(settings), Some(3), &RepetitionUnit::Day);
         assert!(result.is_err());
         match result.unwrap_err() {
             ValidationError::InvalidValue { field, reason, .. } => {
@@ -1086,6 +1223,55 @@ mod tests {
         };
         
         // Should pass because we don't know the valid range
-        assert!(validate_occurrence_settings(&Some(settings), None).is_ok());
+        assert!(validate_occurrence_settings(&Some(settings), None, &RepetitionUnit::Day).is_ok());
+    }
+
+    #[test]
+    fn test_validate_timing_window_within_day() {
+        let settings = OccurrenceTimingSettings {
+            duration: Some(30),
+            not_before: Some(NaiveTime::from_hms_opt(21, 0, 0).unwrap()),
+            best_before: Some(NaiveTime::from_hms_opt(23, 30, 0).unwrap()),
+            rep_timing_settings: None,
+        };
+        
+        for unit in [RepetitionUnit::Day, RepetitionUnit::Week] {
+            assert!(validate_occurrence_settings(&Some(settings.clone()), Some(1), &unit).is_ok());
+        }
+    }
+    
+    #[test]
+    fn test_validate_timing_window_crossing_midnight() {
+        let late = NaiveTime::from_hms_opt(23, 0, 0).unwrap();
+        let early = NaiveTime::from_hms_opt(1, 0, 0).unwrap();
+        let settings = OccurrenceTimingSettings {
+            duration: Some(30),
+            not_before: Some(late),
+            best_before: Some(early),
+            rep_timing_settings: None,
+        };
+        
+        // A daily occurrence cannot hold it
+        match validate_occurrence_settings(&Some(settings.clone()), Some(1), &RepetitionUnit::Day).unwrap_err() {
+            ValidationError::InvalidValue { reason, .. } => assert!(reason.contains("crossing midnight")),
+            other => panic!("Expected InvalidValue error, got {:?}", other),
+        }
+        
+        // A weekly occurrence spans several days: the window ends the next morning
+        assert!(validate_occurrence_settings(&Some(settings), Some(1), &RepetitionUnit::Week).is_ok());
+        
+        // Same policy for per-rep windows
+        let settings = OccurrenceTimingSettings {
+            duration: None,
+            not_before: None,
+            best_before: None,
+            rep_timing_settings: Some(vec![RepTimingSettings {
+                rep_index: 0,
+                not_before: Some(late),
+                best_before: Some(early),
+            }]),
+        };
+        assert!(validate_occurrence_settings(&Some(settings.clone()), Some(1), &RepetitionUnit::Day).is_err());
+        assert!(validate_occurrence_settings(&Some(settings), Some(1), &RepetitionUnit::Month).is_ok());
     }
 }
@@ -1,10 +1,11 @@
-use chrono::{DateTime, Utc, Weekday};
+use chrono::{DateTime, NaiveTime, Utc, Weekday};
 use crate::domain::entities::task::periodicity::Periodicity;
 use crate::domain::entities::user::Location;
 use crate::domain::entities::schedule::{
     SchedulableTask, AvailabilityLevel, DeviceAccess, Mobility,
 };
 use crate::config;
+use crate::domain::clock::{Clock, SystemClock};
 
 // ========================================================================
 // VALIDATION ERRORS
@@ -16,6 +17,10 @@ pub enum TaskValidationError {
     TitleTooLong { max: usize, actual: usize },
     DescriptionTooLong { max: usize, actual: usize },
     InvalidTimestamps { reason: String },
+    ZeroCompletionGoal,
+    InvalidPreferredTimeWindow { start: NaiveTime, end: NaiveTime },
+    EmptyTag,
+    TooManyTags { max: usize },
 }
 
 impl std::fmt::Display for TaskValidationError {
@@ -31,6 +36,16 @@ impl std::fmt::Display for TaskValidationError {
             TaskValidationError::InvalidTimestamps { reason } => {
                 write!(f, "Invalid timestamps: {}", reason)
             }
+            TaskValidationError::ZeroCompletionGoal => {
+                write!(f, "Completion goal must be at least 1")
+            }
+            TaskValidationError::InvalidPreferredTimeWindow { start, end } => {
+                write!(f, "Preferred time window must start before it ends: {}-{}", start, end)
+            }
+            TaskValidationError::EmptyTag => write!(f, "Task tag cannot be empty"),
+            TaskValidationError::TooManyTags { max } => {
+                write!(f, "Too many task tags (max: {})", max)
+            }
         }
     }
 }
@@ -41,9 +56,11 @@ impl std::error::Error for TaskValidationError {}
 // TASK STATUS
 // ========================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TaskStatus {
     /// Task is active and should generate occurrences
+    #[default]
     Active,
     /// Task is paused (not deleted, but won't generate occurrences)
     Paused,
@@ -51,27 +68,28 @@ pub enum TaskStatus {
     Archived,
 }
 
-impl Default for TaskStatus {
-    fn default() -> Self {
-        TaskStatus::Active
-    }
-}
-
 // ========================================================================
 // TASK PRIORITY
 // ========================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TaskPriority {
     Low = 1,
+    #[default]
     Medium = 2,
     High = 3,
     Urgent = 4,
 }
 
-impl Default for TaskPriority {
-    fn default() -> Self {
-        TaskPriority::Medium
+impl TaskPriority {
+    /// The next level up (Urgent stays Urgent)
+    pub fn escalated(self) -> Self {
+        match self {
+            TaskPriority::Low => TaskPriority::Medium,
+            TaskPriority::Medium => TaskPriority::High,
+            TaskPriority::High | TaskPriority::Urgent => TaskPriority::Urgent,
+        }
     }
 }
 
@@ -92,6 +110,7 @@ impl Default for TaskPriority {
 /// - No `id` field - persistence concerns belong in infrastructure layer
 /// - No direct reference to user - multi-tenancy handled in infrastructure
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Task {
     // ── CORE ATTRIBUTES ─────────────────────────────────────
     title: String,
@@ -102,6 +121,14 @@ pub struct Task {
     // ── SCHEDULING ──────────────────────────────────────────
     periodicity: Periodicity,
     
+    /// Total completed reps after which the task is done, whenever they
+    /// happen (None = no goal, the task just keeps recurring)
+    completion_goal: Option<u32>,
+    
+    /// Moment the task should be done by; it gains priority as it nears
+    /// (see `effective_priority`)
+    soft_deadline: Option<DateTime<Utc>>,
+    
     // ── LOCATION REQUIREMENTS ───────────────────────────────
     /// Locations where this task can be performed
     /// Empty = task can be done anywhere (location-free)
@@ -127,6 +154,15 @@ pub struct Task {
     /// Allowed mobility states (empty = all allowed)
     allowed_mobility: Vec<Mobility>,
     
+    // ── PREFERENCES ─────────────────────────────────────────
+    /// Time of day the user would rather do this task (advisory only,
+    /// unlike the hard window in `OccurrenceTimingSettings`)
+    preferred_time_window: Option<(NaiveTime, NaiveTime)>,
+    
+    // ── GROUPING ────────────────────────────────────────────
+    /// Free-form categories ("work", "health"), trimmed, lowercased and unique
+    tags: Vec<String>,
+    
     // ── METADATA ────────────────────────────────────────────
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -142,13 +178,32 @@ impl Task {
     pub fn max_description_length() -> usize {
         config::task_max_description_length()
     }
+    
+    /// Maximum number of tags per task
+    pub fn max_tags() -> usize {
+        config::task_max_tags()
+    }
+    
+    /// The form tags are stored and compared in: trimmed and lowercased
+    pub fn normalize_tag(tag: &str) -> String {
+        tag.trim().to_lowercase()
+    }
 
     /// Creates a new Task with validation
     pub fn new(
         title: String,
         periodicity: Periodicity,
     ) -> Result<Self, TaskValidationError> {
-        let now = Utc::now();
+        Self::new_with_clock(title, periodicity, &SystemClock)
+    }
+
+    /// Creates a new Task timestamped by `clock`
+    pub fn new_with_clock(
+        title: String,
+        periodicity: Periodicity,
+        clock: &dyn Clock,
+    ) -> Result<Self, TaskValidationError> {
+        let now = clock.now();
         Self::with_timestamps(title, periodicity, now, now)
     }
 
@@ -183,6 +238,8 @@ impl Task {
             status: TaskStatus::default(),
             priority: TaskPriority::default(),
             periodicity,
+            completion_goal: None,
+            soft_deadline: None,
             locations: Vec::new(), // Default: location-free
             min_hands: AvailabilityLevel::None, // Default: no hands required
             min_eyes: AvailabilityLevel::None,
@@ -190,11 +247,48 @@ impl Task {
             min_cognitive: AvailabilityLevel::None,
             min_device: DeviceAccess::None, // Default: no device required
             allowed_mobility: Vec::new(), // Default: all mobility states allowed
+            preferred_time_window: None, // Default: no preferred time of day
+            tags: Vec::new(),
             created_at,
             updated_at,
         })
     }
 
+    /// Rebuilds the task through `TaskBuilder`, re-running every check
+    /// 
+    /// Deserialization fills the fields directly, so a task read from a file
+    /// may hold values no constructor allows (an untrimmed title, tags that
+    /// are not normalized, an inverted preferred window). Timestamps are kept.
+    /// The periodicity is carried over unchanged; check it with
+    /// `Periodicity::validate`.
+    pub fn revalidated(&self) -> Result<Self, TaskValidationError> {
+        let mut builder = TaskBuilder::new(self.title.clone(), self.periodicity.clone())
+            .status(self.status)
+            .priority(self.priority)
+            .locations(self.locations.clone())
+            .min_hands(self.min_hands)
+            .min_eyes(self.min_eyes)
+            .min_speech(self.min_speech)
+            .min_cognitive(self.min_cognitive)
+            .min_device(self.min_device)
+            .allowed_mobility(self.allowed_mobility.clone())
+            .tags(self.tags.clone())
+            .timestamps(self.created_at, self.updated_at);
+        if let Some(description) = &self.description {
+            builder = builder.description(description.clone());
+        }
+        if let Some(completion_goal) = self.completion_goal {
+            builder = builder.completion_goal(completion_goal);
+        }
+        if let Some(soft_deadline) = self.soft_deadline {
+            builder = builder.soft_deadline(soft_deadline);
+        }
+        if let Some((start, end)) = self.preferred_time_window {
+            builder = builder.preferred_time_window(start, end);
+        }
+        builder.build()
+    }
+
     // ── GETTERS ─────────────────────────────────────────────
 
     pub fn title(&self) -> &str {
@@ -217,6 +311,33 @@ impl Task {
         &self.periodicity
     }
 
+    pub fn completion_goal(&self) -> Option<u32> {
+        self.completion_goal
+    }
+
+    pub fn soft_deadline(&self) -> Option<DateTime<Utc>> {
+        self.soft_deadline
+    }
+
+    /// Priority at `now`, escalated as the soft deadline approaches
+    /// 
+    /// One level up within `TASK_DEADLINE_ESCALATION_HOURS` of the deadline,
+    /// Urgent once it has passed. Without a deadline this is `priority()`.
+    pub fn effective_priority(&self, now: DateTime<Utc>) -> TaskPriority {
+        let Some(deadline) = self.soft_deadline else {
+            return self.priority;
+        };
+        let window = chrono::Duration::hours(config::task_deadline_escalation_hours() as i64);
+
+        if now >= deadline {
+            TaskPriority::Urgent
+        } else if now >= deadline - window {
+            self.priority.escalated()
+        } else {
+            self.priority
+        }
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -253,6 +374,20 @@ impl Task {
         &self.allowed_mobility
     }
 
+    pub fn preferred_time_window(&self) -> Option<(NaiveTime, NaiveTime)> {
+        self.preferred_time_window
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Check for a tag, in any case and surrounding whitespace
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = Self::normalize_tag(tag);
+        self.tags.contains(&tag)
+    }
+
     // ── SETTERS (with validation) ──────────────────────────
 
     pub fn set_title(&mut self, title: String) -> Result<(), TaskValidationError> {
@@ -299,6 +434,20 @@ impl Task {
         self.touch();
     }
 
+    pub fn set_completion_goal(&mut self, completion_goal: Option<u32>) -> Result<(), TaskValidationError> {
+        if completion_goal == Some(0) {
+            return Err(TaskValidationError::ZeroCompletionGoal);
+        }
+        self.completion_goal = completion_goal;
+        self.touch();
+        Ok(())
+    }
+
+    pub fn set_soft_deadline(&mut self, soft_deadline: Option<DateTime<Utc>>) {
+        self.soft_deadline = soft_deadline;
+        self.touch();
+    }
+
     pub fn set_locations(&mut self, locations: Vec<Option<Location>>) {
         self.locations = locations;
         self.touch();
@@ -334,6 +483,34 @@ impl Task {
         self.touch();
     }
 
+    pub fn set_preferred_time_window(&mut self, window: Option<(NaiveTime, NaiveTime)>) -> Result<(), TaskValidationError> {
+        validate_preferred_time_window(window)?;
+        self.preferred_time_window = window;
+        self.touch();
+        Ok(())
+    }
+
+    /// Add a tag, normalized; returns false if the task already had it
+    pub fn add_tag(&mut self, tag: &str) -> Result<bool, TaskValidationError> {
+        let added = self.insert_tag(tag)?;
+        if added {
+            self.touch();
+        }
+        Ok(added)
+    }
+
+    /// Remove a tag; returns false if the task didn't have it
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let tag = Self::normalize_tag(tag);
+        let before = self.tags.len();
+        self.tags.retain(|existing| *existing != tag);
+        let removed = self.tags.len() < before;
+        if removed {
+            self.touch();
+        }
+        removed
+    }
+
     // ── DOMAIN BEHAVIORS ────────────────────────────────────
 
     /// Check if this task should occur on a specific date
@@ -379,11 +556,253 @@ impl Task {
         self.set_status(TaskStatus::Archived);
     }
 
+    /// Fraction of the completion goal reached, clamped to 1.0
+    /// 
+    /// `completed_total` is the number of completed reps across all
+    /// occurrences, as counted by the application layer.
+    /// Always 0.0 when the task has no completion goal.
+    pub fn goal_progress(&self, completed_total: u32) -> f32 {
+        match self.completion_goal {
+            Some(goal) => (completed_total as f32 / goal as f32).min(1.0),
+            None => 0.0,
+        }
+    }
+
+    /// Check if the completion goal has been reached
+    pub fn is_goal_reached(&self, completed_total: u32) -> bool {
+        self.completion_goal.is_some_and(|goal| completed_total >= goal)
+    }
+
+    /// Record a change made at `now`
+    /// 
+    /// Setters stamp `updated_at` with the system time; call this after them
+    /// to use an injected clock instead. Never moves `updated_at` before
+    /// `created_at`.
+    pub fn touch_with(&mut self, now: DateTime<Utc>) {
+        self.updated_at = now.max(self.created_at);
+    }
+
     // ── INTERNAL HELPERS ────────────────────────────────────
 
+    /// Validate and add a tag without touching `updated_at`
+    fn insert_tag(&mut self, tag: &str) -> Result<bool, TaskValidationError> {
+        let tag = Self::normalize_tag(tag);
+        if tag.is_empty() {
+            return Err(TaskValidationError::EmptyTag);
+        }
+        if self.tags.contains(&tag) {
+            return Ok(false);
+        }
+        if self.tags.len() >= Self::max_tags() {
+            return Err(TaskValidationError::TooManyTags { max: Self::max_tags() });
+        }
+        self.tags.push(tag);
+        Ok(true)
+    }
+
     /// Update the updated_at timestamp
     fn touch(&mut self) {
-        self.updated_at = Utc::now();
+        self.touch_with(SystemClock.now());
+    }
+}
+
+/// A preferred window must start before it ends (no wrapping past midnight)
+fn validate_preferred_time_window(window: Option<(NaiveTime, NaiveTime)>) -> Result<(), TaskValidationError> {
+    match window {
+        Some((start, end)) if start >= end => {
+            Err(TaskValidationError::InvalidPreferredTimeWindow { start, end })
+        }
+        _ => Ok(()),
+    }
+}
+
+// ========================================================================
+// TASK BUILDER
+// Collects every attribute, then validates once in build()
+// ========================================================================
+
+/// Builder for fully-specified tasks
+/// 
+/// Unlike `Task::new` followed by setters, nothing is validated or
+/// timestamped until `build()`, so there are no intermediate states and
+/// `updated_at` is not bumped per field.
+/// 
+/// # Example
+/// ```
+/// use tsadaash::domain::{Periodicity, TaskBuilder, TaskPriority};
+/// use tsadaash::domain::entities::schedule::DeviceAccess;
+/// 
+/// let task = TaskBuilder::new("Write report", Periodicity::daily().unwrap())
+///     .priority(TaskPriority::High)
+///     .min_device(DeviceAccess::Computer)
+///     .build()
+///     .unwrap();
+/// assert_eq!(task.min_device(), DeviceAccess::Computer);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskBuilder {
+    title: String,
+    periodicity: Periodicity,
+    description: Option<String>,
+    completion_goal: Option<u32>,
+    soft_deadline: Option<DateTime<Utc>>,
+    status: TaskStatus,
+    priority: TaskPriority,
+    locations: Vec<Option<Location>>,
+    min_hands: AvailabilityLevel,
+    min_eyes: AvailabilityLevel,
+    min_speech: AvailabilityLevel,
+    min_cognitive: AvailabilityLevel,
+    min_device: DeviceAccess,
+    allowed_mobility: Vec<Mobility>,
+    preferred_time_window: Option<(NaiveTime, NaiveTime)>,
+    tags: Vec<String>,
+    timestamps: Option<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+impl TaskBuilder {
+    /// Starts a builder with the same defaults as `Task::new`
+    pub fn new(title: impl Into<String>, periodicity: Periodicity) -> Self {
+        Self {
+            title: title.into(),
+            periodicity,
+            description: None,
+            completion_goal: None,
+            soft_deadline: None,
+            status: TaskStatus::default(),
+            priority: TaskPriority::default(),
+            locations: Vec::new(),
+            min_hands: AvailabilityLevel::None,
+            min_eyes: AvailabilityLevel::None,
+            min_speech: AvailabilityLevel::None,
+            min_cognitive: AvailabilityLevel::None,
+            min_device: DeviceAccess::None,
+            allowed_mobility: Vec::new(),
+            preferred_time_window: None,
+            tags: Vec::new(),
+            timestamps: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn completion_goal(mut self, completion_goal: u32) -> Self {
+        self.completion_goal = Some(completion_goal);
+        self
+    }
+
+    pub fn status(mut self, status: TaskStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn priority(mut self, priority: TaskPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn locations(mut self, locations: Vec<Option<Location>>) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    pub fn min_hands(mut self, min_hands: AvailabilityLevel) -> Self {
+        self.min_hands = min_hands;
+        self
+    }
+
+    pub fn min_eyes(mut self, min_eyes: AvailabilityLevel) -> Self {
+        self.min_eyes = min_eyes;
+        self
+    }
+
+    pub fn min_speech(mut self, min_speech: AvailabilityLevel) -> Self {
+        self.min_speech = min_speech;
+        self
+    }
+
+    pub fn min_cognitive(mut self, min_cognitive: AvailabilityLevel) -> Self {
+        self.min_cognitive = min_cognitive;
+        self
+    }
+
+    pub fn min_device(mut self, min_device: DeviceAccess) -> Self {
+        self.min_device = min_device;
+        self
+    }
+
+    pub fn allowed_mobility(mut self, allowed_mobility: Vec<Mobility>) -> Self {
+        self.allowed_mobility = allowed_mobility;
+        self
+    }
+
+    pub fn preferred_time_window(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.preferred_time_window = Some((start, end));
+        self
+    }
+
+    pub fn soft_deadline(mut self, soft_deadline: DateTime<Utc>) -> Self {
+        self.soft_deadline = Some(soft_deadline);
+        self
+    }
+
+    /// Tags are normalized and deduplicated in `build()`
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Sets explicit timestamps (defaults to now for both)
+    pub fn timestamps(mut self, created_at: DateTime<Utc>, updated_at: DateTime<Utc>) -> Self {
+        self.timestamps = Some((created_at, updated_at));
+        self
+    }
+
+    /// Validates all attributes and builds the task
+    pub fn build(self) -> Result<Task, TaskValidationError> {
+        let (created_at, updated_at) = self.timestamps.unwrap_or_else(|| {
+            let now = Utc::now();
+            (now, now)
+        });
+
+        let mut task = Task::with_timestamps(self.title, self.periodicity, created_at, updated_at)?;
+
+        if let Some(ref description) = self.description {
+            if description.len() > Task::max_description_length() {
+                return Err(TaskValidationError::DescriptionTooLong {
+                    max: Task::max_description_length(),
+                    actual: description.len(),
+                });
+            }
+        }
+
+        if self.completion_goal == Some(0) {
+            return Err(TaskValidationError::ZeroCompletionGoal);
+        }
+
+        validate_preferred_time_window(self.preferred_time_window)?;
+
+        task.description = self.description.map(|d| d.trim().to_string());
+        task.completion_goal = self.completion_goal;
+        task.soft_deadline = self.soft_deadline;
+        task.status = self.status;
+        task.priority = self.priority;
+        task.locations = self.locations;
+        task.min_hands = self.min_hands;
+        task.min_eyes = self.min_eyes;
+        task.min_speech = self.min_speech;
+        task.min_cognitive = self.min_cognitive;
+        task.min_device = self.min_device;
+        task.allowed_mobility = self.allowed_mobility;
+        task.preferred_time_window = self.preferred_time_window;
+        for tag in &self.tags {
+            task.insert_tag(tag)?;
+        }
+
+        Ok(task)
     }
 }
 
@@ -428,6 +847,10 @@ impl SchedulableTask for Task {
     fn allowed_mobility(&self) -> Vec<Mobility> {
         self.allowed_mobility.clone()
     }
+
+    fn tags(&self) -> &[String] {
+        &self.tags
+    }
 }
 
 // ========================================================================
@@ -512,4 +935,223 @@ mod tests {
         task.set_priority(TaskPriority::Urgent);
         assert_eq!(task.priority(), TaskPriority::Urgent);
     }
-}
\ No newline at end of file
+
+    // ── TaskBuilder Tests ───────────────────────────────────
+
+    #[test]
+    fn test_builder_matches_new_plus_setters() {
+        use crate::domain::entities::user::GeoCoordinates;
+
+        let office = Location::new(
+            Some("Office".to_string()),
+            "Lyon".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(45.76, 4.84).unwrap(),
+        ).unwrap();
+        let created = chrono::TimeZone::with_ymd_and_hms(&Utc, 2026, 1, 5, 9, 0, 0).unwrap();
+
+        let built = TaskBuilder::new("Review budget", Periodicity::daily().unwrap())
+            .description("Quarterly numbers")
+            .priority(TaskPriority::High)
+            .locations(vec![Some(office.clone())])
+            .min_hands(AvailabilityLevel::Limited)
+            .min_eyes(AvailabilityLevel::Full)
+            .min_cognitive(AvailabilityLevel::Full)
+            .min_device(DeviceAccess::Computer)
+            .allowed_mobility(vec![Mobility::Stationary])
+            .timestamps(created, created)
+            .build()
+            .unwrap();
+
+        let mut expected = Task::with_timestamps("Review budget".to_string(), Periodicity::daily().unwrap(), created, created).unwrap();
+        expected.set_description(Some("Quarterly numbers".to_string())).unwrap();
+        expected.set_priority(TaskPriority::High);
+        expected.set_locations(vec![Some(office)]);
+        expected.set_min_hands(AvailabilityLevel::Limited);
+        expected.set_min_eyes(AvailabilityLevel::Full);
+        expected.set_min_cognitive(AvailabilityLevel::Full);
+        expected.set_min_device(DeviceAccess::Computer);
+        expected.set_allowed_mobility(vec![Mobility::Stationary]);
+
+        // Setters bump updated_at; the builder keeps the given timestamps
+        assert_eq!(built.updated_at(), created);
+        assert!(expected.updated_at() > created);
+        let mut built = built;
+        built.updated_at = expected.updated_at();
+        assert_eq!(built, expected);
+    }
+
+    #[test]
+    fn test_builder_validates_once_in_build() {
+        let long_description = "a".repeat(Task::max_description_length() + 1);
+        let result = TaskBuilder::new("Read", Periodicity::daily().unwrap())
+            .description(long_description)
+            .build();
+        assert!(matches!(result, Err(TaskValidationError::DescriptionTooLong { .. })));
+
+        let result = TaskBuilder::new("  ", Periodicity::daily().unwrap()).build();
+        assert!(matches!(result, Err(TaskValidationError::EmptyTitle)));
+    }
+
+    #[test]
+    fn test_revalidated_normalizes_and_rejects() {
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let task = TaskBuilder::new("Write", Periodicity::daily().unwrap())
+            .priority(TaskPriority::High)
+            .tags(vec!["work".to_string()])
+            .preferred_time_window(nine, noon)
+            .build()
+            .unwrap();
+        assert_eq!(task.revalidated(), Ok(task.clone()));
+
+        // As a deserializer could have filled it
+        let mut raw = task.clone();
+        raw.title = "  Write  ".to_string();
+        raw.tags = vec![" WORK".to_string(), "work".to_string()];
+        assert_eq!(raw.revalidated(), Ok(task.clone()));
+
+        raw.preferred_time_window = Some((noon, nine));
+        assert!(matches!(raw.revalidated(), Err(TaskValidationError::InvalidPreferredTimeWindow { .. })));
+    }
+
+    #[test]
+    fn test_completion_goal_progress() {
+        let mut task = Task::new("Run".to_string(), Periodicity::daily().unwrap()).unwrap();
+        assert_eq!(task.goal_progress(3), 0.0);
+        assert!(!task.is_goal_reached(3));
+
+        task.set_completion_goal(Some(12)).unwrap();
+        assert_eq!(task.goal_progress(3), 0.25);
+        assert!(!task.is_goal_reached(3));
+        assert_eq!(task.goal_progress(12), 1.0);
+        assert!(task.is_goal_reached(12));
+        assert_eq!(task.goal_progress(15), 1.0);
+
+        assert_eq!(task.set_completion_goal(Some(0)), Err(TaskValidationError::ZeroCompletionGoal));
+        assert_eq!(task.completion_goal(), Some(12));
+    }
+
+    #[test]
+    fn test_preferred_time_window() {
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+        let task = TaskBuilder::new("Write", Periodicity::daily().unwrap())
+            .preferred_time_window(nine, noon)
+            .build()
+            .unwrap();
+        assert_eq!(task.preferred_time_window(), Some((nine, noon)));
+
+        let result = TaskBuilder::new("Write", Periodicity::daily().unwrap())
+            .preferred_time_window(noon, nine)
+            .build();
+        assert!(matches!(result, Err(TaskValidationError::InvalidPreferredTimeWindow { .. })));
+
+        let mut task = task;
+        assert!(task.set_preferred_time_window(Some((noon, noon))).is_err());
+        assert_eq!(task.preferred_time_window(), Some((nine, noon)));
+        task.set_preferred_time_window(None).unwrap();
+        assert_eq!(task.preferred_time_window(), None);
+    }
+
+    #[test]
+    fn test_tags_are_normalized_and_unique() {
+        let mut task = Task::new("Report".to_string(), Periodicity::daily().unwrap()).unwrap();
+
+        assert_eq!(task.add_tag("  Work "), Ok(true));
+        assert_eq!(task.add_tag("WORK"), Ok(false));
+        assert_eq!(task.add_tag("writing"), Ok(true));
+        assert_eq!(task.tags(), ["work", "writing"]);
+        assert!(task.has_tag("Work"));
+        assert!(!task.has_tag("home"));
+
+        assert_eq!(task.add_tag("   "), Err(TaskValidationError::EmptyTag));
+        assert!(task.remove_tag(" WRITING"));
+        assert!(!task.remove_tag("writing"));
+        assert_eq!(task.tags(), ["work"]);
+
+        // The builder applies the same rules
+        let built = TaskBuilder::new("Report", Periodicity::daily().unwrap())
+            .tags(vec!["Work".to_string(), "work ".to_string()])
+            .build()
+            .unwrap();
+        assert_eq!(built.tags(), ["work"]);
+        let result = TaskBuilder::new("Report", Periodicity::daily().unwrap())
+            .tags(vec!["".to_string()])
+            .build();
+        assert_eq!(result.unwrap_err(), TaskValidationError::EmptyTag);
+    }
+
+    #[test]
+    fn test_tag_count_is_limited() {
+        let mut task = Task::new("Report".to_string(), Periodicity::daily().unwrap()).unwrap();
+        for i in 0..Task::max_tags() {
+            task.add_tag(&format!("tag{}", i)).unwrap();
+        }
+
+        assert_eq!(task.add_tag("one-too-many"), Err(TaskValidationError::TooManyTags { max: Task::max_tags() }));
+        // Re-adding an existing tag is still fine
+        assert_eq!(task.add_tag("tag0"), Ok(false));
+    }
+
+    #[test]
+    fn test_clock_controls_timestamps() {
+        use crate::domain::clock::FixedClock;
+        use chrono::TimeZone;
+
+        let created = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        let edited = Utc.with_ymd_and_hms(2026, 3, 2, 18, 30, 0).unwrap();
+
+        let mut task = Task::new_with_clock(
+            "Report".to_string(),
+            Periodicity::daily().unwrap(),
+            &FixedClock::new(created),
+        ).unwrap();
+        assert_eq!((task.created_at(), task.updated_at()), (created, created));
+
+        task.set_priority(TaskPriority::High);
+        task.touch_with(FixedClock::new(edited).now());
+        assert_eq!((task.created_at(), task.updated_at()), (created, edited));
+
+        // updated_at never precedes created_at
+        task.touch_with(created - chrono::Duration::days(1));
+        assert_eq!(task.updated_at(), created);
+    }
+
+    #[test]
+    fn test_effective_priority_escalates_towards_deadline() {
+        use chrono::TimeZone;
+
+        let deadline = Utc.with_ymd_and_hms(2026, 5, 10, 18, 0, 0).unwrap();
+        let window = chrono::Duration::hours(config::task_deadline_escalation_hours() as i64);
+        let task = TaskBuilder::new("Tax return", Periodicity::daily().unwrap())
+            .priority(TaskPriority::Medium)
+            .soft_deadline(deadline)
+            .build()
+            .unwrap();
+
+        let a_second = chrono::Duration::seconds(1);
+        assert_eq!(task.effective_priority(deadline - window - a_second), TaskPriority::Medium);
+        assert_eq!(task.effective_priority(deadline - window), TaskPriority::High);
+        assert_eq!(task.effective_priority(deadline - a_second), TaskPriority::High);
+        assert_eq!(task.effective_priority(deadline), TaskPriority::Urgent);
+        assert_eq!(task.effective_priority(deadline + chrono::Duration::days(3)), TaskPriority::Urgent);
+
+        // The stored priority is untouched
+        assert_eq!(task.priority(), TaskPriority::Medium);
+    }
+
+    #[test]
+    fn test_effective_priority_without_deadline() {
+        let mut task = Task::new("Read".to_string(), Periodicity::daily().unwrap()).unwrap();
+        task.set_priority(TaskPriority::Low);
+        assert_eq!(task.effective_priority(Utc::now()), TaskPriority::Low);
+
+        task.set_soft_deadline(Some(Utc::now() - chrono::Duration::hours(1)));
+        assert_eq!(task.effective_priority(Utc::now()), TaskPriority::Urgent);
+        task.set_soft_deadline(None);
+        assert_eq!(task.effective_priority(Utc::now()), TaskPriority::Low);
+        assert_eq!(TaskPriority::Urgent.escalated(), TaskPriority::Urgent);
+    }
+}
@@ -1,6 +1,9 @@
-use chrono::{DateTime, Utc};
-use super::OccurenceRep;
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, FixedOffset, Months, NaiveDate, SecondsFormat, TimeZone, Utc};
+use super::{OccurenceRep, OccurrenceTimingSettings, RepetitionUnit};
 use crate::config;
+use crate::domain::clock::{Clock, SystemClock};
 
 // ========================================================================
 // VALIDATION ERRORS
@@ -11,6 +14,7 @@ pub enum TaskOccurrenceValidationError {
     NotesTooLong { max: usize, actual: usize },
     InvalidTimeWindow { reason: String },
     InvalidRepIndex { expected: u8, actual: u8 },
+    InvalidWeight { reason: String },
 }
 
 impl std::fmt::Display for TaskOccurrenceValidationError {
@@ -25,6 +29,9 @@ impl std::fmt::Display for TaskOccurrenceValidationError {
             TaskOccurrenceValidationError::InvalidRepIndex { expected, actual } => {
                 write!(f, "Invalid rep index: expected 0-{}, got {}", expected - 1, actual)
             }
+            TaskOccurrenceValidationError::InvalidWeight { reason } => {
+                write!(f, "Invalid rep weight: {}", reason)
+            }
         }
     }
 }
@@ -40,10 +47,23 @@ impl std::error::Error for TaskOccurrenceValidationError {}
 pub enum OccurrenceStatus {
     /// No repetitions have been completed
     NotStarted,
-    /// Some (but not all) repetitions have been completed
+    /// Some repetitions have been completed and some are still pending
     InProgress,
-    /// All repetitions have been completed
+    /// No repetition is pending and at least one was completed (the rest,
+    /// if any, were skipped)
     Completed,
+    /// Every repetition was skipped
+    Skipped,
+}
+
+/// How skipped occurrences count in streaks and completion rates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkipPolicy {
+    /// Neither success nor failure: ignored
+    #[default]
+    Neutral,
+    /// Counts as a miss, like an incomplete occurrence
+    CountsAsMiss,
 }
 
 // ========================================================================
@@ -67,7 +87,8 @@ pub enum OccurrenceStatus {
 /// - Task is the aggregate root
 /// - TaskOccurrence cannot exist without a Task
 /// - In persistence layer, task_id would link back to Task
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TaskOccurrence {
     // Note: task_id would be added by persistence layer to link back to Task
     
@@ -158,15 +179,20 @@ impl TaskOccurrence {
         let completed_count = self.repetitions.iter()
             .filter(|r| r.is_completed())
             .count();
+        let pending_count = self.repetitions.iter()
+            .filter(|r| r.is_pending())
+            .count();
 
-        match completed_count {
-            0 => OccurrenceStatus::NotStarted,
-            n if n == self.repetitions.len() => OccurrenceStatus::Completed,
+        match (completed_count, pending_count) {
+            (n, _) if n == self.repetitions.len() => OccurrenceStatus::Completed,
+            (0, 0) => OccurrenceStatus::Skipped,
+            (_, 0) => OccurrenceStatus::Completed,
+            (0, _) => OccurrenceStatus::NotStarted,
             _ => OccurrenceStatus::InProgress,
         }
     }
 
-    /// Convenience method: is this occurrence fully completed?
+    /// Convenience method: is this occurrence completed (see `OccurrenceStatus::Completed`)?
     pub fn is_completed(&self) -> bool {
         self.status() == OccurrenceStatus::Completed
     }
@@ -183,14 +209,32 @@ impl TaskOccurrence {
 
     /// Mark a specific repetition as complete
     pub fn mark_rep_complete(&mut self, rep_index: u8) -> Result<(), TaskOccurrenceValidationError> {
+        self.mark_rep_complete_with(rep_index, &SystemClock)
+    }
+
+    /// Mark a specific repetition as complete as of `clock`'s current time
+    pub fn mark_rep_complete_with(
+        &mut self,
+        rep_index: u8,
+        clock: &dyn Clock,
+    ) -> Result<(), TaskOccurrenceValidationError> {
+        self.mark_rep_complete_at(rep_index, clock.now())
+    }
+
+    /// Mark a specific repetition as complete as of `at` (e.g., a `Clock` reading)
+    pub fn mark_rep_complete_at(
+        &mut self,
+        rep_index: u8,
+        at: DateTime<Utc>,
+    ) -> Result<(), TaskOccurrenceValidationError> {
         let rep_count = self.rep_count();
         let rep = self.repetitions.get_mut(rep_index as usize)
-            .ok_or_else(|| TaskOccurrenceValidationError::InvalidRepIndex {
+            .ok_or(TaskOccurrenceValidationError::InvalidRepIndex {
                 expected: rep_count,
                 actual: rep_index,
             })?;
         
-        rep.mark_complete();
+        rep.mark_complete_at(at);
         Ok(())
     }
 
@@ -198,7 +242,7 @@ impl TaskOccurrence {
     pub fn mark_rep_incomplete(&mut self, rep_index: u8) -> Result<(), TaskOccurrenceValidationError> {
         let rep_count = self.rep_count();
         let rep = self.repetitions.get_mut(rep_index as usize)
-            .ok_or_else(|| TaskOccurrenceValidationError::InvalidRepIndex {
+            .ok_or(TaskOccurrenceValidationError::InvalidRepIndex {
                 expected: rep_count,
                 actual: rep_index,
             })?;
@@ -207,10 +251,32 @@ impl TaskOccurrence {
         Ok(())
     }
 
+    /// Mark a specific repetition as intentionally skipped
+    pub fn mark_rep_skipped(
+        &mut self,
+        rep_index: u8,
+        reason: Option<String>,
+    ) -> Result<(), TaskOccurrenceValidationError> {
+        let rep_count = self.rep_count();
+        let rep = self.repetitions.get_mut(rep_index as usize)
+            .ok_or(TaskOccurrenceValidationError::InvalidRepIndex {
+                expected: rep_count,
+                actual: rep_index,
+            })?;
+        
+        rep.mark_skipped(reason)
+    }
+
     /// Mark all repetitions as complete
     pub fn mark_all_complete(&mut self) {
+        self.mark_all_complete_with(&SystemClock);
+    }
+
+    /// Mark all repetitions as complete as of `clock`'s current time
+    pub fn mark_all_complete_with(&mut self, clock: &dyn Clock) {
+        let now = clock.now();
         for rep in &mut self.repetitions {
-            rep.mark_complete();
+            rep.mark_complete_at(now);
         }
     }
 
@@ -221,6 +287,18 @@ impl TaskOccurrence {
         }
     }
 
+    /// Set how much a specific repetition counts towards `progress()`
+    pub fn set_rep_weight(&mut self, rep_index: u8, weight: f32) -> Result<(), TaskOccurrenceValidationError> {
+        let rep_count = self.rep_count();
+        let rep = self.repetitions.get_mut(rep_index as usize)
+            .ok_or(TaskOccurrenceValidationError::InvalidRepIndex {
+                expected: rep_count,
+                actual: rep_index,
+            })?;
+        
+        rep.set_weight(weight)
+    }
+
     /// Set notes for a specific repetition
     pub fn set_rep_notes(
         &mut self,
@@ -229,7 +307,7 @@ impl TaskOccurrence {
     ) -> Result<(), TaskOccurrenceValidationError> {
         let rep_count = self.rep_count();
         let rep = self.repetitions.get_mut(rep_index as usize)
-            .ok_or_else(|| TaskOccurrenceValidationError::InvalidRepIndex {
+            .ok_or(TaskOccurrenceValidationError::InvalidRepIndex {
                 expected: rep_count,
                 actual: rep_index,
             })?;
@@ -251,29 +329,333 @@ impl TaskOccurrence {
         Ok(())
     }
 
-    /// Check if this occurrence is overdue (window has passed and not completed)
+    /// Check if this occurrence is overdue (window has passed with reps still pending)
     pub fn is_overdue(&self) -> bool {
-        !self.is_completed() && Utc::now() > self.window_end
+        self.is_overdue_with(&SystemClock)
+    }
+
+    /// Check if this occurrence is overdue according to `clock`
+    pub fn is_overdue_with(&self, clock: &dyn Clock) -> bool {
+        self.is_overdue_at(clock.now())
+    }
+
+    /// Check if this occurrence is overdue as of `now`
+    pub fn is_overdue_at(&self, now: DateTime<Utc>) -> bool {
+        matches!(self.status(), OccurrenceStatus::NotStarted | OccurrenceStatus::InProgress)
+            && now > self.window_end
     }
 
     /// Check if this occurrence is currently active (within time window)
     pub fn is_active(&self) -> bool {
-        let now = Utc::now();
+        self.is_active_with(&SystemClock)
+    }
+
+    /// Check if this occurrence is active according to `clock`
+    pub fn is_active_with(&self, clock: &dyn Clock) -> bool {
+        self.is_active_at(clock.now())
+    }
+
+    /// Check if `now` falls within this occurrence's time window (inclusive)
+    pub fn is_active_at(&self, now: DateTime<Utc>) -> bool {
         now >= self.window_start && now <= self.window_end
     }
 
     /// Check if this occurrence is in the future
     pub fn is_future(&self) -> bool {
-        Utc::now() < self.window_start
+        self.is_future_with(&SystemClock)
+    }
+
+    /// Check if this occurrence is in the future according to `clock`
+    pub fn is_future_with(&self, clock: &dyn Clock) -> bool {
+        self.is_future_at(clock.now())
     }
 
-    /// Get completion progress (0.0 to 1.0)
+    /// Check if this occurrence's window starts after `now`
+    pub fn is_future_at(&self, now: DateTime<Utc>) -> bool {
+        now < self.window_start
+    }
+
+    /// When to remind the user, `lead` before the window opens
+    pub fn reminder_time(&self, lead: Duration) -> DateTime<Utc> {
+        self.window_start - lead
+    }
+
+    /// Concrete `[not_before, best_before]` window of each rep, by rep index
+    /// 
+    /// Times are read at `offset` on the local date the occurrence starts on.
+    /// Each rep falls back to the occurrence-wide bounds it does not override,
+    /// and a missing bound leaves the window open up to the occurrence's own
+    /// edge. A window crossing midnight closes on the following day. Every
+    /// window is clamped to the occurrence's, so it may come out empty.
+    pub fn rep_windows(
+        &self,
+        timing: Option<&OccurrenceTimingSettings>,
+        offset: FixedOffset,
+    ) -> Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+        let (window_start, window_end) = (self.window_start.with_timezone(&offset), self.window_end.with_timezone(&offset));
+        let date = window_start.date_naive();
+        let at = |day: NaiveDate, time| offset.from_local_datetime(&day.and_time(time)).single();
+
+        (0..self.rep_count())
+            .map(|rep_index| {
+                let rep = timing
+                    .and_then(|t| t.rep_timing_settings.as_ref())
+                    .and_then(|reps| reps.iter().find(|rep| rep.rep_index == rep_index));
+                let not_before = rep.and_then(|r| r.not_before).or(timing.and_then(|t| t.not_before));
+                let best_before = rep.and_then(|r| r.best_before).or(timing.and_then(|t| t.best_before));
+
+                let open = not_before.and_then(|time| at(date, time)).unwrap_or(window_start);
+                let close = match (not_before, best_before) {
+                    (Some(open), Some(close)) if open > close => at(date + Duration::days(1), close),
+                    (_, Some(close)) => at(date, close),
+                    (_, None) => None,
+                }
+                .unwrap_or(window_end);
+
+                (open.max(window_start), close.min(window_end))
+            })
+            .collect()
+    }
+
+    /// Get completion progress (0.0 to 1.0), weighted by each rep's weight
     pub fn progress(&self) -> f32 {
         if self.repetitions.is_empty() {
             return 1.0;
         }
-        let completed = self.repetitions.iter().filter(|r| r.is_completed()).count();
-        completed as f32 / self.repetitions.len() as f32
+        let total: f32 = self.repetitions.iter().map(|r| r.weight()).sum();
+        let completed: f32 = self.repetitions.iter()
+            .filter(|r| r.is_completed())
+            .map(|r| r.weight())
+            .sum();
+        completed / total
+    }
+
+    // ── DISPLAY HELPERS ─────────────────────────────────────
+
+    /// Compact completion summary for list rendering
+    ///
+    /// Returns "Completed" when nothing is pending and some rep is done,
+    /// "Skipped" when every rep was skipped, "Overdue" when the window has
+    /// passed without completion, and "n/m done" otherwise.
+    pub fn completion_summary(&self) -> String {
+        match self.status() {
+            OccurrenceStatus::Completed => return "Completed".to_string(),
+            OccurrenceStatus::Skipped => return "Skipped".to_string(),
+            _ => {}
+        }
+        if self.is_overdue() {
+            return "Overdue".to_string();
+        }
+        let done = self.repetitions.iter().filter(|r| r.is_completed()).count();
+        format!("{}/{} done", done, self.repetitions.len())
+    }
+
+    /// Single-character status marker for compact UIs
+    pub fn status_glyph(&self) -> char {
+        match self.status() {
+            OccurrenceStatus::Completed => '✓',
+            OccurrenceStatus::Skipped => '⊘',
+            _ if self.is_overdue() => '!',
+            OccurrenceStatus::InProgress => '◐',
+            OccurrenceStatus::NotStarted => '○',
+        }
+    }
+}
+
+// ========================================================================
+// AGGREGATION
+// ========================================================================
+
+/// Average completion progress per local day, for calendar heatmaps
+///
+/// Each occurrence is attributed to the date its window starts on in `tz`;
+/// days with several occurrences (across tasks) report the mean of their
+/// `progress()`. Days without occurrences are absent from the map.
+pub fn completion_heatmap(
+    occurrences: &[TaskOccurrence],
+    tz: &impl TimeZone,
+) -> BTreeMap<NaiveDate, f32> {
+    let mut totals: BTreeMap<NaiveDate, (f32, usize)> = BTreeMap::new();
+
+    for occurrence in occurrences {
+        let day = occurrence.window_start.with_timezone(tz).date_naive();
+        let entry = totals.entry(day).or_insert((0.0, 0));
+        entry.0 += occurrence.progress();
+        entry.1 += 1;
+    }
+
+    totals
+        .into_iter()
+        .map(|(day, (sum, count))| (day, sum / count as f32))
+        .collect()
+}
+
+/// Number of consecutive completed occurrences, counting back from the latest
+/// 
+/// Occurrences that are still open (not overdue) don't break the streak, so
+/// today's unfinished occurrence doesn't reset it. Skipped occurrences are
+/// passed over or break the streak depending on `policy`. Gaps between
+/// occurrences are not noticed; see `current_streak_at`.
+pub fn current_streak(occurrences: &[TaskOccurrence], policy: SkipPolicy) -> u32 {
+    let mut ordered: Vec<&TaskOccurrence> = occurrences.iter().collect();
+    ordered.sort_by_key(|o| std::cmp::Reverse(o.window_start));
+
+    let mut streak = 0;
+    for occurrence in ordered {
+        match occurrence.status() {
+            OccurrenceStatus::Completed => streak += 1,
+            OccurrenceStatus::Skipped if policy == SkipPolicy::Neutral => {}
+            OccurrenceStatus::Skipped => break,
+            _ if occurrence.is_overdue() => break,
+            _ => {}
+        }
+    }
+    streak
+}
+
+/// Like `current_streak`, but also breaks on missing windows, as of `now`
+/// 
+/// Occurrences are expected one per `unit` (a day, week, month or year apart);
+/// a window with no occurrence breaks the streak like an incomplete one. The
+/// window containing `now` may be missing or unfinished without breaking it,
+/// and occurrences starting after `now` are ignored. With
+/// `RepetitionUnit::None` there are no windows to miss.
+pub fn current_streak_at(
+    occurrences: &[TaskOccurrence],
+    unit: RepetitionUnit,
+    now: DateTime<Utc>,
+    policy: SkipPolicy,
+) -> u32 {
+    let mut ordered: Vec<&TaskOccurrence> = occurrences.iter()
+        .filter(|o| o.window_start <= now)
+        .collect();
+    ordered.sort_by_key(|o| std::cmp::Reverse(o.window_start));
+
+    // A latest window followed by a whole window that has already ended
+    // means that window is missing
+    if let Some(latest) = ordered.first() {
+        let after_next = window_step(unit, latest.window_start, 1)
+            .and_then(|next| window_step(unit, next, 1));
+        if after_next.is_some_and(|after_next| after_next <= now) {
+            return 0;
+        }
+    }
+
+    let mut streak = 0;
+    let mut expected: Option<DateTime<Utc>> = None;
+    for occurrence in ordered {
+        match expected {
+            Some(start) if occurrence.window_start < start => break,
+            // Another occurrence of a window already counted
+            Some(start) if occurrence.window_start > start => continue,
+            _ => {}
+        }
+        match occurrence.status() {
+            OccurrenceStatus::Completed => streak += 1,
+            OccurrenceStatus::Skipped if policy == SkipPolicy::Neutral => {}
+            OccurrenceStatus::Skipped => break,
+            _ if occurrence.is_overdue_at(now) => break,
+            _ => {}
+        }
+        expected = window_step(unit, occurrence.window_start, -1);
+    }
+    streak
+}
+
+/// Start of the window `steps` windows away from the one starting at `start`
+fn window_step(unit: RepetitionUnit, start: DateTime<Utc>, steps: i32) -> Option<DateTime<Utc>> {
+    let months = |count: u32| {
+        let months = Months::new(count * steps.unsigned_abs());
+        if steps < 0 { start.checked_sub_months(months) } else { start.checked_add_months(months) }
+    };
+    match unit {
+        RepetitionUnit::Day => Some(start + Duration::days(steps as i64)),
+        RepetitionUnit::Week => Some(start + Duration::weeks(steps as i64)),
+        RepetitionUnit::Month => months(1),
+        RepetitionUnit::Year => months(12),
+        RepetitionUnit::None => None,
+    }
+}
+
+/// Share of occurrences that were completed (0.0 when none count)
+/// 
+/// Under `SkipPolicy::Neutral`, skipped occurrences are left out entirely.
+pub fn completion_rate(occurrences: &[TaskOccurrence], policy: SkipPolicy) -> f32 {
+    let counted: Vec<OccurrenceStatus> = occurrences.iter()
+        .map(TaskOccurrence::status)
+        .filter(|status| !(policy == SkipPolicy::Neutral && *status == OccurrenceStatus::Skipped))
+        .collect();
+    if counted.is_empty() {
+        return 0.0;
+    }
+    let completed = counted.iter().filter(|s| **s == OccurrenceStatus::Completed).count();
+    completed as f32 / counted.len() as f32
+}
+
+// ========================================================================
+// REMINDERS
+// ========================================================================
+
+/// Occurrences whose reminder falls in `[now, now + horizon)`, earliest first
+/// 
+/// Each reminder is `lead` before its window opens (see `reminder_time`).
+/// Occurrences with nothing left to do (completed, or skipped) are left out.
+/// Polling with back-to-back horizons yields every reminder exactly once.
+pub fn upcoming_reminders(
+    occurrences: &[TaskOccurrence],
+    now: DateTime<Utc>,
+    horizon: Duration,
+    lead: Duration,
+) -> Vec<&TaskOccurrence> {
+    let mut upcoming: Vec<&TaskOccurrence> = occurrences.iter()
+        .filter(|o| !matches!(o.status(), OccurrenceStatus::Completed | OccurrenceStatus::Skipped))
+        .filter(|o| {
+            let at = o.reminder_time(lead);
+            now <= at && at < now + horizon
+        })
+        .collect();
+    upcoming.sort_by_key(|o| o.reminder_time(lead));
+    upcoming
+}
+
+// ========================================================================
+// EXPORT
+// ========================================================================
+
+/// Completion history as CSV, one row per occurrence, for spreadsheets
+/// 
+/// Columns: window_start, window_end, rep_count, completed_reps, status,
+/// last_completed_at, notes. Timestamps are RFC 3339 in UTC; missing values
+/// are empty. Follows RFC 4180: CRLF line endings, and fields holding a comma,
+/// quote or line break are quoted with inner quotes doubled.
+pub fn occurrences_to_csv(occurrences: &[TaskOccurrence]) -> String {
+    let timestamp = |at: DateTime<Utc>| at.to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let mut csv = String::from("window_start,window_end,rep_count,completed_reps,status,last_completed_at,notes\r\n");
+    for occurrence in occurrences {
+        let completed_reps = occurrence.repetitions.iter().filter(|r| r.is_completed()).count();
+        let fields = [
+            timestamp(occurrence.window_start),
+            timestamp(occurrence.window_end),
+            occurrence.rep_count().to_string(),
+            completed_reps.to_string(),
+            format!("{:?}", occurrence.status()),
+            occurrence.last_completed_at().map(timestamp).unwrap_or_default(),
+            occurrence.notes().unwrap_or_default().to_string(),
+        ];
+        let escaped: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&escaped.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Quote a CSV field if it needs it
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
     }
 }
 
@@ -350,6 +732,34 @@ mod tests {
         assert_eq!(occurrence.progress(), 1.0);
     }
 
+    #[test]
+    fn test_weighted_progress() {
+        let start = Utc.with_ymd_and_hms(2026, 2, 7, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 2, 7, 23, 59, 59).unwrap();
+        
+        // Equal weights by default
+        let mut occurrence = TaskOccurrence::new(start, end, 2).unwrap();
+        occurrence.mark_rep_complete(0).unwrap();
+        assert_eq!(occurrence.progress(), 0.5);
+        
+        // Main set (3.0) done, warmup (1.0) not
+        occurrence.set_rep_weight(0, 3.0).unwrap();
+        assert_eq!(occurrence.repetitions()[1].weight(), 1.0);
+        assert_eq!(occurrence.progress(), 0.75);
+        
+        assert!(matches!(
+            occurrence.set_rep_weight(1, 0.0),
+            Err(TaskOccurrenceValidationError::InvalidWeight { .. })
+        ));
+        assert!(occurrence.set_rep_weight(1, -1.0).is_err());
+        assert!(occurrence.set_rep_weight(1, f32::NAN).is_err());
+        assert!(matches!(
+            occurrence.set_rep_weight(2, 1.0),
+            Err(TaskOccurrenceValidationError::InvalidRepIndex { .. })
+        ));
+        assert_eq!(occurrence.progress(), 0.75);
+    }
+
     #[test]
     fn test_occurrence_mark_all() {
         let start = Utc.with_ymd_and_hms(2026, 2, 7, 0, 0, 0).unwrap();
@@ -429,6 +839,65 @@ mod tests {
         assert!(future.is_future());
     }
 
+    #[test]
+    fn test_occurrence_checks_read_the_injected_clock() {
+        use crate::domain::clock::FixedClock;
+
+        let start = Utc.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 3, 10, 17, 0, 0).unwrap();
+        let mut occurrence = TaskOccurrence::new(start, end, 2).unwrap();
+
+        let morning = FixedClock::new(Utc.with_ymd_and_hms(2026, 3, 10, 8, 0, 0).unwrap());
+        let noon = FixedClock::new(Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap());
+        let night = FixedClock::new(Utc.with_ymd_and_hms(2026, 3, 10, 22, 0, 0).unwrap());
+
+        assert!(occurrence.is_future_with(&morning));
+        assert!(!occurrence.is_active_with(&morning));
+        assert!(occurrence.is_active_with(&noon));
+        assert!(!occurrence.is_overdue_with(&noon));
+        assert!(occurrence.is_overdue_with(&night));
+
+        occurrence.mark_rep_complete_with(1, &noon).unwrap();
+        assert_eq!(occurrence.repetitions()[1].completed_at(), Some(noon.now()));
+        assert!(occurrence.mark_rep_complete_with(2, &noon).is_err());
+
+        occurrence.mark_all_complete_with(&night);
+        assert_eq!(occurrence.repetitions()[0].completed_at(), Some(night.now()));
+        assert_eq!(occurrence.last_completed_at(), Some(night.now()));
+        assert!(!occurrence.is_overdue_with(&night));
+
+        let mut rep = OccurenceRep::new(0);
+        rep.mark_complete_with(&morning);
+        assert_eq!(rep.completed_at(), Some(morning.now()));
+    }
+
+    #[test]
+    fn test_occurrence_time_checks_at_window_boundaries() {
+        let start = Utc.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 3, 10, 17, 0, 0).unwrap();
+        let mut occurrence = TaskOccurrence::new(start, end, 1).unwrap();
+        let second = chrono::Duration::seconds(1);
+
+        let before = start - second;
+        assert!(occurrence.is_future_at(before));
+        assert!(!occurrence.is_active_at(before));
+        assert!(!occurrence.is_overdue_at(before));
+
+        assert!(!occurrence.is_future_at(start));
+        assert!(occurrence.is_active_at(start));
+
+        assert!(occurrence.is_active_at(end));
+        assert!(!occurrence.is_overdue_at(end));
+
+        let after = end + second;
+        assert!(!occurrence.is_active_at(after));
+        assert!(occurrence.is_overdue_at(after));
+
+        // A completed occurrence is never overdue
+        occurrence.mark_rep_complete(0).unwrap();
+        assert!(!occurrence.is_overdue_at(after));
+    }
+
     #[test]
     fn test_occurrence_last_completed_at() {
         let start = Utc.with_ymd_and_hms(2026, 2, 7, 0, 0, 0).unwrap();
@@ -438,16 +907,16 @@ mod tests {
         
         assert!(occurrence.last_completed_at().is_none());
         
-        occurrence.mark_rep_complete(0).unwrap();
-        let first_completed = occurrence.last_completed_at();
-        assert!(first_completed.is_some());
-        
-        std::thread::sleep(std::time::Duration::from_millis(10));
+        let morning = Utc.with_ymd_and_hms(2026, 2, 7, 8, 0, 0).unwrap();
+        let evening = Utc.with_ymd_and_hms(2026, 2, 7, 20, 0, 0).unwrap();
+        occurrence.mark_rep_complete_at(2, evening).unwrap();
+        occurrence.mark_rep_complete_at(0, morning).unwrap();
+        assert_eq!(occurrence.last_completed_at(), Some(evening));
         
-        occurrence.mark_rep_complete(2).unwrap();
-        let last_completed = occurrence.last_completed_at();
-        assert!(last_completed.is_some());
-        assert!(last_completed > first_completed);
+        // Completing again keeps the original time
+        occurrence.mark_rep_complete_at(0, evening + chrono::Duration::hours(1)).unwrap();
+        assert_eq!(occurrence.repetitions()[0].completed_at(), Some(morning));
+        assert!(occurrence.mark_rep_complete_at(3, evening).is_err());
     }
 
     #[test]
@@ -467,4 +936,307 @@ mod tests {
         let result = occurrence.set_rep_notes(0, Some(long_rep_notes));
         assert!(matches!(result, Err(TaskOccurrenceValidationError::NotesTooLong { .. })));
     }
+
+    #[test]
+    fn test_completion_summary_in_progress() {
+        let start = Utc.with_ymd_and_hms(2099, 2, 7, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2099, 2, 7, 23, 59, 59).unwrap();
+
+        let mut occurrence = TaskOccurrence::new(start, end, 3).unwrap();
+        assert_eq!(occurrence.completion_summary(), "0/3 done");
+        assert_eq!(occurrence.status_glyph(), '○');
+
+        occurrence.mark_rep_complete(0).unwrap();
+        occurrence.mark_rep_complete(2).unwrap();
+        assert_eq!(occurrence.completion_summary(), "2/3 done");
+        assert_eq!(occurrence.status_glyph(), '◐');
+    }
+
+    #[test]
+    fn test_completion_summary_completed() {
+        let start = Utc.with_ymd_and_hms(2026, 2, 7, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 2, 7, 23, 59, 59).unwrap();
+
+        let mut occurrence = TaskOccurrence::new(start, end, 3).unwrap();
+        occurrence.mark_all_complete();
+
+        assert_eq!(occurrence.completion_summary(), "Completed");
+        assert_eq!(occurrence.status_glyph(), '✓');
+    }
+
+    #[test]
+    fn test_completion_summary_overdue() {
+        let start = Utc.with_ymd_and_hms(2020, 2, 7, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 2, 7, 23, 59, 59).unwrap();
+
+        let mut occurrence = TaskOccurrence::new(start, end, 3).unwrap();
+        occurrence.mark_rep_complete(0).unwrap();
+
+        assert_eq!(occurrence.completion_summary(), "Overdue");
+        assert_eq!(occurrence.status_glyph(), '!');
+    }
+
+    #[test]
+    fn test_completion_heatmap_averages_per_day() {
+        let day_start = Utc.with_ymd_and_hms(2026, 2, 7, 0, 0, 0).unwrap();
+        let day_end = Utc.with_ymd_and_hms(2026, 2, 7, 23, 59, 59).unwrap();
+
+        let mut full = TaskOccurrence::new(day_start, day_end, 1).unwrap();
+        full.mark_all_complete();
+
+        let mut half = TaskOccurrence::new(day_start, day_end, 2).unwrap();
+        half.mark_rep_complete(0).unwrap();
+
+        let next_day = TaskOccurrence::new(
+            Utc.with_ymd_and_hms(2026, 2, 8, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 8, 23, 59, 59).unwrap(),
+            1,
+        ).unwrap();
+
+        let heatmap = completion_heatmap(&[full, half, next_day], &Utc);
+
+        assert_eq!(heatmap.len(), 2);
+        assert_eq!(heatmap[&NaiveDate::from_ymd_opt(2026, 2, 7).unwrap()], 0.75);
+        assert_eq!(heatmap[&NaiveDate::from_ymd_opt(2026, 2, 8).unwrap()], 0.0);
+    }
+
+    #[test]
+    fn test_completion_heatmap_uses_local_date() {
+        // 02:00 UTC on Feb 7 is still Feb 6 in New York
+        let start = Utc.with_ymd_and_hms(2026, 2, 7, 2, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 2, 7, 3, 0, 0).unwrap();
+        let occurrence = TaskOccurrence::new(start, end, 1).unwrap();
+
+        let heatmap = completion_heatmap(&[occurrence], &chrono_tz::America::New_York);
+
+        assert!(heatmap.contains_key(&NaiveDate::from_ymd_opt(2026, 2, 6).unwrap()));
+    }
+
+    #[test]
+    fn test_rep_skip_is_distinct_from_pending() {
+        let mut rep = OccurenceRep::new(0);
+        rep.mark_skipped(Some("  Sick  ".to_string())).unwrap();
+        assert!(rep.is_skipped());
+        assert!(!rep.is_completed());
+        assert_eq!(rep.skip_reason(), Some("Sick"));
+
+        rep.mark_complete();
+        assert_eq!(rep.state(), crate::domain::entities::task::RepState::Completed);
+        assert_eq!(rep.skip_reason(), None);
+
+        rep.mark_incomplete();
+        assert!(rep.is_pending());
+    }
+
+    #[test]
+    fn test_occurrence_status_reflects_skips() {
+        let start = Utc.with_ymd_and_hms(2020, 2, 7, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 2, 7, 23, 59, 59).unwrap();
+
+        let mut occurrence = TaskOccurrence::new(start, end, 3).unwrap();
+        occurrence.mark_rep_skipped(0, None).unwrap();
+        assert_eq!(occurrence.status(), OccurrenceStatus::NotStarted);
+
+        occurrence.mark_rep_complete(1).unwrap();
+        assert_eq!(occurrence.status(), OccurrenceStatus::InProgress);
+        assert!(occurrence.is_overdue());
+
+        // Done or skipped, with one rep done: the partial completion shows
+        occurrence.mark_rep_skipped(2, None).unwrap();
+        assert_eq!(occurrence.status(), OccurrenceStatus::Completed);
+        assert!(!occurrence.is_overdue());
+        assert_eq!(occurrence.completion_summary(), "Completed");
+        assert_eq!(occurrence.status_glyph(), '✓');
+        assert_eq!(completion_rate(std::slice::from_ref(&occurrence), SkipPolicy::Neutral), 1.0);
+
+        // Nothing done at all
+        occurrence.mark_rep_skipped(1, None).unwrap();
+        assert_eq!(occurrence.status(), OccurrenceStatus::Skipped);
+        assert!(!occurrence.is_completed());
+        assert_eq!(occurrence.completion_summary(), "Skipped");
+        assert_eq!(occurrence.status_glyph(), '⊘');
+    }
+
+    #[test]
+    fn test_skip_reason_is_trimmed_before_length_check() {
+        let max = OccurenceRep::max_notes_length();
+        let mut rep = OccurenceRep::new(0);
+        let padded = format!("  {}  ", "x".repeat(max));
+        rep.mark_skipped(Some(padded)).unwrap();
+        assert_eq!(rep.skip_reason().map(str::len), Some(max));
+
+        assert!(rep.mark_skipped(Some("x".repeat(max + 1))).is_err());
+    }
+
+    #[test]
+    fn test_skip_is_neutral_for_streaks() {
+        let day = |d: u32| {
+            TaskOccurrence::new(
+                Utc.with_ymd_and_hms(2020, 3, d, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2020, 3, d, 23, 59, 59).unwrap(),
+                1,
+            ).unwrap()
+        };
+        let completed = |d| {
+            let mut o = day(d);
+            o.mark_all_complete();
+            o
+        };
+        let mut skipped = day(3);
+        skipped.mark_rep_skipped(0, Some("Rest day".to_string())).unwrap();
+
+        // Missed, done, skipped, done, done
+        let history = [day(1), completed(2), skipped, completed(4), completed(5)];
+
+        assert_eq!(current_streak(&history, SkipPolicy::Neutral), 3);
+        assert_eq!(current_streak(&history, SkipPolicy::CountsAsMiss), 2);
+        assert_eq!(completion_rate(&history, SkipPolicy::Neutral), 0.75);
+        assert_eq!(completion_rate(&history, SkipPolicy::CountsAsMiss), 0.6);
+    }
+
+    #[test]
+    fn test_streak_at_breaks_on_missing_window() {
+        let day = |d: u32| {
+            let mut o = TaskOccurrence::new(
+                Utc.with_ymd_and_hms(2020, 3, d, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2020, 3, d, 23, 59, 59).unwrap(),
+                1,
+            ).unwrap();
+            o.mark_all_complete();
+            o
+        };
+        let evening = |d| Utc.with_ymd_and_hms(2020, 3, d, 20, 0, 0).unwrap();
+        let streak = |history: &[TaskOccurrence], now| {
+            current_streak_at(history, RepetitionUnit::Day, now, SkipPolicy::Neutral)
+        };
+
+        // Unbroken 5-day streak, whether or not today has been generated yet
+        let unbroken: Vec<_> = (1..=5).map(day).collect();
+        assert_eq!(streak(&unbroken, evening(5)), 5);
+        assert_eq!(streak(&unbroken, evening(6)), 5);
+        // A whole day with nothing recorded ends it
+        assert_eq!(streak(&unbroken, evening(7)), 0);
+        // Later occurrences don't count yet
+        assert_eq!(streak(&unbroken, evening(3)), 3);
+
+        // Day 3 missing: only days 4 and 5 count
+        let gap = [day(1), day(2), day(4), day(5)];
+        assert_eq!(streak(&gap, evening(5)), 2);
+        // current_streak has no notion of windows
+        assert_eq!(current_streak(&gap, SkipPolicy::Neutral), 4);
+    }
+
+    #[test]
+    fn test_streak_at_weekly_and_monthly() {
+        let completed = |start: DateTime<Utc>, end: DateTime<Utc>| {
+            let mut o = TaskOccurrence::new(start, end, 1).unwrap();
+            o.mark_all_complete();
+            o
+        };
+
+        // Three consecutive weeks starting Monday 2020-03-02
+        let week = |n: i64| {
+            let start = Utc.with_ymd_and_hms(2020, 3, 2, 0, 0, 0).unwrap() + Duration::weeks(n);
+            completed(start, start + Duration::weeks(1) - Duration::seconds(1))
+        };
+        let weeks = [week(0), week(1), week(2)];
+        let now = Utc.with_ymd_and_hms(2020, 3, 25, 12, 0, 0).unwrap();
+        assert_eq!(current_streak_at(&weeks, RepetitionUnit::Week, now, SkipPolicy::Neutral), 3);
+        let gap = [week(0), week(2)];
+        assert_eq!(current_streak_at(&gap, RepetitionUnit::Week, now, SkipPolicy::Neutral), 1);
+
+        // January and February, across months of different lengths
+        let month = |m: u32| {
+            let start = Utc.with_ymd_and_hms(2020, m, 1, 0, 0, 0).unwrap();
+            completed(start, start.checked_add_months(Months::new(1)).unwrap() - Duration::seconds(1))
+        };
+        let months = [month(1), month(2)];
+        let now = Utc.with_ymd_and_hms(2020, 3, 15, 0, 0, 0).unwrap();
+        assert_eq!(current_streak_at(&months, RepetitionUnit::Month, now, SkipPolicy::Neutral), 2);
+    }
+
+    #[test]
+    fn test_occurrences_to_csv() {
+        let day = |d| Utc.with_ymd_and_hms(2026, 3, d, 0, 0, 0).unwrap();
+
+        let mut run = TaskOccurrence::new(day(1), day(2), 2).unwrap();
+        run.mark_rep_complete_at(0, day(1) + Duration::hours(7)).unwrap();
+        run.mark_rep_complete_at(1, day(1) + Duration::hours(19)).unwrap();
+        run.set_notes(Some("Ran 5km, felt \"great\"\nthen stretched".to_string())).unwrap();
+        let rest = TaskOccurrence::new(day(2), day(3), 1).unwrap();
+
+        let csv = occurrences_to_csv(&[run, rest]);
+        let lines: Vec<&str> = csv.split_terminator("\r\n").collect();
+
+        assert_eq!(lines, vec![
+            "window_start,window_end,rep_count,completed_reps,status,last_completed_at,notes",
+            "2026-03-01T00:00:00Z,2026-03-02T00:00:00Z,2,2,Completed,2026-03-01T19:00:00Z,\"Ran 5km, felt \"\"great\"\"\nthen stretched\"",
+            "2026-03-02T00:00:00Z,2026-03-03T00:00:00Z,1,0,NotStarted,,",
+        ]);
+        assert_eq!(occurrences_to_csv(&[]).lines().count(), 1);
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\r\nlines"), "\"two\r\nlines\"");
+    }
+
+    #[test]
+    fn test_upcoming_reminders() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        let window = |start: DateTime<Utc>| TaskOccurrence::new(start, start + Duration::hours(1), 1).unwrap();
+        let (lead, horizon) = (Duration::minutes(30), Duration::hours(1));
+
+        // Reminders at 9:40, 9:20, 9:10 (done), 10:00 (past the horizon), 8:50 (already due)
+        let standup = window(now + Duration::minutes(70));
+        let call = window(now + Duration::minutes(50));
+        let mut done = window(now + Duration::minutes(40));
+        done.mark_all_complete();
+        let later = window(now + Duration::minutes(90));
+        let missed = window(now + Duration::minutes(20));
+
+        assert_eq!(call.reminder_time(lead), now + Duration::minutes(20));
+
+        let occurrences = [standup.clone(), call.clone(), done, later, missed];
+        assert_eq!(upcoming_reminders(&occurrences, now, horizon, lead), vec![&call, &standup]);
+        assert!(upcoming_reminders(&occurrences, now, Duration::zero(), lead).is_empty());
+    }
+
+    #[test]
+    fn test_rep_windows() {
+        use crate::domain::entities::task::RepTimingSettings;
+        use chrono::NaiveTime;
+
+        let paris = FixedOffset::east_opt(3600).unwrap();
+        let at = |day, hour| paris.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap();
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0);
+        let occurrence = TaskOccurrence::new(
+            at(1, 0).with_timezone(&Utc),
+            at(2, 0).with_timezone(&Utc) - Duration::seconds(1),
+            3,
+        ).unwrap();
+        let day_end = at(2, 0) - Duration::seconds(1);
+
+        let timing = OccurrenceTimingSettings {
+            duration: Some(10),
+            not_before: time(6),
+            best_before: None,
+            rep_timing_settings: Some(vec![
+                RepTimingSettings { rep_index: 0, not_before: None, best_before: time(9) },
+                RepTimingSettings { rep_index: 2, not_before: time(22), best_before: time(2) },
+            ]),
+        };
+
+        assert_eq!(occurrence.rep_windows(Some(&timing), paris), vec![
+            (at(1, 6), at(1, 9)),
+            // Only the occurrence-wide bound
+            (at(1, 6), day_end),
+            // Crosses midnight, clamped to the occurrence
+            (at(1, 22), day_end),
+        ]);
+        assert_eq!(occurrence.rep_windows(None, paris), vec![(at(1, 0), day_end); 3]);
+    }
 }
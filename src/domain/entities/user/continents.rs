@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::fmt;
+use once_cell::sync::Lazy;
+
+/// Cities of each area, as listed in data/timezones.json
+static CITIES: Lazy<HashMap<&'static str, Vec<&'static str>>> =
+    Lazy::new(|| parse_cities(include_str!("../../../../data/timezones.json")));
+
+// ========================================================================
+// CONTINENTS
+// The IANA timezone areas, for picking a zone step by step
+// ========================================================================
+
+/// Top-level area of an IANA timezone identifier ("Europe" in "Europe/Paris")
+///
+/// Lets an interface offer the area first, then the cities within it. The
+/// oceans are included because the database files islands under them
+/// ("Atlantic/Azores", "Indian/Maldives").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Continents {
+    Africa,
+    America,
+    Antarctica,
+    Asia,
+    Atlantic,
+    Australia,
+    Europe,
+    Indian,
+    Pacific,
+}
+
+impl Continents {
+    /// Every area, in alphabetical order
+    pub const ALL: [Continents; 9] = [
+        Continents::Africa,
+        Continents::America,
+        Continents::Antarctica,
+        Continents::Asia,
+        Continents::Atlantic,
+        Continents::Australia,
+        Continents::Europe,
+        Continents::Indian,
+        Continents::Pacific,
+    ];
+
+    /// Iterates over every area, in the order of `from_choice`
+    pub fn iter() -> impl Iterator<Item = Continents> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the area at 1-indexed position `choice` in a numbered menu
+    ///
+    /// # Examples
+    /// ```
+    /// use tsadaash::domain::Continents;
+    ///
+    /// assert_eq!(Continents::from_choice(1), Some(Continents::Africa));
+    /// assert_eq!(Continents::from_choice(0), None);
+    /// ```
+    pub fn from_choice(choice: usize) -> Option<Continents> {
+        choice.checked_sub(1).and_then(|index| Self::ALL.get(index).copied())
+    }
+
+    /// Cities of this area, for a pick list
+    /// 
+    /// Each one joins the area into an identifier, see `Timezone::from_parts`.
+    /// Multi-word cities keep their underscores ("Buenos_Aires").
+    pub fn cities(&self) -> &'static [&'static str] {
+        CITIES.get(self.as_str()).map_or(&[], Vec::as_slice)
+    }
+
+    /// The area as written in timezone identifiers
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Continents::Africa => "Africa",
+            Continents::America => "America",
+            Continents::Antarctica => "Antarctica",
+            Continents::Asia => "Asia",
+            Continents::Atlantic => "Atlantic",
+            Continents::Australia => "Australia",
+            Continents::Europe => "Europe",
+            Continents::Indian => "Indian",
+            Continents::Pacific => "Pacific",
+        }
+    }
+}
+
+impl fmt::Display for Continents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Cities of `continent`, as an owned list a menu can sort or filter
+///
+/// # Examples
+/// ```
+/// use tsadaash::domain::{cities_for, Continents, Timezone};
+///
+/// let city = cities_for(Continents::Europe).into_iter().find(|c| *c == "Paris").unwrap();
+/// assert_eq!(Timezone::from_parts(Continents::Europe, city).unwrap(), "Europe/Paris");
+/// ```
+pub fn cities_for(continent: Continents) -> Vec<&'static str> {
+    continent.cities().to_vec()
+}
+
+/// Read `{ "Area": ["City", ...], ... }` without a JSON dependency
+/// 
+/// Every quoted string followed by a colon starts a new area; the strings
+/// after it are its cities.
+fn parse_cities(json: &'static str) -> HashMap<&'static str, Vec<&'static str>> {
+    let mut cities: HashMap<&'static str, Vec<&'static str>> = HashMap::new();
+    let mut area = None;
+    let mut rest = json;
+
+    while let Some(open) = rest.find('"') {
+        let Some(close) = rest[open + 1..].find('"') else { break };
+        let text = &rest[open + 1..open + 1 + close];
+        rest = &rest[open + close + 2..];
+
+        if rest.trim_start().starts_with(':') {
+            area = Some(text);
+            cities.entry(text).or_default();
+        } else if let Some(area) = area {
+            cities.entry(area).or_default().push(text);
+        }
+    }
+
+    cities
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_choice_is_one_indexed() {
+        assert_eq!(Continents::from_choice(0), None);
+        assert_eq!(Continents::from_choice(1), Some(Continents::Africa));
+        assert_eq!(Continents::from_choice(9), Some(Continents::Pacific));
+        assert_eq!(Continents::from_choice(10), None);
+        assert_eq!(Continents::from_choice(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_iter_matches_choices() {
+        for (index, continent) in Continents::iter().enumerate() {
+            assert_eq!(Continents::from_choice(index + 1), Some(continent));
+        }
+        assert_eq!(Continents::iter().count(), 9);
+    }
+
+    #[test]
+    fn test_cities() {
+        assert!(Continents::Europe.cities().contains(&"Paris"));
+        assert!(!Continents::Europe.cities().contains(&"Tokyo"));
+        for continent in Continents::iter() {
+            assert!(!continent.cities().is_empty(), "no cities under {}", continent);
+        }
+    }
+
+    #[test]
+    fn test_cities_for() {
+        assert_eq!(cities_for(Continents::Pacific), Continents::Pacific.cities());
+        assert!(!cities_for(Continents::Asia).contains(&"Paris"));
+    }
+
+    #[test]
+    fn test_parse_cities() {
+        let cities = parse_cities(r#"{ "Europe": ["Paris", "Rome"], "Indian": [] }"#);
+        assert_eq!(cities["Europe"], vec!["Paris", "Rome"]);
+        assert!(cities["Indian"].is_empty());
+        assert_eq!(cities.len(), 2);
+    }
+
+    #[test]
+    fn test_display_is_timezone_area() {
+        assert_eq!(Continents::Europe.to_string(), "Europe");
+        assert_eq!(Continents::Indian.to_string(), "Indian");
+
+        // Every area prefixes real zones
+        for continent in Continents::iter() {
+            let has_zone = chrono_tz::TZ_VARIANTS.iter()
+                .any(|tz| tz.name().starts_with(&format!("{}/", continent)));
+            assert!(has_zone, "no zone under {}", continent);
+        }
+    }
+}
@@ -26,7 +26,8 @@ use std::fmt;
 /// 
 /// assert_eq!(location.city(), "New York");
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
     name: Option<String>,
     city: String,
@@ -134,6 +135,11 @@ impl fmt::Display for Location {
 /// - Latitude must be between -90.0 and 90.0 (inclusive)
 /// - Longitude must be between -180.0 and 180.0 (inclusive)
 /// 
+/// # Storage
+/// Coordinates are stored as fixed-point microdegrees (1e-6°, roughly 11 cm
+/// at the equator). Inputs are rounded to the nearest microdegree, so
+/// equality and hashing are exact instead of depending on f64 noise.
+/// 
 /// # Examples
 /// ```
 /// use tsadaash::domain::entities::user::GeoCoordinates;
@@ -147,13 +153,17 @@ impl fmt::Display for Location {
 /// assert!(GeoCoordinates::new(91.0, 0.0).is_err());  // Latitude too high
 /// assert!(GeoCoordinates::new(0.0, 181.0).is_err()); // Longitude too high
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GeoCoordinates {
-    latitude: f64,
-    longitude: f64,
+    latitude_micro: i32,
+    longitude_micro: i32,
 }
 
 impl GeoCoordinates {
+    /// Number of stored units per decimal degree
+    pub const MICRODEGREES_PER_DEGREE: f64 = 1_000_000.0;
+
     /// Creates new geographic coordinates with validation
     /// 
     /// # Arguments
@@ -178,28 +188,63 @@ impl GeoCoordinates {
             return Err(GeoCoordinatesError::InvalidLongitude(longitude));
         }
         
-        Ok(Self { latitude, longitude })
+        Ok(Self {
+            latitude_micro: Self::to_micro(latitude),
+            longitude_micro: Self::to_micro(longitude),
+        })
     }
     
     /// Returns the latitude in decimal degrees
     pub fn latitude(&self) -> f64 {
-        self.latitude
+        Self::from_micro(self.latitude_micro)
     }
     
     /// Returns the longitude in decimal degrees
     pub fn longitude(&self) -> f64 {
-        self.longitude
+        Self::from_micro(self.longitude_micro)
+    }
+    
+    /// Returns the latitude in microdegrees (exact stored value)
+    pub fn latitude_microdegrees(&self) -> i32 {
+        self.latitude_micro
+    }
+    
+    /// Returns the longitude in microdegrees (exact stored value)
+    pub fn longitude_microdegrees(&self) -> i32 {
+        self.longitude_micro
     }
     
     /// Returns coordinates as a tuple (latitude, longitude)
     pub fn as_tuple(&self) -> (f64, f64) {
-        (self.latitude, self.longitude)
+        (self.latitude(), self.longitude())
+    }
+    
+    /// Mean Earth radius used for distance calculations, in metres
+    pub const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+    
+    /// Returns the great-circle distance to `other` in metres (haversine)
+    pub fn distance_to(&self, other: &GeoCoordinates) -> f64 {
+        let (lat1, lat2) = (self.latitude().to_radians(), other.latitude().to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude() - self.longitude()).to_radians();
+        
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * Self::EARTH_RADIUS_METERS * a.sqrt().min(1.0).asin()
+    }
+
+    // Range is validated before conversion, so ±180° * 1e6 always fits in i32
+    fn to_micro(degrees: f64) -> i32 {
+        (degrees * Self::MICRODEGREES_PER_DEGREE).round() as i32
+    }
+
+    fn from_micro(micro: i32) -> f64 {
+        micro as f64 / Self::MICRODEGREES_PER_DEGREE
     }
 }
 
 impl fmt::Display for GeoCoordinates {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:.4}°, {:.4}°", self.latitude, self.longitude)
+        write!(f, "{:.4}°, {:.4}°", self.latitude(), self.longitude())
     }
 }
 
@@ -360,6 +405,64 @@ mod tests {
         assert!(display.contains("-74.0060"));
     }
 
+    #[test]
+    fn test_coordinates_equal_below_precision() {
+        // Differences smaller than half a microdegree round to the same value
+        let a = GeoCoordinates::new(40.7128, -74.0060).unwrap();
+        let b = GeoCoordinates::new(40.712_800_3, -74.005_999_8).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(b.latitude(), 40.7128);
+        assert_eq!(b.longitude(), -74.0060);
+    }
+
+    #[test]
+    fn test_coordinates_differ_at_precision() {
+        let a = GeoCoordinates::new(40.7128, -74.0060).unwrap();
+        let b = GeoCoordinates::new(40.712_801, -74.0060).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(b.latitude_microdegrees() - a.latitude_microdegrees(), 1);
+    }
+
+    #[test]
+    fn test_coordinates_distance() {
+        let paris = GeoCoordinates::new(48.8566, 2.3522).unwrap();
+        let london = GeoCoordinates::new(51.5074, -0.1278).unwrap();
+        
+        assert_eq!(paris.distance_to(&paris), 0.0);
+        let distance = paris.distance_to(&london);
+        assert!((distance - 343_500.0).abs() < 1_000.0, "got {}", distance);
+        assert_eq!(distance, london.distance_to(&paris));
+
+        let new_york = GeoCoordinates::new(40.7128, -74.0060).unwrap();
+        let distance = new_york.distance_to(&london);
+        assert!((distance - 5_570_000.0).abs() < 10_000.0, "got {}", distance);
+    }
+
+    #[test]
+    fn test_coordinates_microdegree_storage() {
+        let coords = GeoCoordinates::new(-33.868_82, 151.209_29).unwrap();
+        assert_eq!(coords.latitude_microdegrees(), -33_868_820);
+        assert_eq!(coords.longitude_microdegrees(), 151_209_290);
+        assert_eq!(coords.as_tuple(), (-33.868_82, 151.209_29));
+    }
+
+    #[test]
+    fn test_location_dedup_with_float_noise() {
+        let home = |lat: f64, lng: f64| {
+            Location::new(
+                Some("Home".to_string()),
+                "Paris".to_string(),
+                "France".to_string(),
+                GeoCoordinates::new(lat, lng).unwrap(),
+            ).unwrap()
+        };
+
+        // 0.1 + 0.2 != 0.3 in f64, but both land on the same microdegree
+        let mut locations = vec![home(48.3, 2.3), home(48.0 + 0.1 + 0.2, 2.3)];
+        locations.dedup();
+        assert_eq!(locations.len(), 1);
+    }
+
     // ── Location Tests ────────────────────────────────────────
 
     #[test]
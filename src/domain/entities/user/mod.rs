@@ -1,8 +1,12 @@
 pub mod timezone;
 pub use timezone::{Timezone, TimezoneError};
 
+pub mod continents;
+pub use continents::{cities_for, Continents};
+
 pub mod location;
 pub use location::{Location, LocationError, GeoCoordinates, GeoCoordinatesError};
 
+#[allow(clippy::module_inception)]
 pub mod user;
-pub use user::User;
\ No newline at end of file
+pub use user::{User, UserValidationError};
\ No newline at end of file
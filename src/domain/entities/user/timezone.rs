@@ -1,5 +1,7 @@
 use std::fmt;
 use std::ops::Deref;
+use chrono::{DateTime, FixedOffset, Offset, Utc};
+use super::Continents;
 
 // ========================================================================
 // TIMEZONE VALUE OBJECT
@@ -38,9 +40,10 @@ use std::ops::Deref;
 /// ```
 /// 
 /// # Application Layer Responsibility
-/// The application layer should validate that the timezone actually exists
-/// using the tz_cities.json data or chrono-tz crate (infrastructure concern)
+/// The application layer should validate that the timezone actually exists,
+/// e.g. with `new_validated()`, `from_parts()` or `to_tz()`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "String"))]
 pub struct Timezone(String);
 
 impl Timezone {
@@ -98,6 +101,42 @@ impl Timezone {
         Ok(Self(trimmed.to_string()))
     }
     
+    /// Creates a timezone that must also exist in the IANA database
+    /// 
+    /// `new` stays format-only so stored or offline data never fails on a
+    /// zone database update; use this at input boundaries.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tsadaash::domain::entities::user::{Timezone, TimezoneError};
+    /// 
+    /// assert!(Timezone::new_validated("Europe/Paris".to_string()).is_ok());
+    /// assert_eq!(
+    ///     Timezone::new_validated("America/Atlantis".to_string()),
+    ///     Err(TimezoneError::UnknownZone("America/Atlantis".to_string()))
+    /// );
+    /// ```
+    pub fn new_validated(identifier: String) -> Result<Self, TimezoneError> {
+        let timezone = Self::new(identifier)?;
+        timezone.to_tz()?;
+        Ok(timezone)
+    }
+    
+    /// Joins an area and one of its cities, then checks the IANA database
+    /// 
+    /// Meant for pick lists built from `Continents::cities`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tsadaash::domain::entities::user::{Continents, Timezone};
+    /// 
+    /// let tz = Timezone::from_parts(Continents::Europe, "Paris").unwrap();
+    /// assert_eq!(tz, "Europe/Paris");
+    /// ```
+    pub fn from_parts(continent: Continents, city: &str) -> Result<Self, TimezoneError> {
+        Self::new_validated(format!("{}/{}", continent, city.trim()))
+    }
+    
     /// Returns the timezone identifier as a string slice
     /// 
     /// Note: You can also use `&*tz` or `tz.as_ref()` due to `Deref` implementation
@@ -109,12 +148,65 @@ impl Timezone {
     pub fn into_string(self) -> String {
         self.0
     }
+    
+    /// Resolves the identifier against the IANA database
+    /// 
+    /// # Examples
+    /// ```
+    /// use tsadaash::domain::entities::user::Timezone;
+    /// 
+    /// let tz = Timezone::new("Europe/Paris".to_string()).unwrap();
+    /// assert_eq!(tz.to_tz().unwrap(), chrono_tz::Europe::Paris);
+    /// ```
+    pub fn to_tz(&self) -> Result<chrono_tz::Tz, TimezoneError> {
+        self.0.parse().map_err(|_| TimezoneError::UnknownZone(self.0.clone()))
+    }
+    
+    /// Returns the UTC offset in effect at `when`, accounting for DST
+    /// 
+    /// This is the offset to build expansion bounds with, e.g. the local
+    /// midnight passed to `expand_template`.
+    pub fn offset_at(&self, when: DateTime<Utc>) -> Result<FixedOffset, TimezoneError> {
+        let tz = self.to_tz()?;
+        Ok(when.with_timezone(&tz).offset().fix())
+    }
+    
+    /// Returns the zone's fixed offset at the UTC instant `at`
+    /// 
+    /// Same as `offset_at`; named for the conversion it performs.
+    /// 
+    /// # Examples
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use tsadaash::domain::entities::user::Timezone;
+    /// 
+    /// let tz = Timezone::new("America/New_York".to_string()).unwrap();
+    /// let july = Utc.with_ymd_and_hms(2026, 7, 1, 12, 0, 0).unwrap();
+    /// assert_eq!(tz.to_fixed_offset(july).unwrap(), FixedOffset::west_opt(4 * 3600).unwrap());
+    /// ```
+    pub fn to_fixed_offset(&self, at: DateTime<Utc>) -> Result<FixedOffset, TimezoneError> {
+        self.offset_at(at)
+    }
+    
+    /// Returns the UTC offset in effect right now
+    pub fn current_offset(&self) -> Result<FixedOffset, TimezoneError> {
+        self.offset_at(Utc::now())
+    }
 }
 
 // ========================================================================
 // TRAIT IMPLEMENTATIONS FOR ERGONOMICS
 // ========================================================================
 
+/// Same format validation as `new`, used when deserializing
+impl TryFrom<String> for Timezone {
+    type Error = TimezoneError;
+
+    fn try_from(identifier: String) -> Result<Self, Self::Error> {
+        Self::new(identifier)
+    }
+}
+
 /// Allows using Timezone like a &str in many contexts
 impl Deref for Timezone {
     type Target = str;
@@ -174,6 +266,9 @@ pub enum TimezoneError {
     /// Timezone identifier contains invalid characters
     /// Valid characters: alphanumeric, underscore, slash, hyphen, plus
     InvalidCharacters(String),
+    
+    /// Timezone identifier is well-formed but not in the IANA database
+    UnknownZone(String),
 }
 
 impl fmt::Display for TimezoneError {
@@ -203,6 +298,9 @@ impl fmt::Display for TimezoneError {
                     tz
                 )
             }
+            TimezoneError::UnknownZone(tz) => {
+                write!(f, "Unknown timezone '{}': not in the IANA timezone database", tz)
+            }
         }
     }
 }
@@ -317,6 +415,80 @@ mod tests {
         assert!(Timezone::new("Mars/Olympus_Mons".to_string()).is_ok());
     }
     
+    #[test]
+    fn test_to_tz_resolves_known_zone() {
+        let tz = Timezone::new("Europe/Paris".to_string()).unwrap();
+        assert_eq!(tz.to_tz(), Ok(chrono_tz::Europe::Paris));
+    }
+
+    #[test]
+    fn test_to_tz_rejects_unknown_zone() {
+        let tz = Timezone::new("Europe/Atlantis".to_string()).unwrap();
+        assert_eq!(tz.to_tz(), Err(TimezoneError::UnknownZone("Europe/Atlantis".to_string())));
+    }
+    
+    #[test]
+    fn test_new_validated_checks_zone_database() {
+        let tz = Timezone::new_validated("Europe/Paris".to_string()).unwrap();
+        assert_eq!(tz.as_str(), "Europe/Paris");
+
+        assert_eq!(
+            Timezone::new_validated("America/Atlantis".to_string()),
+            Err(TimezoneError::UnknownZone("America/Atlantis".to_string()))
+        );
+        // Format errors still come first
+        assert_eq!(Timezone::new_validated("".to_string()), Err(TimezoneError::EmptyIdentifier));
+    }
+    
+    #[test]
+    fn test_from_parts() {
+        assert_eq!(Timezone::from_parts(Continents::Europe, "Paris").unwrap(), "Europe/Paris");
+        assert_eq!(
+            Timezone::from_parts(Continents::Europe, "Atlantis"),
+            Err(TimezoneError::UnknownZone("Europe/Atlantis".to_string()))
+        );
+        // A real city under the wrong area
+        assert!(Timezone::from_parts(Continents::Asia, "Paris").is_err());
+        assert!(Timezone::from_parts(Continents::Europe, "").is_err());
+
+        // Every listed city makes a known zone
+        for continent in Continents::iter() {
+            for city in continent.cities() {
+                assert!(Timezone::from_parts(continent, city).is_ok(), "{}/{}", continent, city);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_offset_at_follows_dst() {
+        use chrono::TimeZone;
+        
+        let tz = Timezone::new("America/New_York".to_string()).unwrap();
+        let january = Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap();
+        let july = Utc.with_ymd_and_hms(2026, 7, 15, 12, 0, 0).unwrap();
+        
+        assert_eq!(tz.offset_at(january), Ok(FixedOffset::west_opt(5 * 3600).unwrap()));
+        assert_eq!(tz.offset_at(july), Ok(FixedOffset::west_opt(4 * 3600).unwrap()));
+        
+        let unknown = Timezone::new("Europe/Atlantis".to_string()).unwrap();
+        assert!(matches!(unknown.offset_at(july), Err(TimezoneError::UnknownZone(_))));
+    }
+
+    #[test]
+    fn test_to_fixed_offset() {
+        use chrono::TimeZone;
+
+        let tz = Timezone::new("America/New_York".to_string()).unwrap();
+        let january = Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap();
+        let july = Utc.with_ymd_and_hms(2026, 7, 15, 12, 0, 0).unwrap();
+
+        assert_eq!(tz.to_fixed_offset(january).unwrap().local_minus_utc(), -5 * 3600);
+        assert_eq!(tz.to_fixed_offset(july).unwrap().local_minus_utc(), -4 * 3600);
+
+        let unknown = Timezone::new("Europe/Atlantis".to_string()).unwrap();
+        assert!(matches!(unknown.to_fixed_offset(july), Err(TimezoneError::UnknownZone(_))));
+    }
+    
     // ========================================================================
     // ERGONOMICS TESTS (Deref, AsRef, PartialEq)
     // ========================================================================
@@ -326,6 +498,7 @@ mod tests {
         let tz = Timezone::new("America/New_York".to_string()).unwrap();
         
         // Can use &* to get &str
+        #[allow(clippy::explicit_auto_deref)]
         let s: &str = &*tz;
         assert_eq!(s, "America/New_York");
         
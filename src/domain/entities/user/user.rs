@@ -1,8 +1,33 @@
+use std::fmt;
 use chrono::{Month, NaiveTime, Weekday};
 use super::timezone::Timezone;
 use super::location::Location;
 
-#[derive(Debug, Clone)]
+// ========================================================================
+// VALIDATION ERRORS
+// ========================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserValidationError {
+    /// Email is not of the form local@domain.tld
+    InvalidEmail(String),
+}
+
+impl fmt::Display for UserValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserValidationError::InvalidEmail(email) => write!(f, "Invalid email address: '{}'", email),
+        }
+    }
+}
+
+impl std::error::Error for UserValidationError {}
+
+// ========================================================================
+// USER AGGREGATE
+// ========================================================================
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct User {
     pub username: String,
     pub email: String,
@@ -12,7 +37,7 @@ pub struct User {
     /// User's timezone (e.g., "America/New_York", "Europe/London")
     pub timezone: Timezone,
     
-    /// User's physical locations
+    /// User's physical locations, home first
     pub locations: Vec<Option<Location>>,  // Allow for multiple locations, some of which may be None (e.g., "home", "work", "gym", etc.)
 
     // ── CALENDAR SETTINGS ────────────────────────────────────
@@ -36,7 +61,38 @@ pub struct User {
 }
 
 impl User {
-    /// Creates a new user with the given timezone
+    /// Creates a new user, checking the email format
+    /// 
+    /// The email is trimmed. The check is deliberately loose: one `@`, a
+    /// non-empty local part, and a domain with a dot that neither starts nor
+    /// ends it. Whether the address exists is not for the domain to decide.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tsadaash::domain::entities::user::{Timezone, User, UserValidationError};
+    /// 
+    /// let timezone = Timezone::new("Europe/Paris".to_string()).unwrap();
+    /// let user = User::try_new("alice".to_string(), " alice@example.com ".to_string(), "hash".to_string(), timezone.clone());
+    /// assert_eq!(user.unwrap().email, "alice@example.com");
+    /// 
+    /// let user = User::try_new("bob".to_string(), "notanemail".to_string(), "hash".to_string(), timezone);
+    /// assert_eq!(user, Err(UserValidationError::InvalidEmail("notanemail".to_string())));
+    /// ```
+    pub fn try_new(
+        username: String,
+        email: String,
+        password_hash: String,
+        timezone: Timezone,
+    ) -> Result<Self, UserValidationError> {
+        let email = email.trim().to_string();
+        validate_email(&email)?;
+        Ok(Self::new(username, email, password_hash, timezone))
+    }
+    
+    /// Creates a new user with the given timezone, without validation
+    /// 
+    /// For data that was already validated, such as repository round-trips;
+    /// user input goes through `try_new`.
     pub fn new(
         username: String,
         email: String,
@@ -56,6 +112,7 @@ impl User {
     }
     
     /// Create a user with custom calendar settings and optional locations
+    #[allow(clippy::too_many_arguments)]
     pub fn with_all_settings(
         username: String,
         email: String,
@@ -78,9 +135,21 @@ impl User {
         }
     }
     
-    // ── TIMEZONE & LOCATION SETTERS ─────────────────────────
+    // ── TIMEZONE & LOCATION ─────────────────────────────────
+    
+    /// Returns the user's timezone
+    pub fn timezone(&self) -> &Timezone {
+        &self.timezone
+    }
+    
+    /// Returns the user's home, the first of their locations, if known
+    pub fn home_location(&self) -> Option<&Location> {
+        self.locations.first().and_then(Option::as_ref)
+    }
     
     /// Updates the user's timezone
+    /// 
+    /// `Timezone` is validated on construction, so any value is accepted.
     pub fn set_timezone(&mut self, timezone: Timezone) {
         self.timezone = timezone;
     }
@@ -90,6 +159,14 @@ impl User {
         self.locations = locations;
     }
     
+    /// Sets the user's home, keeping their other locations
+    pub fn set_home_location(&mut self, location: Location) {
+        match self.locations.first_mut() {
+            Some(home) => *home = Some(location),
+            None => self.locations.push(Some(location)),
+        }
+    }
+    
     // ── CALENDAR SETTINGS SETTERS ──────────────────────────
     
     /// Sets the first day of the week
@@ -122,4 +199,100 @@ impl User {
     pub fn set_day_start(&mut self, time: NaiveTime) {
         self.day_start = time;
     }
-}
\ No newline at end of file
+}
+
+/// Loose email format check, see `User::try_new`
+fn validate_email(email: &str) -> Result<(), UserValidationError> {
+    let invalid = || UserValidationError::InvalidEmail(email.to_string());
+
+    let (local, domain) = email.split_once('@').ok_or_else(invalid)?;
+    let well_formed = !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.chars().any(char::is_whitespace);
+
+    if well_formed { Ok(()) } else { Err(invalid()) }
+}
+
+// ========================================================================
+// TESTS
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::user::GeoCoordinates;
+
+    fn user() -> User {
+        User::new(
+            "user".to_string(),
+            "user@example.com".to_string(),
+            "password_hash".to_string(),
+            Timezone::new("America/New_York".to_string()).unwrap(),
+        )
+    }
+
+    fn location(city: &str) -> Location {
+        Location::new(None, city.to_string(), "France".to_string(), GeoCoordinates::new(45.0, 3.0).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_set_timezone() {
+        let mut user = user();
+        user.set_timezone(Timezone::new("Europe/Paris".to_string()).unwrap());
+        assert_eq!(user.timezone(), "Europe/Paris");
+    }
+
+    #[test]
+    fn test_set_home_location_keeps_other_locations() {
+        let mut user = user();
+        assert_eq!(user.home_location(), None);
+
+        user.set_home_location(location("Lyon"));
+        assert_eq!(user.home_location(), Some(&location("Lyon")));
+
+        user.locations.push(Some(location("Paris")));
+        user.set_home_location(location("Nantes"));
+        assert_eq!(user.locations, vec![Some(location("Nantes")), Some(location("Paris"))]);
+
+        // An unknown home is not replaced by the next known location
+        user.set_locations(vec![None, Some(location("Paris"))]);
+        assert_eq!(user.home_location(), None);
+    }
+
+    #[test]
+    fn test_try_new_validates_email() {
+        let try_new = |email: &str| User::try_new(
+            "user".to_string(),
+            email.to_string(),
+            "password_hash".to_string(),
+            Timezone::new("America/New_York".to_string()).unwrap(),
+        );
+
+        for valid in ["user@example.com", "first.last+tag@mail.example.co.uk", "  padded@example.org  "] {
+            assert!(try_new(valid).is_ok(), "{} should be valid", valid);
+        }
+        assert_eq!(try_new("  padded@example.org ").unwrap().email, "padded@example.org");
+
+        for invalid in [
+            "notanemail",
+            "",
+            "@example.com",
+            "user@",
+            "user@localhost",
+            "user@@example.com",
+            "user@exa@mple.com",
+            "user@.com",
+            "user@example.",
+            "us er@example.com",
+        ] {
+            assert_eq!(
+                try_new(invalid),
+                Err(UserValidationError::InvalidEmail(invalid.trim().to_string())),
+                "{} should be invalid", invalid,
+            );
+        }
+    }
+}
@@ -3,6 +3,7 @@
 // Pure business logic with no external dependencies
 // ========================================================================
 
+pub mod clock;
 pub mod entities;
 pub mod tests;
 
@@ -11,11 +12,17 @@ pub mod tests;
 // Flatten common types to avoid deep nesting in imports
 // ========================================================================
 
+// Time
+pub use clock::{Clock, SystemClock};
+
 // User aggregate
 pub use entities::user::{
     User,
+    UserValidationError,
     Timezone,
     TimezoneError,
+    Continents,
+    cities_for,
     Location,
     LocationError,
     GeoCoordinates,
@@ -25,12 +32,21 @@ pub use entities::user::{
 // Task aggregate
 pub use entities::task::{
     Task,
+    TaskBuilder,
     TaskStatus,
     TaskPriority,
     TaskValidationError,
     TaskOccurrence,
     TaskOccurrenceValidationError,
     OccurenceRep,
+    RepState,
+    SkipPolicy,
+    completion_heatmap,
+    completion_rate,
+    current_streak,
+    current_streak_at,
+    occurrences_to_csv,
+    upcoming_reminders,
     
     // Periodicity types
     Periodicity,
@@ -40,7 +56,9 @@ pub use entities::task::{
     RepetitionUnit,
     DayConstraint,
     WeekConstraint,
+    WeekCountingMode,
     MonthConstraint,
+    MonthAnchor,
     YearConstraint,
     MonthWeekPosition,
     NthWeekdayOfMonth,
@@ -49,6 +67,7 @@ pub use entities::task::{
     UniqueDate,
     OccurrenceTimingSettings,
     RepTimingSettings,
+    OccurrenceIter,
 };
 
 // Schedule module
@@ -56,6 +75,8 @@ pub use entities::schedule::{
     // Core types
     AvailabilityKind,
     AvailabilityLevel,
+    BusyFlexLimits,
+    CapabilityError,
     CapabilitySet,
     DeviceAccess,
     LocationConstraint,
@@ -63,21 +84,49 @@ pub use entities::schedule::{
     UnavailableReason,
     
     // Template types
+    DateOverride,
+    OverrideBlock,
     RecurringRule,
+    RuleConflict,
     ScheduleTemplate,
     
     // Expansion
+    BlockContender,
+    ConflictResolution,
+    ExpansionMode,
+    ResolutionReason,
     TimeBlock,
+    coalesce_blocks,
     expand_template,
+    expand_template_tz,
+    expand_template_with_mode,
+    expand_template_with_report,
+    find_gaps,
+    free_gaps,
+    resolve_block_winner,
+    total_available_minutes,
     
     // Matching
     SchedulableTask,
+    best_block_for_task,
+    block_fill_ratio,
     can_schedule_task_in_block,
+    can_schedule_task_in_block_with,
     find_candidate_slots,
+    find_candidate_slots_stepped,
+    find_rep_slots_stepped,
+    score_block_for_task,
+    
+    // Placement
+    Placement,
+    detect_location_conflicts,
     
     // Config functions
     busy_flex_max_device,
     busy_flex_max_eyes,
     busy_flex_max_hands,
     busy_flex_max_minutes,
-};
\ No newline at end of file
+};
+
+#[cfg(feature = "json")]
+pub use entities::schedule::ScheduleTemplateError;
\ No newline at end of file
@@ -1 +1,2 @@
+#[allow(clippy::module_inception)]
 pub mod tests;
\ No newline at end of file
@@ -1,18 +1,18 @@
-/// Comprehensive tests for the Periodicity system
-/// 
-/// These tests demonstrate the robustness of the domain model and cover:
-/// - Valid configurations
-/// - Invalid configurations (boundary testing)
-/// - Constraint combinations
-/// - Edge cases
-/// - Real-world examples
+//! Comprehensive tests for the Periodicity system
+//! 
+//! These tests demonstrate the robustness of the domain model and cover:
+//! - Valid configurations
+//! - Invalid configurations (boundary testing)
+//! - Constraint combinations
+//! - Edge cases
+//! - Real-world examples
 
 #[cfg(test)]
 mod periodicity_tests {
     use crate::domain::{PeriodicityBuilder, PeriodicityValidationError};
-    use crate::domain::entities::task::{Periodicity, DayConstraint, MonthConstraint,
-        NthWeekdayOfMonth, RepetitionUnit};
-    use chrono::{Utc, Weekday, Month, TimeZone};
+    use crate::domain::entities::task::{Periodicity, DayConstraint, MonthConstraint, MonthAnchor,
+        NthWeekdayOfMonth, RepetitionUnit, WeekCountingMode};
+    use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday, Month, TimeZone};
 
     // ========================================================================
     // BASIC VALID CONFIGURATIONS
@@ -55,6 +55,38 @@ mod periodicity_tests {
         assert!(p.special_pattern.is_some());
     }
 
+    #[test]
+    fn test_unique_date_matches_same_calendar_day() {
+        // Stored at midnight, checked mid-morning on the same day
+        let christmas = Utc.with_ymd_and_hms(2026, 12, 25, 0, 0, 0).unwrap();
+        let p = Periodicity::unique(christmas).unwrap();
+        
+        let same_day = Utc.with_ymd_and_hms(2026, 12, 25, 10, 0, 0).unwrap();
+        assert!(p.matches_constraints(&same_day, Weekday::Mon));
+        
+        let next_day = Utc.with_ymd_and_hms(2026, 12, 26, 0, 0, 0).unwrap();
+        assert!(!p.matches_constraints(&next_day, Weekday::Mon));
+    }
+
+    #[test]
+    fn test_custom_dates_match_same_calendar_day() {
+        let dates = vec![
+            Utc.with_ymd_and_hms(2026, 3, 1, 8, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 6, 1, 8, 30, 0).unwrap(),
+        ];
+        let p = PeriodicityBuilder::new()
+            .custom_dates(dates)
+            .unwrap()
+            .build()
+            .unwrap();
+        
+        let evening = Utc.with_ymd_and_hms(2026, 6, 1, 21, 0, 0).unwrap();
+        assert!(p.matches_constraints(&evening, Weekday::Mon));
+        
+        let other_day = Utc.with_ymd_and_hms(2026, 6, 2, 8, 30, 0).unwrap();
+        assert!(!p.matches_constraints(&other_day, Weekday::Mon));
+    }
+
     // ========================================================================
     // REAL-WORLD USE CASES
     // ========================================================================
@@ -240,6 +272,12 @@ mod periodicity_tests {
             timeframe: None,
             special_pattern: None,
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         let result = p.validate();
@@ -265,6 +303,12 @@ mod periodicity_tests {
             timeframe: None,
             special_pattern: None,
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         let result = p.validate();
@@ -301,6 +345,12 @@ mod periodicity_tests {
             timeframe: None,
             special_pattern: None,
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         let result = p.validate();
@@ -323,6 +373,12 @@ mod periodicity_tests {
             timeframe: None,
             special_pattern: None,
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         let result = p.validate();
@@ -345,6 +401,12 @@ mod periodicity_tests {
             timeframe: None,
             special_pattern: None,
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         let result = p.validate();
@@ -367,6 +429,12 @@ mod periodicity_tests {
             timeframe: None,
             special_pattern: None,
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         let result = p.validate();
@@ -403,6 +471,12 @@ mod periodicity_tests {
             },
             timeframe: None,
             reference_date: None,
+            reference_is_first_occurrence: false,
+            month_anchor: None,
+            exclusions: Default::default(),
+            max_occurrences: None,
+            year_start: None,
+            week_counting: Default::default(),
         };
         
         let result = p.validate();
@@ -573,6 +647,49 @@ mod periodicity_tests {
         assert_eq!(weeks_count, 4, "February 2026 should have 4 weeks with Monday start");
     }
 
+    #[test]
+    fn test_week_counting_modes_february_2026() {
+        let aligned = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weeks_of_month(vec![1])
+            .build()
+            .unwrap();
+        let blocks = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weeks_of_month(vec![1])
+            .count_weeks_by(WeekCountingMode::CalendarDayBlocks)
+            .build()
+            .unwrap();
+        
+        // Feb 1 (Sun) precedes the first Monday: not week 0 when aligned,
+        // but day 1 is always in the first block
+        let feb_1 = Utc.with_ymd_and_hms(2026, 2, 1, 12, 0, 0).unwrap();
+        assert!(!aligned.matches_constraints(&feb_1, Weekday::Mon));
+        assert!(blocks.matches_constraints(&feb_1, Weekday::Mon));
+        
+        // Feb 3 is in week 0 either way
+        let feb_3 = Utc.with_ymd_and_hms(2026, 2, 3, 12, 0, 0).unwrap();
+        assert!(aligned.matches_constraints(&feb_3, Weekday::Mon));
+        assert!(blocks.matches_constraints(&feb_3, Weekday::Mon));
+        
+        // Feb 8 (Sun) ends the first aligned week but starts the second block
+        let feb_8 = Utc.with_ymd_and_hms(2026, 2, 8, 12, 0, 0).unwrap();
+        assert!(aligned.matches_constraints(&feb_8, Weekday::Mon));
+        assert!(!blocks.matches_constraints(&feb_8, Weekday::Mon));
+        
+        // From the end: Feb 22-28 is the last block, Feb 23-28 the last aligned week
+        let last_blocks = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weeks_of_month_from_end(vec![1])
+            .count_weeks_by(WeekCountingMode::CalendarDayBlocks)
+            .build()
+            .unwrap();
+        let feb_22 = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let feb_21 = Utc.with_ymd_and_hms(2026, 2, 21, 12, 0, 0).unwrap();
+        assert!(last_blocks.matches_constraints(&feb_22, Weekday::Mon));
+        assert!(!last_blocks.matches_constraints(&feb_21, Weekday::Mon));
+    }
+
     #[test]
     fn test_week_constraint_first_two_weeks() {
         // Create periodicity: first 2 weeks of month (weeks 0 and 1 internally, but 1 and 2 for humans)
@@ -610,18 +727,293 @@ mod periodicity_tests {
         // January 2026: starts Thursday, ends Saturday (31 days)
         // With Monday start: Mon Jan 5 starts week 0
         let jan_weeks = Periodicity::weeks_in_month(2026, 1, Weekday::Mon);
-        assert!(jan_weeks >= 4 && jan_weeks <= 5, "January 2026 should have 4-5 weeks, got {}", jan_weeks);
+        assert!((4..=5).contains(&jan_weeks), "January 2026 should have 4-5 weeks, got {}", jan_weeks);
         
         // March 2026: starts Sunday, ends Tuesday (31 days)  
         // With Monday start: Mon Mar 2 starts week 0
         let mar_weeks = Periodicity::weeks_in_month(2026, 3, Weekday::Mon);
-        assert!(mar_weeks >= 4 && mar_weeks <= 5, "March 2026 should have 4-5 weeks, got {}", mar_weeks);
+        assert!((4..=5).contains(&mar_weeks), "March 2026 should have 4-5 weeks, got {}", mar_weeks);
         
         // February 2026: 28 days, starts Sunday
         let feb_weeks = Periodicity::weeks_in_month(2026, 2, Weekday::Mon);
         assert_eq!(feb_weeks, 4, "February 2026 should have exactly 4 weeks");
     }
 
+    #[test]
+    fn test_last_day_of_month_boundaries() {
+        // 1 = last day of the month (stored 0-indexed in SpecificDaysMonthFromLast)
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days_from_end(vec![1])
+            .build()
+            .unwrap();
+        
+        let cases = [
+            ((2026, 1, 31), (2026, 1, 30)),  // January
+            ((2026, 2, 28), (2026, 2, 27)),  // February, non-leap
+            ((2028, 2, 29), (2028, 2, 28)),  // February, leap
+            ((2026, 12, 31), (2026, 12, 30)), // December rolls into next year
+        ];
+        
+        for ((y, m, last), (py, pm, prev)) in cases {
+            let last_day = Utc.with_ymd_and_hms(y, m, last, 12, 0, 0).unwrap();
+            let day_before = Utc.with_ymd_and_hms(py, pm, prev, 12, 0, 0).unwrap();
+            assert!(periodicity.matches_constraints(&last_day, Weekday::Mon), "{}-{:02}-{} should be last day", y, m, last);
+            assert!(!periodicity.matches_constraints(&day_before, Weekday::Mon), "{}-{:02}-{} should not be last day", py, pm, prev);
+        }
+    }
+
+    #[test]
+    fn test_exclusions_skip_days_inside_a_week() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_day()
+            .except_dates(vec![
+                NaiveDate::from_ymd_opt(2026, 2, 10).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 2, 12).unwrap(),
+            ])
+            .build()
+            .unwrap();
+        
+        // Mon Feb 9 to Sun Feb 15, checked mid-day
+        let matching: Vec<u32> = (9..=15)
+            .map(|day| Utc.with_ymd_and_hms(2026, 2, day, 14, 30, 0).unwrap())
+            .filter(|date| periodicity.matches_constraints(date, Weekday::Mon))
+            .map(|date| date.day())
+            .collect();
+        
+        assert_eq!(matching, vec![9, 11, 13, 14, 15]);
+    }
+
+    #[test]
+    fn test_exclusions_outside_timeframe_rejected() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap();
+        
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .every_day()
+            .between(start, end)
+            .except_dates(vec![NaiveDate::from_ymd_opt(2026, 7, 1).unwrap()])
+            .build();
+        assert!(matches!(result, Err(PeriodicityValidationError::InvalidValue { .. })));
+        
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .every_day()
+            .between(start, end)
+            .except_dates(vec![NaiveDate::from_ymd_opt(2026, 6, 30).unwrap()])
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_is_satisfiable_feb_31_never_fires() {
+        // Passes validation, but February never has a 31st
+        let p = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![31])
+            .in_months(vec![Month::February])
+            .build()
+            .unwrap();
+        
+        assert!(!p.is_satisfiable(Weekday::Mon));
+    }
+
+    #[test]
+    fn test_is_satisfiable_feb_29_in_leap_years() {
+        let p = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![29])
+            .in_months(vec![Month::February])
+            .build()
+            .unwrap();
+        
+        assert!(p.is_satisfiable(Weekday::Mon));
+    }
+
+    #[test]
+    fn test_is_satisfiable_respects_timeframe() {
+        // Feb 29 only exists in 2028 within this window, which is excluded
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2028, 1, 1, 0, 0, 0).unwrap();
+        let p = PeriodicityBuilder::new()
+            .daily(1)
+            .on_month_days(vec![29])
+            .in_months(vec![Month::February])
+            .between(start, end)
+            .build()
+            .unwrap();
+        
+        assert!(!p.is_satisfiable(Weekday::Mon));
+        assert!(Periodicity::daily().unwrap().is_satisfiable(Weekday::Mon));
+    }
+
+    #[test]
+    fn test_occurrences_between_skips_exclusions() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_day()
+            .except_dates(vec![NaiveDate::from_ymd_opt(2026, 2, 10).unwrap()])
+            .build()
+            .unwrap();
+        
+        let start = Utc.with_ymd_and_hms(2026, 2, 9, 8, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 2, 12, 0, 0, 0).unwrap();
+        let days: Vec<u32> = periodicity.occurrences_between(&start, &end, Weekday::Mon)
+            .iter()
+            .map(|date| date.day())
+            .collect();
+        
+        assert_eq!(days, vec![9, 11]);
+    }
+
+    #[test]
+    fn test_max_occurrences_limits_daily_task() {
+        let reference = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_day()
+            .with_reference_date(reference)
+            .limit_occurrences(5)
+            .build()
+            .unwrap();
+        
+        let month = periodicity.occurrences_between(
+            &reference,
+            &Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap(),
+            Weekday::Mon,
+        );
+        let year = periodicity.occurrences_between(
+            &reference,
+            &Utc.with_ymd_and_hms(2027, 3, 1, 0, 0, 0).unwrap(),
+            Weekday::Mon,
+        );
+        
+        assert_eq!(month.len(), 5);
+        assert_eq!(month, year);
+        assert_eq!(month.last().unwrap().day(), 5);
+        
+        // The count starts at the reference date, not the window start
+        let later = Utc.with_ymd_and_hms(2026, 3, 4, 0, 0, 0).unwrap();
+        assert_eq!(periodicity.occurrences_between(&later, &year[4], Weekday::Mon).len(), 1);
+        
+        assert_eq!(
+            periodicity.next_occurrence_after(&reference, Weekday::Mon),
+            Some(Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap())
+        );
+        assert_eq!(periodicity.next_occurrence_after(&month[4], Weekday::Mon), None);
+    }
+
+    #[test]
+    fn test_max_occurrences_counts_rep_unit_periods() {
+        // Mon 2 Mar 2026; a weekly task may fall on any day of its week
+        let reference = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+        let horizon = Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap();
+        let weekly = PeriodicityBuilder::new()
+            .weekly(1)
+            .with_reference_date(reference)
+            .limit_occurrences(3)
+            .build()
+            .unwrap();
+        
+        let days = weekly.occurrences_between(&reference, &horizon, Weekday::Mon);
+        assert_eq!(days.len(), 21);
+        assert_eq!(days.last().unwrap().date_naive(), NaiveDate::from_ymd_opt(2026, 3, 22).unwrap());
+        assert_eq!(weekly.occurrences_from(&reference, Weekday::Mon).count(), 21);
+        assert_eq!(weekly.next_occurrence_after(days.last().unwrap(), Weekday::Mon), None);
+        
+        // Monthly periods run from the 15th; only Mondays match
+        let reference = Utc.with_ymd_and_hms(2026, 1, 15, 0, 0, 0).unwrap();
+        let monthly = PeriodicityBuilder::new()
+            .monthly(1)
+            .on_weekdays(vec![Weekday::Mon])
+            .with_reference_date(reference)
+            .limit_occurrences(2)
+            .build()
+            .unwrap();
+        
+        let mondays = monthly.occurrences_between(&reference, &horizon, Weekday::Mon);
+        assert_eq!(mondays.first().unwrap().date_naive(), NaiveDate::from_ymd_opt(2026, 1, 19).unwrap());
+        // The second period ends on 14 Mar, so 9 Mar is the last Monday
+        assert_eq!(mondays.last().unwrap().date_naive(), NaiveDate::from_ymd_opt(2026, 3, 9).unwrap());
+        assert_eq!(mondays.len(), 8);
+    }
+
+    #[test]
+    fn test_max_occurrences_rejects_zero() {
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .every_day()
+            .limit_occurrences(0)
+            .build();
+        
+        assert!(matches!(result, Err(PeriodicityValidationError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_next_occurrence_after_respects_timeframe() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Fri])
+            .between(start, end)
+            .build()
+            .unwrap();
+        
+        // Jan 2, 2026 is the first Friday
+        assert_eq!(
+            periodicity.next_occurrence_after(&start, Weekday::Mon),
+            Some(Utc.with_ymd_and_hms(2026, 1, 2, 0, 0, 0).unwrap())
+        );
+        let last_friday = Utc.with_ymd_and_hms(2026, 1, 30, 0, 0, 0).unwrap();
+        assert_eq!(periodicity.next_occurrence_after(&last_friday, Weekday::Mon), None);
+    }
+
+    #[test]
+    fn test_occurrences_from_open_ended_take() {
+        let periodicity = Periodicity::daily().unwrap();
+        let start = Utc.with_ymd_and_hms(2026, 1, 30, 15, 0, 0).unwrap();
+        
+        let next: Vec<DateTime<Utc>> = periodicity.occurrences_from(&start, Weekday::Mon)
+            .take(3)
+            .collect();
+        
+        assert_eq!(next, vec![
+            Utc.with_ymd_and_hms(2026, 1, 30, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap(),
+        ]);
+    }
+
+    #[test]
+    fn test_occurrences_from_stops_when_exhausted() {
+        let reference = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        let limited = PeriodicityBuilder::new()
+            .daily(1)
+            .on_weekdays(vec![Weekday::Mon, Weekday::Wed])
+            .with_reference_date(reference)
+            .limit_occurrences(4)
+            .build()
+            .unwrap();
+        
+        let dates: Vec<DateTime<Utc>> = limited.occurrences_from(&reference, Weekday::Mon)
+            .take(100)
+            .collect();
+        assert_eq!(dates.len(), 4);
+        assert_eq!(dates.iter().map(|d| d.day()).collect::<Vec<_>>(), vec![2, 4, 9, 11]);
+        
+        let end = Utc.with_ymd_and_hms(2026, 3, 15, 0, 0, 0).unwrap();
+        let bounded = PeriodicityBuilder::new()
+            .daily(1)
+            .every_day()
+            .between(reference, end)
+            .build()
+            .unwrap();
+        assert_eq!(bounded.occurrences_from(&reference, Weekday::Mon).take(100).count(), 14);
+    }
+
     // ========================================================================
     // EVERY N* ROLLING PATTERN TESTS
     // ========================================================================
@@ -658,6 +1050,81 @@ mod periodicity_tests {
         assert!(!periodicity.matches_constraints(&jan_6, Weekday::Mon), "Jan 6 (day 5) should NOT match");
     }
 
+    #[test]
+    fn test_every_nth_weekday_every_other_tuesday() {
+        let reference = Utc.with_ymd_and_hms(2026, 1, 6, 0, 0, 0).unwrap(); // Tuesday
+        
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 2)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        
+        let days = |n: i64| reference + chrono::Duration::days(n);
+        assert!(periodicity.matches_constraints(&days(0), Weekday::Mon), "ref should match");
+        assert!(!periodicity.matches_constraints(&days(7), Weekday::Mon), "ref+7 should NOT match");
+        assert!(periodicity.matches_constraints(&days(14), Weekday::Mon), "ref+14 should match");
+        assert!(periodicity.matches_constraints(&days(28), Weekday::Mon), "ref+28 should match");
+        assert!(!periodicity.matches_constraints(&days(1), Weekday::Mon), "Wednesday should NOT match");
+        assert!(!periodicity.matches_constraints(&days(-14), Weekday::Mon), "before ref should NOT match");
+    }
+
+    #[test]
+    fn test_every_nth_weekday_counts_from_first_weekday_after_reference() {
+        // Reference on a Thursday: first Tuesday is Jan 13
+        let reference = Utc.with_ymd_and_hms(2026, 1, 8, 0, 0, 0).unwrap();
+        
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 2)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        
+        let jan_13 = Utc.with_ymd_and_hms(2026, 1, 13, 9, 0, 0).unwrap();
+        let jan_20 = Utc.with_ymd_and_hms(2026, 1, 20, 9, 0, 0).unwrap();
+        let jan_27 = Utc.with_ymd_and_hms(2026, 1, 27, 9, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&jan_13, Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&jan_20, Weekday::Mon));
+        assert!(periodicity.matches_constraints(&jan_27, Weekday::Mon));
+    }
+
+    #[test]
+    fn test_every_nth_weekday_conflicts_with_week_of_month() {
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 2)
+            .on_weeks_of_month(vec![1])
+            .build();
+        assert!(matches!(result, Err(PeriodicityValidationError::ConflictingConstraints { .. })));
+        
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 2)
+            .on_weeks_of_month_from_end(vec![1])
+            .build();
+        assert!(matches!(result, Err(PeriodicityValidationError::ConflictingConstraints { .. })));
+        
+        // n = 1 is plain weekly and has no cadence to drift
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 1)
+            .on_weeks_of_month(vec![1])
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_every_nth_weekday_rejects_zero() {
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .every_nth_weekday(Weekday::Tue, 0)
+            .build();
+        
+        assert!(matches!(result, Err(PeriodicityValidationError::InvalidValue { .. })));
+    }
+
     #[test]
     fn test_every_n_weeks_with_reference_date() {
         // EveryNWeeks(2) with Monday start, reference Jan 5 (Monday)
@@ -726,6 +1193,84 @@ mod periodicity_tests {
         assert!(periodicity.matches_constraints(&may_15, Weekday::Mon), "May (month 4) should match");
     }
 
+    #[test]
+    fn test_every_n_months_last_day_anchor() {
+        let reference = Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap();
+        
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_months(1)
+            .anchored_to(MonthAnchor::LastDay)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        
+        // February (28 days in 2026)
+        let feb_28 = Utc.with_ymd_and_hms(2026, 2, 28, 9, 0, 0).unwrap();
+        let feb_27 = Utc.with_ymd_and_hms(2026, 2, 27, 9, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&feb_28, Weekday::Mon), "Feb 28 is the last day");
+        assert!(!periodicity.matches_constraints(&feb_27, Weekday::Mon), "Feb 27 is not the last day");
+        
+        // March (31 days)
+        let mar_31 = Utc.with_ymd_and_hms(2026, 3, 31, 9, 0, 0).unwrap();
+        let mar_30 = Utc.with_ymd_and_hms(2026, 3, 30, 9, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&mar_31, Weekday::Mon), "Mar 31 is the last day");
+        assert!(!periodicity.matches_constraints(&mar_30, Weekday::Mon), "Mar 30 is not the last day");
+    }
+
+    #[test]
+    fn test_every_n_months_day_of_month_anchor_clamps() {
+        // Anchored on Jan 31, every 1 month: clamps to Feb 28, back to Mar 31
+        let reference = Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap();
+        
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_months(1)
+            .anchored_to(MonthAnchor::default())
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        
+        let feb_28 = Utc.with_ymd_and_hms(2026, 2, 28, 0, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&feb_28, Weekday::Mon));
+        
+        let mar_28 = Utc.with_ymd_and_hms(2026, 3, 28, 0, 0, 0).unwrap();
+        let mar_31 = Utc.with_ymd_and_hms(2026, 3, 31, 0, 0, 0).unwrap();
+        assert!(!periodicity.matches_constraints(&mar_28, Weekday::Mon));
+        assert!(periodicity.matches_constraints(&mar_31, Weekday::Mon));
+    }
+
+    #[test]
+    fn test_every_n_months_first_day_anchor() {
+        let reference = Utc.with_ymd_and_hms(2026, 1, 15, 0, 0, 0).unwrap();
+        
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_months(2)
+            .anchored_to(MonthAnchor::FirstDay)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        
+        let mar_1 = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        let mar_2 = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+        let feb_1 = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&mar_1, Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&mar_2, Weekday::Mon));
+        assert!(!periodicity.matches_constraints(&feb_1, Weekday::Mon), "February is an off month");
+    }
+
+    #[test]
+    fn test_month_anchor_requires_every_n_months() {
+        let result = PeriodicityBuilder::new()
+            .daily(1)
+            .in_months(vec![Month::January])
+            .anchored_to(MonthAnchor::LastDay)
+            .build();
+        
+        assert!(matches!(result, Err(PeriodicityValidationError::InvalidValue { .. })));
+    }
+
     #[test]
     fn test_every_n_years_with_reference_date() {
         // EveryNYears(2) - every 2 years starting from 2026
@@ -755,6 +1300,182 @@ mod periodicity_tests {
         assert!(periodicity.matches_constraints(&y2030, Weekday::Mon), "2030 (year 4) should match");
     }
 
+    #[test]
+    fn test_fiscal_year_boundary_april() {
+        let march_31 = Utc.with_ymd_and_hms(2027, 3, 31, 23, 0, 0).unwrap();
+        let april_1 = Utc.with_ymd_and_hms(2027, 4, 1, 0, 0, 0).unwrap();
+        let feb = Utc.with_ymd_and_hms(2027, 2, 15, 12, 0, 0).unwrap();
+        
+        assert_eq!(Periodicity::fiscal_year(&march_31, Month::April), 2026);
+        assert_eq!(Periodicity::fiscal_year(&april_1, Month::April), 2027);
+        assert_eq!(Periodicity::fiscal_year(&feb, Month::April), 2026);
+        assert_eq!(Periodicity::fiscal_year(&feb, Month::January), 2027);
+    }
+
+    #[test]
+    fn test_specific_years_use_fiscal_year() {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .in_years(vec![2026])
+            .fiscal_year_starting(Month::April)
+            .build()
+            .unwrap();
+        
+        let cases = [
+            ((2026, 3, 31), false), // Fiscal 2025
+            ((2026, 4, 1), true),
+            ((2027, 2, 15), true),  // Still fiscal 2026
+            ((2027, 3, 31), true),
+            ((2027, 4, 1), false),  // Fiscal 2027
+        ];
+        for ((y, m, d), expected) in cases {
+            let date = Utc.with_ymd_and_hms(y, m, d, 12, 0, 0).unwrap();
+            assert_eq!(periodicity.matches_constraints(&date, Weekday::Mon), expected, "{}-{:02}-{:02}", y, m, d);
+        }
+    }
+
+    #[test]
+    fn test_every_n_years_uses_fiscal_year() {
+        // Reference in fiscal 2026; every 2 fiscal years → 2026, 2028, ...
+        let reference = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_years(2)
+            .with_reference_date(reference)
+            .fiscal_year_starting(Month::April)
+            .build()
+            .unwrap();
+        
+        // Feb 2027 is fiscal 2026 → matches, although calendar years differ by one
+        let feb_2027 = Utc.with_ymd_and_hms(2027, 2, 15, 12, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&feb_2027, Weekday::Mon));
+        
+        // Apr 2027 starts fiscal 2027 → skipped
+        let apr_2027 = Utc.with_ymd_and_hms(2027, 4, 1, 12, 0, 0).unwrap();
+        assert!(!periodicity.matches_constraints(&apr_2027, Weekday::Mon));
+        
+        // Jan 2029 is fiscal 2028 → matches
+        let jan_2029 = Utc.with_ymd_and_hms(2029, 1, 10, 12, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&jan_2029, Weekday::Mon));
+    }
+
+    #[test]
+    fn test_every_n_years_reference_before_fiscal_start() {
+        // March 2026 is fiscal 2025, so every 2 fiscal years → 2025, 2027, ...
+        let reference = Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap();
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_years(2)
+            .with_reference_date(reference)
+            .fiscal_year_starting(Month::April)
+            .build()
+            .unwrap();
+        
+        // March 2027 is fiscal 2026 → skipped, although calendar years differ by one
+        let march_2027 = Utc.with_ymd_and_hms(2027, 3, 10, 12, 0, 0).unwrap();
+        assert!(!periodicity.matches_constraints(&march_2027, Weekday::Mon));
+        
+        // April 2027 starts fiscal 2027 → matches
+        let april_2027 = Utc.with_ymd_and_hms(2027, 4, 10, 12, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&april_2027, Weekday::Mon));
+    }
+
+    #[test]
+    fn test_every_n_days_does_not_match_before_reference() {
+        // 3-day cadence anchored Jan 10: Jan 7 is 3 days before and must not match
+        let reference = Utc.with_ymd_and_hms(2026, 1, 10, 0, 0, 0).unwrap();
+        
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_days(3)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        
+        let jan_7 = Utc.with_ymd_and_hms(2026, 1, 7, 0, 0, 0).unwrap();
+        assert!(!periodicity.matches_constraints(&jan_7, Weekday::Mon), "Jan 7 is before the anchor");
+        
+        let jan_13 = Utc.with_ymd_and_hms(2026, 1, 13, 0, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&jan_13, Weekday::Mon), "Jan 13 (day 3) should match");
+    }
+
+    #[test]
+    fn test_every_n_days_counts_calendar_days() {
+        // A later time-of-day on the anchor should not shift the cadence
+        let reference = Utc.with_ymd_and_hms(2026, 1, 10, 18, 0, 0).unwrap();
+        
+        let periodicity = PeriodicityBuilder::new()
+            .daily(1)
+            .every_n_days(3)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        
+        let jan_13_morning = Utc.with_ymd_and_hms(2026, 1, 13, 6, 0, 0).unwrap();
+        assert!(periodicity.matches_constraints(&jan_13_morning, Weekday::Mon));
+    }
+
+    #[test]
+    fn test_every_n_weeks_months_years_do_not_match_before_reference() {
+        let reference = Utc.with_ymd_and_hms(2026, 6, 15, 0, 0, 0).unwrap(); // Monday
+        
+        let weeks = PeriodicityBuilder::new()
+            .weekly(1)
+            .every_n_weeks(2)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        let two_weeks_before = Utc.with_ymd_and_hms(2026, 6, 1, 12, 0, 0).unwrap();
+        assert!(!weeks.matches_constraints(&two_weeks_before, Weekday::Mon));
+        
+        let months = PeriodicityBuilder::new()
+            .monthly(1)
+            .every_n_months(2)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        let two_months_before = Utc.with_ymd_and_hms(2026, 4, 15, 0, 0, 0).unwrap();
+        assert!(!months.matches_constraints(&two_months_before, Weekday::Mon));
+        
+        let years = PeriodicityBuilder::new()
+            .yearly(1)
+            .every_n_years(2)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        let two_years_before = Utc.with_ymd_and_hms(2024, 6, 15, 0, 0, 0).unwrap();
+        assert!(!years.matches_constraints(&two_years_before, Weekday::Mon));
+    }
+
+    #[test]
+    fn test_every_n_months_years_do_not_match_earlier_in_reference_period() {
+        let reference = Utc.with_ymd_and_hms(2026, 6, 15, 0, 0, 0).unwrap();
+        
+        // Same month, before the reference day (no month anchor)
+        let months = PeriodicityBuilder::new()
+            .monthly(1)
+            .every_n_months(2)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        let june_1 = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+        let june_14 = Utc.with_ymd_and_hms(2026, 6, 14, 23, 0, 0).unwrap();
+        assert!(!months.matches_constraints(&june_1, Weekday::Mon));
+        assert!(!months.matches_constraints(&june_14, Weekday::Mon));
+        assert!(months.matches_constraints(&reference, Weekday::Mon));
+        
+        // Same year, before the reference date
+        let years = PeriodicityBuilder::new()
+            .yearly(1)
+            .every_n_years(2)
+            .with_reference_date(reference)
+            .build()
+            .unwrap();
+        let march = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        assert!(!years.matches_constraints(&march, Weekday::Mon));
+        assert!(years.matches_constraints(&Utc.with_ymd_and_hms(2026, 12, 1, 0, 0, 0).unwrap(), Weekday::Mon));
+    }
+
     #[test]
     fn test_reference_date_fallback_to_timeframe() {
         // When reference_date is not set, should use timeframe.start
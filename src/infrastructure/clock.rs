@@ -1,39 +1,10 @@
-/// Clock abstraction for time injection
+//! Clock implementations
+//!
+//! The `Clock` trait, `SystemClock` and the test-only `FixedClock` live in
+//! the domain, which stamps entities with them; they are re-exported here
+//! for existing callers.
 
-use chrono::{DateTime, Utc};
-
-/// Trait for providing current time
-/// This allows for easy testing and deterministic behavior
-pub trait Clock {
-    /// Get the current UTC time
-    fn now(&self) -> DateTime<Utc>;
-}
-
-/// System clock implementation using actual system time
-pub struct SystemClock;
-
-impl Clock for SystemClock {
-    fn now(&self) -> DateTime<Utc> {
-        Utc::now()
-    }
-}
-
-/// Fixed clock for testing (always returns the same time)
-#[cfg(test)]
-pub struct FixedClock {
-    time: DateTime<Utc>,
-}
-
-#[cfg(test)]
-impl FixedClock {
-    pub fn new(time: DateTime<Utc>) -> Self {
-        Self { time }
-    }
-}
+pub use crate::domain::clock::{Clock, SystemClock};
 
 #[cfg(test)]
-impl Clock for FixedClock {
-    fn now(&self) -> DateTime<Utc> {
-        self.time
-    }
-}
+pub use crate::domain::clock::FixedClock;
@@ -1,4 +1,4 @@
-/// In-memory repository implementations
+//! In-memory repository implementations
 
 pub mod user_repository;
 pub mod task_repository;
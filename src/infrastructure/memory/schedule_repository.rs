@@ -1,4 +1,4 @@
-/// In-memory schedule repository implementation
+//! In-memory schedule repository implementation
 
 use std::collections::HashMap;
 use crate::application::errors::{AppError, AppResult};
@@ -26,6 +26,12 @@ impl InMemoryScheduleRepository {
     }
 }
 
+impl Default for InMemoryScheduleRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ScheduleRepository for InMemoryScheduleRepository {
     fn save_template(&mut self, user_id: UserId, template: ScheduleTemplate) -> AppResult<ScheduleTemplateId> {
         let template_id = ScheduleTemplateId::new(self.next_template_id);
@@ -1,10 +1,10 @@
-/// In-memory task repository implementation
+//! In-memory task repository implementation
 
 use std::collections::HashMap;
 use crate::application::errors::{AppError, AppResult};
 use crate::application::ports::TaskRepository;
 use crate::application::types::{TaskId, UserId};
-use crate::domain::entities::task::Task;
+use crate::domain::entities::task::{Task, TaskPriority, TaskStatus};
 use chrono::{DateTime, Utc};
 
 /// Key for storing tasks per user
@@ -25,6 +25,12 @@ impl InMemoryTaskRepository {
     }
 }
 
+impl Default for InMemoryTaskRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TaskRepository for InMemoryTaskRepository {
     fn save(&mut self, user_id: UserId, task: Task) -> AppResult<TaskId> {
         let task_id = TaskId::new(self.next_id);
@@ -79,6 +85,36 @@ impl TaskRepository for InMemoryTaskRepository {
         Ok(tasks)
     }
 
+    fn find_by_tag(&self, user_id: UserId, tag: &str) -> AppResult<Vec<(TaskId, Task)>> {
+        let tasks: Vec<(TaskId, Task)> = self.tasks
+            .iter()
+            .filter(|((uid, _), task)| *uid == user_id && task.has_tag(tag))
+            .map(|((_, tid), task)| (*tid, task.clone()))
+            .collect();
+
+        Ok(tasks)
+    }
+
+    fn find_by_status(&self, user_id: UserId, status: TaskStatus) -> AppResult<Vec<(TaskId, Task)>> {
+        let tasks: Vec<(TaskId, Task)> = self.tasks
+            .iter()
+            .filter(|((uid, _), task)| *uid == user_id && task.status() == status)
+            .map(|((_, tid), task)| (*tid, task.clone()))
+            .collect();
+
+        Ok(tasks)
+    }
+
+    fn find_by_priority(&self, user_id: UserId, priority: TaskPriority) -> AppResult<Vec<(TaskId, Task)>> {
+        let tasks: Vec<(TaskId, Task)> = self.tasks
+            .iter()
+            .filter(|((uid, _), task)| *uid == user_id && task.priority() == priority)
+            .map(|((_, tid), task)| (*tid, task.clone()))
+            .collect();
+
+        Ok(tasks)
+    }
+
     fn find_tasks_for_date(&self, user_id: UserId, date: DateTime<Utc>) -> AppResult<Vec<(TaskId, Task)>> {
         // For MVP, return all active tasks
         // In a real implementation, you'd check periodicity to see if the task should occur on this date
@@ -98,3 +134,39 @@ impl TaskRepository for InMemoryTaskRepository {
         Ok(tasks)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::task::{Periodicity, TaskBuilder};
+
+    #[test]
+    fn test_find_by_status_and_priority() {
+        let mut repo = InMemoryTaskRepository::new();
+        let user = UserId::new(1);
+        let task = |title: &str, status, priority| {
+            TaskBuilder::new(title, Periodicity::daily().unwrap())
+                .status(status)
+                .priority(priority)
+                .build()
+                .unwrap()
+        };
+
+        let taxes = repo.save(user, task("Taxes", TaskStatus::Active, TaskPriority::Urgent)).unwrap();
+        let piano = repo.save(user, task("Piano", TaskStatus::Paused, TaskPriority::Low)).unwrap();
+        let emails = repo.save(user, task("Emails", TaskStatus::Active, TaskPriority::Low)).unwrap();
+        let move_out = repo.save(user, task("Move out", TaskStatus::Archived, TaskPriority::Urgent)).unwrap();
+        repo.save(UserId::new(2), task("Other user", TaskStatus::Active, TaskPriority::Low)).unwrap();
+
+        let ids = |tasks: AppResult<Vec<(TaskId, Task)>>| {
+            let mut ids: Vec<TaskId> = tasks.unwrap().into_iter().map(|(id, _)| id).collect();
+            ids.sort_by_key(|id| id.value());
+            ids
+        };
+        assert_eq!(ids(repo.find_by_status(user, TaskStatus::Active)), vec![taxes, emails]);
+        assert_eq!(ids(repo.find_by_status(user, TaskStatus::Paused)), vec![piano]);
+        assert_eq!(ids(repo.find_by_priority(user, TaskPriority::Urgent)), vec![taxes, move_out]);
+        assert_eq!(ids(repo.find_by_priority(user, TaskPriority::Low)), vec![piano, emails]);
+        assert!(ids(repo.find_by_priority(user, TaskPriority::Medium)).is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-/// In-memory user repository implementation
+//! In-memory user repository implementation
 
 use std::collections::HashMap;
 use crate::application::errors::{AppError, AppResult};
@@ -25,6 +25,12 @@ impl InMemoryUserRepository {
     }
 }
 
+impl Default for InMemoryUserRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl UserRepository for InMemoryUserRepository {
     fn save(&mut self, user: User) -> AppResult<UserId> {
         let id = UserId::new(self.next_id);
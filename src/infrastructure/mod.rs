@@ -1,7 +1,11 @@
-/// Infrastructure layer components
+//! Infrastructure layer components
 
 pub mod clock;
 pub mod memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
 pub use clock::{Clock, SystemClock};
 pub use memory::{InMemoryUserRepository, InMemoryTaskRepository, InMemoryScheduleRepository};
+#[cfg(feature = "sqlite")]
+pub use sqlite::{SqliteScheduleRepository, SqliteTaskRepository, SqliteUserRepository};
@@ -0,0 +1,20 @@
+//! SQLite repository implementations
+//!
+//! Repositories borrow a shared `rusqlite::Connection`, so several of them
+//! can work on the same database. Each one creates its own tables in
+//! `migrate()`, which is safe to call on every startup.
+
+pub mod schedule_repository;
+pub mod task_repository;
+pub mod user_repository;
+
+pub use schedule_repository::SqliteScheduleRepository;
+pub use task_repository::SqliteTaskRepository;
+pub use user_repository::SqliteUserRepository;
+
+use crate::application::errors::AppError;
+
+/// Map a database failure to an application error
+pub(crate) fn db_error(error: rusqlite::Error) -> AppError {
+    AppError::InternalError(format!("Database error: {}", error))
+}
@@ -0,0 +1,594 @@
+//! SQLite schedule repository implementation
+//!
+//! Column encodings:
+//! - `days`: comma-separated weekday numbers from Monday ("0,2,4")
+//! - `start_time`/`end_time`: `HH:MM:SS`, end before start for overnight rules
+//! - `availability`, `capabilities`, `location_constraint`: serde JSON, since
+//!   the enums carry payloads (`{"Unavailable":"Sleep"}`)
+//! - `cadence_reference`, `valid_from`, `valid_until`: `YYYY-MM-DD`
+//!
+//! Rules are returned in insertion (ID) order. Which template is active is
+//! not stored here but on the user, in `users.active_schedule_template_id`.
+
+use chrono::{NaiveDate, NaiveTime, Weekday};
+use rusqlite::{params, Connection, OptionalExtension};
+use crate::application::errors::{AppError, AppResult};
+use crate::application::ports::ScheduleRepository;
+use crate::application::types::{RecurringRuleId, ScheduleTemplateId, UserId};
+use crate::domain::entities::schedule::{DateOverride, RecurringRule, ScheduleTemplate};
+use super::db_error;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS schedule_templates (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        user_id INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        timezone TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS idx_schedule_templates_user ON schedule_templates(user_id);
+
+    CREATE TABLE IF NOT EXISTS recurring_rules (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        template_id INTEGER NOT NULL REFERENCES schedule_templates(id) ON DELETE CASCADE,
+        -- Comma-separated days from Monday (0 = Monday)
+        days TEXT NOT NULL,
+        start_time TEXT NOT NULL,
+        end_time TEXT NOT NULL,
+        -- JSON: enums with payloads
+        availability TEXT NOT NULL,
+        capabilities TEXT NOT NULL,
+        location_constraint TEXT NOT NULL,
+        label TEXT,
+        priority INTEGER NOT NULL,
+        -- Both NULL for rules that apply every week
+        cadence_weeks INTEGER,
+        cadence_reference TEXT,
+        -- Inclusive YYYY-MM-DD bounds, NULL when open
+        valid_from TEXT,
+        valid_until TEXT
+    );
+    CREATE INDEX IF NOT EXISTS idx_recurring_rules_template ON recurring_rules(template_id);
+
+    CREATE TABLE IF NOT EXISTS schedule_date_overrides (
+        template_id INTEGER NOT NULL REFERENCES schedule_templates(id) ON DELETE CASCADE,
+        date TEXT NOT NULL,
+        availability TEXT NOT NULL,
+        label TEXT,
+        -- JSON array of time blocks, NULL when the override has none
+        blocks TEXT,
+        PRIMARY KEY (template_id, date)
+    );
+";
+
+/// SQLite implementation of ScheduleRepository
+///
+/// Rules are stored in their own table, so unlike the in-memory repository
+/// each rule keeps a stable `RecurringRuleId`. Rules are returned in
+/// insertion order.
+pub struct SqliteScheduleRepository<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> SqliteScheduleRepository<'a> {
+    pub fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Create the template, rule and date override tables if they don't exist yet
+    pub fn migrate(&self) -> AppResult<()> {
+        self.conn.execute_batch(SCHEMA).map_err(db_error)
+    }
+
+    /// Fail unless the template exists and belongs to the user
+    fn ensure_owned(&self, user_id: UserId, template_id: ScheduleTemplateId) -> AppResult<()> {
+        self.conn
+            .query_row(
+                "SELECT 1 FROM schedule_templates WHERE id = ?1 AND user_id = ?2",
+                params![template_id.value() as i64, user_id.value() as i64],
+                |_| Ok(()),
+            )
+            .optional()
+            .map_err(db_error)?
+            .ok_or(AppError::ScheduleTemplateNotFound(template_id))
+    }
+
+    // ── ROW MAPPING ─────────────────────────────────────────
+
+    fn load_template(&self, template_id: ScheduleTemplateId, name: String, timezone: String) -> AppResult<ScheduleTemplate> {
+        let rules = self.load_rules(template_id)?
+            .into_iter()
+            .map(|(_, rule)| rule)
+            .collect();
+
+        let corrupt = |e: String| {
+            AppError::InternalError(format!("Stored schedule template {} is invalid: {}", template_id.value(), e))
+        };
+        ScheduleTemplate::new(name, timezone, rules)
+            .and_then(|template| template.with_date_overrides(self.load_date_overrides(template_id)?))
+            .map_err(corrupt)
+    }
+
+    fn load_date_overrides(&self, template_id: ScheduleTemplateId) -> Result<Vec<DateOverride>, String> {
+        let mut stmt = self.conn
+            .prepare("SELECT date, availability, label, blocks FROM schedule_date_overrides WHERE template_id = ?1 ORDER BY date")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(params![template_id.value() as i64], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                ))
+            })
+            .map_err(|e| e.to_string())?;
+
+        let mut overrides = Vec::new();
+        for row in rows {
+            let (date, availability, label, blocks) = row.map_err(|e| e.to_string())?;
+            let date = date.parse::<NaiveDate>().map_err(|e| format!("override date {}: {}", date, e))?;
+            let availability = serde_json::from_str(&availability)
+                .map_err(|e| format!("override availability: {}", e))?;
+            let blocks = match blocks {
+                Some(blocks) => serde_json::from_str(&blocks).map_err(|e| format!("override blocks: {}", e))?,
+                None => Vec::new(),
+            };
+            overrides.push(DateOverride::new(date, availability, label).with_blocks(blocks)?);
+        }
+        Ok(overrides)
+    }
+
+    /// Replace the stored date overrides of a template
+    fn store_date_overrides(conn: &Connection, template_id: ScheduleTemplateId, overrides: &[DateOverride]) -> AppResult<()> {
+        conn.execute("DELETE FROM schedule_date_overrides WHERE template_id = ?1", params![template_id.value() as i64])
+            .map_err(db_error)?;
+        for date_override in overrides {
+            let encode_error = |e: serde_json::Error| AppError::InternalError(format!("Failed to encode date override: {}", e));
+            let availability = serde_json::to_string(&date_override.availability).map_err(encode_error)?;
+            let blocks = if date_override.blocks.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&date_override.blocks).map_err(encode_error)?)
+            };
+            conn.execute(
+                "INSERT INTO schedule_date_overrides (template_id, date, availability, label, blocks) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![template_id.value() as i64, date_override.date.to_string(), availability, date_override.label, blocks],
+            )
+            .map_err(db_error)?;
+        }
+        Ok(())
+    }
+
+    fn load_rules(&self, template_id: ScheduleTemplateId) -> AppResult<Vec<(RecurringRuleId, RecurringRule)>> {
+        let mut stmt = self.conn
+            .prepare(
+                "SELECT id, days, start_time, end_time, availability, capabilities,
+                        location_constraint, label, priority, cadence_weeks, cadence_reference,
+                        valid_from, valid_until
+                 FROM recurring_rules WHERE template_id = ?1 ORDER BY id",
+            )
+            .map_err(db_error)?;
+
+        let rows = stmt
+            .query_map(params![template_id.value() as i64], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, String>(6)?,
+                    row.get::<_, Option<String>>(7)?,
+                    row.get::<_, i16>(8)?,
+                    row.get::<_, Option<u8>>(9)?,
+                    row.get::<_, Option<String>>(10)?,
+                    row.get::<_, Option<String>>(11)?,
+                    row.get::<_, Option<String>>(12)?,
+                ))
+            })
+            .map_err(db_error)?;
+
+        let mut rules = Vec::new();
+        for row in rows {
+            let (
+                id, days, start, end, availability, capabilities, location_constraint, label, priority,
+                cadence_weeks, cadence_reference, valid_from, valid_until,
+            ) = row.map_err(db_error)?;
+            let rule_id = RecurringRuleId::new(id as u64);
+            let corrupt = |reason: String| {
+                AppError::InternalError(format!("Stored recurring rule {} is invalid: {}", rule_id.value(), reason))
+            };
+
+            let days = days.split(',')
+                .filter(|day| !day.is_empty())
+                .map(|day| day.parse::<u8>().ok().and_then(|day| Weekday::try_from(day).ok()))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| corrupt(format!("days {}", days)))?;
+            let start = start.parse::<NaiveTime>().map_err(|e| corrupt(format!("start {}: {}", start, e)))?;
+            let end = end.parse::<NaiveTime>().map_err(|e| corrupt(format!("end {}: {}", end, e)))?;
+
+            let mut rule = RecurringRule::new(
+                days,
+                start,
+                end,
+                serde_json::from_str(&availability).map_err(|e| corrupt(format!("availability: {}", e)))?,
+                serde_json::from_str(&capabilities).map_err(|e| corrupt(format!("capabilities: {}", e)))?,
+                serde_json::from_str(&location_constraint).map_err(|e| corrupt(format!("location constraint: {}", e)))?,
+                label,
+                priority,
+            )
+            .map_err(corrupt)?;
+            if let (Some(every_n_weeks), Some(reference)) = (cadence_weeks, cadence_reference) {
+                let reference = reference.parse::<NaiveDate>()
+                    .map_err(|e| corrupt(format!("cadence reference {}: {}", reference, e)))?;
+                rule = rule.with_week_cadence(every_n_weeks, reference).map_err(corrupt)?;
+            }
+            let date = |column: &str, value: Option<String>| {
+                value
+                    .map(|text| text.parse::<NaiveDate>().map_err(|e| corrupt(format!("{} {}: {}", column, text, e))))
+                    .transpose()
+            };
+            let (valid_from, valid_until) = (date("valid_from", valid_from)?, date("valid_until", valid_until)?);
+            if valid_from.is_some() || valid_until.is_some() {
+                rule = rule.with_validity(valid_from, valid_until).map_err(corrupt)?;
+            }
+            rules.push((rule_id, rule));
+        }
+        Ok(rules)
+    }
+
+    fn insert_rule(conn: &Connection, template_id: ScheduleTemplateId, rule: &RecurringRule) -> AppResult<RecurringRuleId> {
+        let (days, availability, capabilities, location_constraint) = Self::encode_rule(rule)?;
+        conn.execute(
+            "INSERT INTO recurring_rules
+             (template_id, days, start_time, end_time, availability, capabilities, location_constraint, label, priority,
+              cadence_weeks, cadence_reference, valid_from, valid_until)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            params![
+                template_id.value() as i64,
+                days,
+                rule.start.to_string(),
+                rule.end.to_string(),
+                availability,
+                capabilities,
+                location_constraint,
+                rule.label,
+                rule.priority,
+                rule.week_cadence.map(|(every_n_weeks, _)| every_n_weeks),
+                rule.week_cadence.map(|(_, reference)| reference.to_string()),
+                rule.valid_from.map(|date| date.to_string()),
+                rule.valid_until.map(|date| date.to_string()),
+            ],
+        )
+        .map_err(db_error)?;
+        Ok(RecurringRuleId::new(conn.last_insert_rowid() as u64))
+    }
+
+    /// Encode the days list and the JSON columns of a rule
+    fn encode_rule(rule: &RecurringRule) -> AppResult<(String, String, String, String)> {
+        let encode = |result: serde_json::Result<String>| {
+            result.map_err(|e| AppError::InternalError(format!("Failed to encode recurring rule: {}", e)))
+        };
+        let days = rule.days.iter()
+            .map(|day| day.num_days_from_monday().to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Ok((
+            days,
+            encode(serde_json::to_string(&rule.availability))?,
+            encode(serde_json::to_string(&rule.capabilities))?,
+            encode(serde_json::to_string(&rule.location_constraint))?,
+        ))
+    }
+}
+
+impl ScheduleRepository for SqliteScheduleRepository<'_> {
+    fn save_template(&mut self, user_id: UserId, template: ScheduleTemplate) -> AppResult<ScheduleTemplateId> {
+        let tx = self.conn.unchecked_transaction().map_err(db_error)?;
+        tx.execute(
+            "INSERT INTO schedule_templates (user_id, name, timezone) VALUES (?1, ?2, ?3)",
+            params![user_id.value() as i64, template.name, template.timezone],
+        )
+        .map_err(db_error)?;
+        let template_id = ScheduleTemplateId::new(tx.last_insert_rowid() as u64);
+
+        for rule in &template.rules {
+            Self::insert_rule(&tx, template_id, rule)?;
+        }
+        Self::store_date_overrides(&tx, template_id, &template.date_overrides)?;
+        tx.commit().map_err(db_error)?;
+
+        Ok(template_id)
+    }
+
+    fn find_template(&self, user_id: UserId, template_id: ScheduleTemplateId) -> AppResult<ScheduleTemplate> {
+        let (name, timezone) = self.conn
+            .query_row(
+                "SELECT name, timezone FROM schedule_templates WHERE id = ?1 AND user_id = ?2",
+                params![template_id.value() as i64, user_id.value() as i64],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(db_error)?
+            .ok_or(AppError::ScheduleTemplateNotFound(template_id))?;
+
+        self.load_template(template_id, name, timezone)
+    }
+
+    fn update_template(&mut self, user_id: UserId, template_id: ScheduleTemplateId, template: ScheduleTemplate) -> AppResult<()> {
+        self.ensure_owned(user_id, template_id)?;
+
+        // Rules are replaced wholesale, so they get fresh IDs
+        let tx = self.conn.unchecked_transaction().map_err(db_error)?;
+        tx.execute(
+            "UPDATE schedule_templates SET name = ?2, timezone = ?3 WHERE id = ?1",
+            params![template_id.value() as i64, template.name, template.timezone],
+        )
+        .map_err(db_error)?;
+        tx.execute("DELETE FROM recurring_rules WHERE template_id = ?1", params![template_id.value() as i64])
+            .map_err(db_error)?;
+        for rule in &template.rules {
+            Self::insert_rule(&tx, template_id, rule)?;
+        }
+        Self::store_date_overrides(&tx, template_id, &template.date_overrides)?;
+        tx.commit().map_err(db_error)
+    }
+
+    fn delete_template(&mut self, user_id: UserId, template_id: ScheduleTemplateId) -> AppResult<()> {
+        self.ensure_owned(user_id, template_id)?;
+
+        let tx = self.conn.unchecked_transaction().map_err(db_error)?;
+        tx.execute("DELETE FROM recurring_rules WHERE template_id = ?1", params![template_id.value() as i64])
+            .map_err(db_error)?;
+        tx.execute("DELETE FROM schedule_date_overrides WHERE template_id = ?1", params![template_id.value() as i64])
+            .map_err(db_error)?;
+        tx.execute("DELETE FROM schedule_templates WHERE id = ?1", params![template_id.value() as i64])
+            .map_err(db_error)?;
+        tx.commit().map_err(db_error)
+    }
+
+    fn list_templates_by_user(&self, user_id: UserId) -> AppResult<Vec<(ScheduleTemplateId, ScheduleTemplate)>> {
+        let mut stmt = self.conn
+            .prepare("SELECT id, name, timezone FROM schedule_templates WHERE user_id = ?1 ORDER BY id")
+            .map_err(db_error)?;
+        let rows = stmt
+            .query_map(params![user_id.value() as i64], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+            })
+            .map_err(db_error)?;
+
+        let mut templates = Vec::new();
+        for row in rows {
+            let (id, name, timezone) = row.map_err(db_error)?;
+            let template_id = ScheduleTemplateId::new(id as u64);
+            templates.push((template_id, self.load_template(template_id, name, timezone)?));
+        }
+        Ok(templates)
+    }
+
+    fn upsert_rule(&mut self, user_id: UserId, template_id: ScheduleTemplateId, rule_id: Option<RecurringRuleId>, rule: RecurringRule) -> AppResult<RecurringRuleId> {
+        self.ensure_owned(user_id, template_id)?;
+
+        let Some(rule_id) = rule_id else {
+            return Self::insert_rule(self.conn, template_id, &rule);
+        };
+
+        let (days, availability, capabilities, location_constraint) = Self::encode_rule(&rule)?;
+        let updated = self.conn
+            .execute(
+                "UPDATE recurring_rules
+                 SET days = ?3, start_time = ?4, end_time = ?5, availability = ?6, capabilities = ?7,
+                     location_constraint = ?8, label = ?9, priority = ?10,
+                     cadence_weeks = ?11, cadence_reference = ?12, valid_from = ?13, valid_until = ?14
+                 WHERE id = ?1 AND template_id = ?2",
+                params![
+                    rule_id.value() as i64,
+                    template_id.value() as i64,
+                    days,
+                    rule.start.to_string(),
+                    rule.end.to_string(),
+                    availability,
+                    capabilities,
+                    location_constraint,
+                    rule.label,
+                    rule.priority,
+                    rule.week_cadence.map(|(every_n_weeks, _)| every_n_weeks),
+                    rule.week_cadence.map(|(_, reference)| reference.to_string()),
+                    rule.valid_from.map(|date| date.to_string()),
+                    rule.valid_until.map(|date| date.to_string()),
+                ],
+            )
+            .map_err(db_error)?;
+        if updated == 0 {
+            return Err(AppError::RecurringRuleNotFound(rule_id));
+        }
+        Ok(rule_id)
+    }
+
+    fn remove_rule(&mut self, user_id: UserId, template_id: ScheduleTemplateId, rule_id: RecurringRuleId) -> AppResult<()> {
+        self.ensure_owned(user_id, template_id)?;
+
+        let deleted = self.conn
+            .execute(
+                "DELETE FROM recurring_rules WHERE id = ?1 AND template_id = ?2",
+                params![rule_id.value() as i64, template_id.value() as i64],
+            )
+            .map_err(db_error)?;
+        if deleted == 0 {
+            return Err(AppError::RecurringRuleNotFound(rule_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::UserRepository;
+    use crate::application::use_cases::SetActiveScheduleTemplate;
+    use crate::domain::entities::schedule::{AvailabilityKind, CapabilitySet, LocationConstraint, OverrideBlock, UnavailableReason};
+    use crate::domain::entities::user::{GeoCoordinates, Location, Timezone, User};
+    use crate::infrastructure::sqlite::SqliteUserRepository;
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        SqliteScheduleRepository::new(&conn).migrate().unwrap();
+        conn
+    }
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    fn work_week() -> ScheduleTemplate {
+        let office = Location::new(
+            Some("Office".to_string()),
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(48.8566, 2.3522).unwrap(),
+        ).unwrap();
+        let weekdays = vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+
+        let rules = vec![
+            RecurringRule::new(
+                weekdays.clone(), time(9), time(17),
+                AvailabilityKind::BusyButFlexible,
+                CapabilitySet::free(),
+                LocationConstraint::MustBeOneOf(vec![office]),
+                Some("Work".to_string()),
+                10,
+            ).unwrap(),
+            RecurringRule::new(
+                weekdays, time(8), time(9),
+                AvailabilityKind::BusyButFlexible,
+                CapabilitySet::in_transit(),
+                LocationConstraint::MustBeNear { center: GeoCoordinates::new(48.85, 2.35).unwrap(), radius_meters: 5_000.0 },
+                Some("Commute".to_string()),
+                5,
+            ).unwrap()
+            .with_week_cadence(2, NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())
+            .unwrap()
+            .with_validity(NaiveDate::from_ymd_opt(2026, 1, 5), None)
+            .unwrap(),
+            RecurringRule::new(
+                vec![Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat],
+                time(23), time(7),
+                AvailabilityKind::Unavailable(UnavailableReason::Sleep),
+                CapabilitySet::free(),
+                LocationConstraint::Any,
+                None,
+                100,
+            ).unwrap(),
+        ];
+
+        ScheduleTemplate::new("Work week".to_string(), "Europe/Paris".to_string(), rules).unwrap()
+            .with_date_overrides(vec![
+                DateOverride::new(
+                    NaiveDate::from_ymd_opt(2026, 12, 25).unwrap(),
+                    AvailabilityKind::Unavailable(UnavailableReason::Other("Christmas".to_string())),
+                    Some("Christmas".to_string()),
+                ),
+                DateOverride::new(NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(), AvailabilityKind::Available, None)
+                    .with_blocks(vec![OverrideBlock::new(
+                        time(20), NaiveTime::MIN,
+                        AvailabilityKind::Unavailable(UnavailableReason::Other("Party".to_string())),
+                        Some("New Year's Eve".to_string()),
+                    ).unwrap()])
+                    .unwrap(),
+            ])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_template_round_trip() {
+        let conn = connection();
+        let mut repo = SqliteScheduleRepository::new(&conn);
+        let user = UserId::new(1);
+
+        let id = repo.save_template(user, work_week()).unwrap();
+
+        assert_eq!(repo.find_template(user, id).unwrap(), work_week());
+        assert_eq!(repo.list_templates_by_user(user).unwrap(), vec![(id, work_week())]);
+        assert!(matches!(repo.find_template(UserId::new(2), id), Err(AppError::ScheduleTemplateNotFound(_))));
+    }
+
+    #[test]
+    fn test_update_and_delete_template() {
+        let conn = connection();
+        let mut repo = SqliteScheduleRepository::new(&conn);
+        let user = UserId::new(1);
+        let id = repo.save_template(user, work_week()).unwrap();
+
+        let mut template = work_week();
+        template.name = "Part time".to_string();
+        template.rules.truncate(1);
+        repo.update_template(user, id, template.clone()).unwrap();
+        assert_eq!(repo.find_template(user, id).unwrap(), template);
+
+        repo.delete_template(user, id).unwrap();
+        assert!(matches!(repo.find_template(user, id), Err(AppError::ScheduleTemplateNotFound(_))));
+        assert!(matches!(repo.delete_template(user, id), Err(AppError::ScheduleTemplateNotFound(_))));
+    }
+
+    #[test]
+    fn test_upsert_and_remove_rule_by_id() {
+        let conn = connection();
+        let mut repo = SqliteScheduleRepository::new(&conn);
+        let user = UserId::new(1);
+        let id = repo.save_template(user, work_week()).unwrap();
+
+        let lunch = RecurringRule::new(
+            vec![Weekday::Mon], time(12), time(13),
+            AvailabilityKind::Available, CapabilitySet::free(), LocationConstraint::Any,
+            Some("Lunch".to_string()), 20,
+        ).unwrap();
+        let rule_id = repo.upsert_rule(user, id, None, lunch.clone()).unwrap();
+        assert_eq!(repo.find_template(user, id).unwrap().rules.last(), Some(&lunch));
+
+        let longer_lunch = RecurringRule { end: time(14), ..lunch };
+        assert_eq!(repo.upsert_rule(user, id, Some(rule_id), longer_lunch.clone()).unwrap(), rule_id);
+        let rules = repo.find_template(user, id).unwrap().rules;
+        assert_eq!(rules.len(), 4);
+        assert_eq!(rules[3], longer_lunch);
+
+        repo.remove_rule(user, id, rule_id).unwrap();
+        assert_eq!(repo.find_template(user, id).unwrap(), work_week());
+        assert!(matches!(repo.remove_rule(user, id, rule_id), Err(AppError::RecurringRuleNotFound(_))));
+        assert!(matches!(
+            repo.upsert_rule(user, id, Some(rule_id), longer_lunch),
+            Err(AppError::RecurringRuleNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_set_active_template_persists() {
+        let conn = connection();
+        let mut users = SqliteUserRepository::new(&conn);
+        users.migrate().unwrap();
+        let user = users.save(User::new(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+        )).unwrap();
+
+        let mut schedules = SqliteScheduleRepository::new(&conn);
+        let template_id = schedules.save_template(user, work_week()).unwrap();
+
+        SetActiveScheduleTemplate::new(&mut users, &schedules)
+            .execute(user, Some(template_id))
+            .unwrap();
+        assert_eq!(
+            SqliteUserRepository::new(&conn).get_active_schedule_template(user).unwrap(),
+            Some(template_id)
+        );
+
+        let missing = ScheduleTemplateId::new(template_id.value() + 1);
+        assert!(matches!(
+            SetActiveScheduleTemplate::new(&mut users, &schedules).execute(user, Some(missing)),
+            Err(AppError::ScheduleTemplateNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,596 @@
+//! SQLite task repository implementation
+
+use chrono::{DateTime, NaiveTime, SecondsFormat, Utc, Weekday};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use crate::application::errors::{AppError, AppResult};
+use crate::application::ports::TaskRepository;
+use crate::application::types::{TaskId, UserId};
+use crate::domain::entities::schedule::{AvailabilityLevel, DeviceAccess, Mobility};
+use crate::domain::entities::task::{Periodicity, Task, TaskBuilder, TaskPriority, TaskStatus};
+use crate::domain::entities::user::{GeoCoordinates, Location};
+use super::db_error;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS tasks (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        user_id INTEGER NOT NULL,
+        title TEXT NOT NULL,
+        description TEXT,
+        status TEXT NOT NULL,
+        priority INTEGER NOT NULL,
+        completion_goal INTEGER,
+        soft_deadline TEXT,
+        -- JSON: the periodicity has too many nested shapes for columns
+        periodicity TEXT NOT NULL,
+        -- JSON: locations and capability requirements
+        requirements TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        -- JSON array of normalized tags
+        tags TEXT NOT NULL DEFAULT '[]'
+    );
+    CREATE INDEX IF NOT EXISTS idx_tasks_user ON tasks(user_id);
+";
+
+const TASK_COLUMNS: &str = "id, title, description, status, priority, completion_goal, \
+                            periodicity, requirements, created_at, updated_at, tags, soft_deadline";
+
+/// Where and how a task can be done, stored as one JSON document
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Requirements {
+    locations: Vec<Option<Location>>,
+    min_hands: AvailabilityLevel,
+    min_eyes: AvailabilityLevel,
+    min_speech: AvailabilityLevel,
+    min_cognitive: AvailabilityLevel,
+    min_device: DeviceAccess,
+    allowed_mobility: Vec<Mobility>,
+    #[serde(default)]
+    preferred_time_window: Option<(NaiveTime, NaiveTime)>,
+}
+
+impl From<&Task> for Requirements {
+    fn from(task: &Task) -> Self {
+        Self {
+            locations: task.locations().to_vec(),
+            min_hands: task.min_hands(),
+            min_eyes: task.min_eyes(),
+            min_speech: task.min_speech(),
+            min_cognitive: task.min_cognitive(),
+            min_device: task.min_device(),
+            allowed_mobility: task.allowed_mobility().to_vec(),
+            preferred_time_window: task.preferred_time_window(),
+        }
+    }
+}
+
+/// Raw column values of a `tasks` row
+struct TaskRow {
+    id: i64,
+    title: String,
+    description: Option<String>,
+    status: String,
+    priority: u8,
+    completion_goal: Option<u32>,
+    periodicity: String,
+    requirements: String,
+    created_at: String,
+    updated_at: String,
+    tags: String,
+    soft_deadline: Option<String>,
+}
+
+impl TaskRow {
+    fn read(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            title: row.get(1)?,
+            description: row.get(2)?,
+            status: row.get(3)?,
+            priority: row.get(4)?,
+            completion_goal: row.get(5)?,
+            periodicity: row.get(6)?,
+            requirements: row.get(7)?,
+            created_at: row.get(8)?,
+            updated_at: row.get(9)?,
+            tags: row.get(10)?,
+            soft_deadline: row.get(11)?,
+        })
+    }
+}
+
+/// SQLite implementation of TaskRepository
+pub struct SqliteTaskRepository<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> SqliteTaskRepository<'a> {
+    pub fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Create the task table if it doesn't exist yet
+    pub fn migrate(&self) -> AppResult<()> {
+        self.conn.execute_batch(SCHEMA).map_err(db_error)
+    }
+
+    // ── ROW MAPPING ─────────────────────────────────────────
+
+    fn query_tasks(&self, user_id: UserId, active_only: bool) -> AppResult<Vec<(TaskId, Task)>> {
+        let mut sql = format!("SELECT {} FROM tasks WHERE user_id = ?1", TASK_COLUMNS);
+        if active_only {
+            sql.push_str(" AND status = 'Active'");
+        }
+        self.query(sql, params![user_id.value() as i64])
+    }
+
+    /// Run a task query and rebuild each row, in ID order
+    fn query(&self, mut sql: String, params: impl rusqlite::Params) -> AppResult<Vec<(TaskId, Task)>> {
+        sql.push_str(" ORDER BY id");
+
+        let mut stmt = self.conn.prepare(&sql).map_err(db_error)?;
+        let rows = stmt
+            .query_map(params, TaskRow::read)
+            .map_err(db_error)?;
+
+        let mut tasks = Vec::new();
+        for row in rows {
+            let row = row.map_err(db_error)?;
+            let id = TaskId::new(row.id as u64);
+            tasks.push((id, Self::build_task(id, row)?));
+        }
+        Ok(tasks)
+    }
+
+    /// Rebuild a task from its row, re-running domain validation
+    fn build_task(id: TaskId, row: TaskRow) -> AppResult<Task> {
+        let corrupt = |reason: String| {
+            AppError::InternalError(format!("Stored task {} is invalid: {}", id.value(), reason))
+        };
+
+        let periodicity: Periodicity = serde_json::from_str(&row.periodicity)
+            .map_err(|e| corrupt(format!("periodicity: {}", e)))?;
+        periodicity.validate().map_err(|e| corrupt(e.to_string()))?;
+
+        let requirements: Requirements = serde_json::from_str(&row.requirements)
+            .map_err(|e| corrupt(format!("requirements: {}", e)))?;
+        let locations = requirements.locations.into_iter()
+            .map(|location| location.map(Self::revalidate_location).transpose())
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(corrupt)?;
+
+        let status = [TaskStatus::Active, TaskStatus::Paused, TaskStatus::Archived]
+            .into_iter()
+            .find(|status| status_name(*status) == row.status)
+            .ok_or_else(|| corrupt(format!("unknown status {}", row.status)))?;
+        let priority = match row.priority {
+            1 => TaskPriority::Low,
+            2 => TaskPriority::Medium,
+            3 => TaskPriority::High,
+            4 => TaskPriority::Urgent,
+            other => return Err(corrupt(format!("unknown priority {}", other))),
+        };
+        let tags: Vec<String> = serde_json::from_str(&row.tags)
+            .map_err(|e| corrupt(format!("tags: {}", e)))?;
+        let created_at = parse_timestamp(&row.created_at).map_err(&corrupt)?;
+        let updated_at = parse_timestamp(&row.updated_at).map_err(&corrupt)?;
+        let soft_deadline = row.soft_deadline.as_deref().map(parse_timestamp).transpose().map_err(&corrupt)?;
+
+        let mut builder = TaskBuilder::new(row.title, periodicity)
+            .status(status)
+            .priority(priority)
+            .locations(locations)
+            .min_hands(requirements.min_hands)
+            .min_eyes(requirements.min_eyes)
+            .min_speech(requirements.min_speech)
+            .min_cognitive(requirements.min_cognitive)
+            .min_device(requirements.min_device)
+            .allowed_mobility(requirements.allowed_mobility)
+            .tags(tags)
+            .timestamps(created_at, updated_at);
+        if let Some(description) = row.description {
+            builder = builder.description(description);
+        }
+        if let Some(goal) = row.completion_goal {
+            builder = builder.completion_goal(goal);
+        }
+        if let Some(deadline) = soft_deadline {
+            builder = builder.soft_deadline(deadline);
+        }
+        if let Some((start, end)) = requirements.preferred_time_window {
+            builder = builder.preferred_time_window(start, end);
+        }
+
+        builder.build().map_err(|e| corrupt(e.to_string()))
+    }
+
+    fn revalidate_location(location: Location) -> Result<Location, String> {
+        let geoloc = GeoCoordinates::new(location.geoloc().latitude(), location.geoloc().longitude())
+            .map_err(|e| e.to_string())?;
+        Location::new(
+            location.name().map(str::to_string),
+            location.city().to_string(),
+            location.country().to_string(),
+            geoloc,
+        )
+        .map_err(|e| e.to_string())
+    }
+
+    /// Encode the JSON columns of a task
+    fn encode(task: &Task) -> AppResult<(String, String, String)> {
+        let periodicity = serde_json::to_string(task.periodicity())
+            .map_err(|e| AppError::InternalError(format!("Failed to encode periodicity: {}", e)))?;
+        let requirements = serde_json::to_string(&Requirements::from(task))
+            .map_err(|e| AppError::InternalError(format!("Failed to encode requirements: {}", e)))?;
+        let tags = serde_json::to_string(task.tags())
+            .map_err(|e| AppError::InternalError(format!("Failed to encode tags: {}", e)))?;
+        Ok((periodicity, requirements, tags))
+    }
+}
+
+fn status_name(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Active => "Active",
+        TaskStatus::Paused => "Paused",
+        TaskStatus::Archived => "Archived",
+    }
+}
+
+/// RFC 3339 with full sub-second precision, so timestamps round-trip exactly
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|e| format!("timestamp {}: {}", value, e))
+}
+
+impl TaskRepository for SqliteTaskRepository<'_> {
+    fn save(&mut self, user_id: UserId, task: Task) -> AppResult<TaskId> {
+        let (periodicity, requirements, tags) = Self::encode(&task)?;
+        self.conn
+            .execute(
+                "INSERT INTO tasks (user_id, title, description, status, priority, completion_goal,
+                                    periodicity, requirements, created_at, updated_at, tags, soft_deadline)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                params![
+                    user_id.value() as i64,
+                    task.title(),
+                    task.description(),
+                    status_name(task.status()),
+                    task.priority() as u8,
+                    task.completion_goal(),
+                    periodicity,
+                    requirements,
+                    format_timestamp(task.created_at()),
+                    format_timestamp(task.updated_at()),
+                    tags,
+                    task.soft_deadline().map(format_timestamp),
+                ],
+            )
+            .map_err(db_error)?;
+        Ok(TaskId::new(self.conn.last_insert_rowid() as u64))
+    }
+
+    fn find_by_id(&self, user_id: UserId, task_id: TaskId) -> AppResult<Task> {
+        let row = self.conn
+            .query_row(
+                &format!("SELECT {} FROM tasks WHERE id = ?1 AND user_id = ?2", TASK_COLUMNS),
+                params![task_id.value() as i64, user_id.value() as i64],
+                TaskRow::read,
+            )
+            .optional()
+            .map_err(db_error)?
+            .ok_or(AppError::TaskNotFound(task_id))?;
+
+        Self::build_task(task_id, row)
+    }
+
+    fn update(&mut self, user_id: UserId, task_id: TaskId, task: Task) -> AppResult<()> {
+        let (periodicity, requirements, tags) = Self::encode(&task)?;
+        let updated = self.conn
+            .execute(
+                "UPDATE tasks SET title = ?3, description = ?4, status = ?5, priority = ?6,
+                                  completion_goal = ?7, periodicity = ?8, requirements = ?9,
+                                  created_at = ?10, updated_at = ?11, tags = ?12, soft_deadline = ?13
+                 WHERE id = ?1 AND user_id = ?2",
+                params![
+                    task_id.value() as i64,
+                    user_id.value() as i64,
+                    task.title(),
+                    task.description(),
+                    status_name(task.status()),
+                    task.priority() as u8,
+                    task.completion_goal(),
+                    periodicity,
+                    requirements,
+                    format_timestamp(task.created_at()),
+                    format_timestamp(task.updated_at()),
+                    tags,
+                    task.soft_deadline().map(format_timestamp),
+                ],
+            )
+            .map_err(db_error)?;
+        if updated == 0 {
+            return Err(AppError::TaskNotFound(task_id));
+        }
+        Ok(())
+    }
+
+    fn delete(&mut self, user_id: UserId, task_id: TaskId) -> AppResult<()> {
+        let deleted = self.conn
+            .execute(
+                "DELETE FROM tasks WHERE id = ?1 AND user_id = ?2",
+                params![task_id.value() as i64, user_id.value() as i64],
+            )
+            .map_err(db_error)?;
+        if deleted == 0 {
+            return Err(AppError::TaskNotFound(task_id));
+        }
+        Ok(())
+    }
+
+    fn list_by_user(&self, user_id: UserId) -> AppResult<Vec<(TaskId, Task)>> {
+        self.query_tasks(user_id, false)
+    }
+
+    fn list_active_by_user(&self, user_id: UserId) -> AppResult<Vec<(TaskId, Task)>> {
+        self.query_tasks(user_id, true)
+    }
+
+    fn find_by_tag(&self, user_id: UserId, tag: &str) -> AppResult<Vec<(TaskId, Task)>> {
+        let sql = format!(
+            "SELECT {} FROM tasks WHERE user_id = ?1 \
+             AND EXISTS (SELECT 1 FROM json_each(tasks.tags) WHERE value = ?2)",
+            TASK_COLUMNS,
+        );
+        self.query(sql, params![user_id.value() as i64, Task::normalize_tag(tag)])
+    }
+
+    fn find_by_status(&self, user_id: UserId, status: TaskStatus) -> AppResult<Vec<(TaskId, Task)>> {
+        let sql = format!("SELECT {} FROM tasks WHERE user_id = ?1 AND status = ?2", TASK_COLUMNS);
+        self.query(sql, params![user_id.value() as i64, status_name(status)])
+    }
+
+    fn find_by_priority(&self, user_id: UserId, priority: TaskPriority) -> AppResult<Vec<(TaskId, Task)>> {
+        let sql = format!("SELECT {} FROM tasks WHERE user_id = ?1 AND priority = ?2", TASK_COLUMNS);
+        self.query(sql, params![user_id.value() as i64, priority as u8])
+    }
+
+    fn find_tasks_for_date(&self, user_id: UserId, date: DateTime<Utc>) -> AppResult<Vec<(TaskId, Task)>> {
+        // Periodicities live in JSON, so matching happens after loading.
+        // Monday is assumed as week start, as in the in-memory repository.
+        let week_start = Weekday::Mon;
+
+        Ok(self.query_tasks(user_id, true)?
+            .into_iter()
+            .filter(|(_, task)| task.should_occur_on(&date, week_start))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Month, NaiveDate, NaiveTime, TimeZone};
+    use crate::domain::entities::task::{
+        MonthAnchor, OccurrenceTimingSettings, PeriodicityBuilder, RepTimingSettings, WeekCountingMode,
+    };
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        SqliteTaskRepository::new(&conn).migrate().unwrap();
+        conn
+    }
+
+    fn complex_task() -> Task {
+        let periodicity = PeriodicityBuilder::new()
+            .daily(2)
+            .on_weekdays(vec![Weekday::Mon, Weekday::Thu])
+            .in_months(vec![Month::March, Month::April])
+            .at_times(vec![
+                NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
+            ])
+            .except_dates(vec![NaiveDate::from_ymd_opt(2026, 3, 5).unwrap()])
+            .limit_occurrences(20)
+            .build()
+            .unwrap();
+
+        let gym = Location::new(
+            Some("Gym".to_string()),
+            "Lyon".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(45.764_043, 4.835_659).unwrap(),
+        ).unwrap();
+
+        let created = Utc.with_ymd_and_hms(2026, 2, 1, 10, 0, 0).unwrap();
+        TaskBuilder::new("Stretch", periodicity)
+            .description("Ten minutes")
+            .completion_goal(40)
+            .priority(TaskPriority::High)
+            .locations(vec![Some(gym), None])
+            .min_hands(AvailabilityLevel::Full)
+            .min_device(DeviceAccess::PhoneOnly)
+            .allowed_mobility(vec![Mobility::Stationary])
+            .preferred_time_window(NaiveTime::from_hms_opt(7, 0, 0).unwrap(), NaiveTime::from_hms_opt(9, 30, 0).unwrap())
+            .tags(vec!["health".to_string(), "morning".to_string()])
+            .soft_deadline(created + chrono::Duration::days(30))
+            .timestamps(created, created + chrono::Duration::nanoseconds(1_500))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_save_and_find_round_trip() {
+        let conn = connection();
+        let mut repo = SqliteTaskRepository::new(&conn);
+        let user = UserId::new(1);
+
+        let id = repo.save(user, complex_task()).unwrap();
+
+        assert_eq!(repo.find_by_id(user, id).unwrap(), complex_task());
+        assert!(matches!(repo.find_by_id(UserId::new(2), id), Err(AppError::TaskNotFound(_))));
+    }
+
+    #[test]
+    fn test_periodicity_shapes_round_trip() {
+        let conn = connection();
+        let mut repo = SqliteTaskRepository::new(&conn);
+        let user = UserId::new(1);
+        let at = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 9, 0, 0).unwrap();
+
+        let periodicities = vec![
+            PeriodicityBuilder::new()
+                .monthly(1)
+                .on_last_weekday(Weekday::Fri)
+                .every_n_months(2)
+                .anchored_to(MonthAnchor::LastDay)
+                .starting_from(at(2026, 1, 1))
+                .build()
+                .unwrap(),
+            PeriodicityBuilder::new()
+                .weekly(3)
+                .on_weeks_of_month_from_end(vec![1])
+                .count_weeks_by(WeekCountingMode::CalendarDayBlocks)
+                .with_occurrence_settings(OccurrenceTimingSettings {
+                    duration: Some(45),
+                    not_before: NaiveTime::from_hms_opt(6, 0, 0),
+                    best_before: NaiveTime::from_hms_opt(22, 0, 0),
+                    rep_timing_settings: Some(vec![RepTimingSettings {
+                        rep_index: 1,
+                        not_before: NaiveTime::from_hms_opt(12, 0, 0),
+                        best_before: None,
+                    }]),
+                })
+                .build()
+                .unwrap(),
+            PeriodicityBuilder::new()
+                .yearly(1)
+                .fiscal_year_starting(Month::April)
+                .in_years(vec![2026, 2027])
+                .build()
+                .unwrap(),
+            PeriodicityBuilder::new()
+                .custom_dates(vec![at(2026, 5, 1), at(2026, 6, 12)])
+                .unwrap()
+                .build()
+                .unwrap(),
+            PeriodicityBuilder::new().unique(at(2026, 12, 24)).build().unwrap(),
+        ];
+
+        for periodicity in periodicities {
+            let task = TaskBuilder::new("Shape", periodicity)
+                .timestamps(at(2026, 1, 1), at(2026, 1, 1))
+                .build()
+                .unwrap();
+            let id = repo.save(user, task.clone()).unwrap();
+            assert_eq!(repo.find_by_id(user, id).unwrap(), task);
+        }
+    }
+
+    #[test]
+    fn test_list_active_and_delete() {
+        let conn = connection();
+        let mut repo = SqliteTaskRepository::new(&conn);
+        let user = UserId::new(1);
+
+        let active = repo.save(user, complex_task()).unwrap();
+        let mut paused = complex_task();
+        paused.set_status(TaskStatus::Paused);
+        let paused = repo.save(user, paused).unwrap();
+        repo.save(UserId::new(2), complex_task()).unwrap();
+
+        assert_eq!(repo.list_by_user(user).unwrap().len(), 2);
+        let listed: Vec<TaskId> = repo.list_active_by_user(user).unwrap().into_iter().map(|(id, _)| id).collect();
+        assert_eq!(listed, vec![active]);
+
+        repo.delete(user, paused).unwrap();
+        assert!(matches!(repo.delete(user, paused), Err(AppError::TaskNotFound(_))));
+        assert_eq!(repo.list_by_user(user).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_update_and_find_for_date() {
+        let conn = connection();
+        let mut repo = SqliteTaskRepository::new(&conn);
+        let user = UserId::new(1);
+        let id = repo.save(user, complex_task()).unwrap();
+
+        // Monday March 9th matches, Tuesday March 10th does not
+        let monday = Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap();
+        let tuesday = Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap();
+        assert_eq!(repo.find_tasks_for_date(user, monday).unwrap().len(), 1);
+        assert!(repo.find_tasks_for_date(user, tuesday).unwrap().is_empty());
+
+        let mut task = complex_task();
+        task.set_title("Stretch longer".to_string()).unwrap();
+        repo.update(user, id, task.clone()).unwrap();
+        assert_eq!(repo.find_by_id(user, id).unwrap(), task);
+        assert!(matches!(repo.update(user, TaskId::new(99), task), Err(AppError::TaskNotFound(_))));
+    }
+
+    #[test]
+    fn test_invalid_stored_periodicity_is_rejected() {
+        let conn = connection();
+        let mut repo = SqliteTaskRepository::new(&conn);
+        let user = UserId::new(1);
+        let id = repo.save(user, complex_task()).unwrap();
+
+        let mut broken = complex_task().periodicity().clone();
+        broken.rep_per_unit = Some(0);
+        conn.execute(
+            "UPDATE tasks SET periodicity = ?1",
+            params![serde_json::to_string(&broken).unwrap()],
+        ).unwrap();
+
+        assert!(matches!(repo.find_by_id(user, id), Err(AppError::InternalError(_))));
+    }
+
+    #[test]
+    fn test_find_by_tag() {
+        let conn = connection();
+        let mut repo = SqliteTaskRepository::new(&conn);
+        let user = UserId::new(1);
+
+        let stretch = repo.save(user, complex_task()).unwrap();
+        let mut report = complex_task();
+        report.set_title("Report".to_string()).unwrap();
+        report.remove_tag("health");
+        report.add_tag("Work").unwrap();
+        let report = repo.save(user, report).unwrap();
+        repo.save(UserId::new(2), complex_task()).unwrap();
+
+        let tagged = |tag| -> Vec<TaskId> {
+            repo.find_by_tag(user, tag).unwrap().into_iter().map(|(id, _)| id).collect()
+        };
+        assert_eq!(tagged(" WORK "), vec![report]);
+        assert_eq!(tagged("morning"), vec![stretch, report]);
+        assert!(tagged("work-ish").is_empty());
+    }
+
+    #[test]
+    fn test_find_by_status_and_priority() {
+        let conn = connection();
+        let mut repo = SqliteTaskRepository::new(&conn);
+        let user = UserId::new(1);
+
+        let stretch = repo.save(user, complex_task()).unwrap();
+        let mut paused = complex_task();
+        paused.set_status(TaskStatus::Paused);
+        paused.set_priority(TaskPriority::Low);
+        let paused = repo.save(user, paused).unwrap();
+        repo.save(UserId::new(2), complex_task()).unwrap();
+
+        let ids = |tasks: AppResult<Vec<(TaskId, Task)>>| -> Vec<TaskId> {
+            tasks.unwrap().into_iter().map(|(id, _)| id).collect()
+        };
+        assert_eq!(ids(repo.find_by_status(user, TaskStatus::Active)), vec![stretch]);
+        assert_eq!(ids(repo.find_by_status(user, TaskStatus::Paused)), vec![paused]);
+        assert!(ids(repo.find_by_status(user, TaskStatus::Archived)).is_empty());
+        assert_eq!(ids(repo.find_by_priority(user, TaskPriority::High)), vec![stretch]);
+        assert_eq!(ids(repo.find_by_priority(user, TaskPriority::Low)), vec![paused]);
+    }
+}
@@ -0,0 +1,438 @@
+//! SQLite user repository implementation
+
+use chrono::{Month, NaiveTime, Weekday};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use crate::application::errors::{AppError, AppResult};
+use crate::application::ports::UserRepository;
+use crate::application::types::{ScheduleTemplateId, UserId};
+use crate::domain::entities::user::{GeoCoordinates, Location, Timezone, User};
+use super::db_error;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS users (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        username TEXT NOT NULL UNIQUE,
+        email TEXT NOT NULL,
+        password_hash TEXT NOT NULL,
+        timezone TEXT NOT NULL,
+        week_start INTEGER NOT NULL,
+        year_start INTEGER NOT NULL,
+        day_start TEXT NOT NULL,
+        active_schedule_template_id INTEGER
+    );
+    CREATE INDEX IF NOT EXISTS idx_users_email ON users(email);
+
+    -- One row per entry of User::locations; a None entry has NULL columns
+    CREATE TABLE IF NOT EXISTS user_locations (
+        user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        name TEXT,
+        city TEXT,
+        country TEXT,
+        latitude_micro INTEGER,
+        longitude_micro INTEGER,
+        PRIMARY KEY (user_id, position)
+    );
+";
+
+const USER_COLUMNS: &str =
+    "id, username, email, password_hash, timezone, week_start, year_start, day_start";
+
+/// SQLite implementation of UserRepository
+pub struct SqliteUserRepository<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> SqliteUserRepository<'a> {
+    pub fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Create the user tables if they don't exist yet
+    pub fn migrate(&self) -> AppResult<()> {
+        self.conn.execute_batch(SCHEMA).map_err(db_error)
+    }
+
+    /// Find a user by email address
+    pub fn find_by_email(&self, email: &str) -> AppResult<(UserId, User)> {
+        self.find_one("email", email)?
+            .ok_or_else(|| AppError::ValidationError(format!("User not found: {}", email)))
+    }
+
+    /// Delete a user and their locations
+    pub fn delete(&mut self, id: UserId) -> AppResult<()> {
+        let tx = self.conn.unchecked_transaction().map_err(db_error)?;
+        tx.execute("DELETE FROM user_locations WHERE user_id = ?1", params![id.value() as i64])
+            .map_err(db_error)?;
+        let deleted = tx.execute("DELETE FROM users WHERE id = ?1", params![id.value() as i64])
+            .map_err(db_error)?;
+        if deleted == 0 {
+            return Err(AppError::UserNotFound(id));
+        }
+        tx.commit().map_err(db_error)
+    }
+
+    // ── ROW MAPPING ─────────────────────────────────────────
+
+    fn find_one(&self, column: &str, value: &str) -> AppResult<Option<(UserId, User)>> {
+        let row = self.conn
+            .query_row(
+                &format!("SELECT {} FROM users WHERE {} = ?1", USER_COLUMNS, column),
+                params![value],
+                Self::read_row,
+            )
+            .optional()
+            .map_err(db_error)?;
+
+        match row {
+            Some(row) => {
+                let id = UserId::new(row.0 as u64);
+                Ok(Some((id, self.build_user(id, row)?)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn read_row(row: &Row) -> rusqlite::Result<(i64, String, String, String, String, u8, u8, String)> {
+        Ok((
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            row.get(3)?,
+            row.get(4)?,
+            row.get(5)?,
+            row.get(6)?,
+            row.get(7)?,
+        ))
+    }
+
+    fn build_user(
+        &self,
+        id: UserId,
+        (_, username, email, password_hash, timezone, week_start, year_start, day_start):
+            (i64, String, String, String, String, u8, u8, String),
+    ) -> AppResult<User> {
+        let corrupt = |field: &str, value: &dyn std::fmt::Display| {
+            AppError::InternalError(format!("Stored user {} has invalid {}: {}", id.value(), field, value))
+        };
+
+        let timezone = Timezone::new(timezone.clone()).map_err(|_| corrupt("timezone", &timezone))?;
+        let week_start = Weekday::try_from(week_start).map_err(|_| corrupt("week_start", &week_start))?;
+        let year_start = Month::try_from(year_start).map_err(|_| corrupt("year_start", &year_start))?;
+        let day_start = day_start.parse::<NaiveTime>().map_err(|_| corrupt("day_start", &day_start))?;
+
+        Ok(User::with_all_settings(
+            username,
+            email,
+            password_hash,
+            timezone,
+            self.load_locations(id)?,
+            week_start,
+            year_start,
+            day_start,
+        ))
+    }
+
+    fn load_locations(&self, id: UserId) -> AppResult<Vec<Option<Location>>> {
+        let mut stmt = self.conn
+            .prepare(
+                "SELECT name, city, country, latitude_micro, longitude_micro
+                 FROM user_locations WHERE user_id = ?1 ORDER BY position",
+            )
+            .map_err(db_error)?;
+
+        let rows = stmt
+            .query_map(params![id.value() as i64], |row| {
+                Ok((
+                    row.get::<_, Option<String>>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<i32>>(3)?,
+                    row.get::<_, Option<i32>>(4)?,
+                ))
+            })
+            .map_err(db_error)?;
+
+        let mut locations = Vec::new();
+        for row in rows {
+            let location = match row.map_err(db_error)? {
+                (name, Some(city), Some(country), Some(lat), Some(lon)) => {
+                    let geoloc = GeoCoordinates::new(
+                        lat as f64 / GeoCoordinates::MICRODEGREES_PER_DEGREE,
+                        lon as f64 / GeoCoordinates::MICRODEGREES_PER_DEGREE,
+                    )
+                    .map_err(|e| AppError::InternalError(format!("Stored location is invalid: {}", e)))?;
+                    let location = Location::new(name, city, country, geoloc)
+                        .map_err(|e| AppError::InternalError(format!("Stored location is invalid: {}", e)))?;
+                    Some(location)
+                }
+                _ => None,
+            };
+            locations.push(location);
+        }
+        Ok(locations)
+    }
+
+    fn store_locations(conn: &Connection, id: UserId, locations: &[Option<Location>]) -> AppResult<()> {
+        conn.execute("DELETE FROM user_locations WHERE user_id = ?1", params![id.value() as i64])
+            .map_err(db_error)?;
+
+        for (position, location) in locations.iter().enumerate() {
+            let (name, city, country, lat, lon) = match location {
+                Some(loc) => (
+                    loc.name(),
+                    Some(loc.city()),
+                    Some(loc.country()),
+                    Some(loc.geoloc().latitude_microdegrees()),
+                    Some(loc.geoloc().longitude_microdegrees()),
+                ),
+                None => (None, None, None, None, None),
+            };
+            conn.execute(
+                "INSERT INTO user_locations
+                 (user_id, position, name, city, country, latitude_micro, longitude_micro)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![id.value() as i64, position as i64, name, city, country, lat, lon],
+            )
+            .map_err(db_error)?;
+        }
+        Ok(())
+    }
+
+    fn ensure_exists(&self, id: UserId) -> AppResult<()> {
+        let exists = self.conn
+            .query_row("SELECT 1 FROM users WHERE id = ?1", params![id.value() as i64], |_| Ok(()))
+            .optional()
+            .map_err(db_error)?;
+        exists.ok_or(AppError::UserNotFound(id))
+    }
+}
+
+impl UserRepository for SqliteUserRepository<'_> {
+    fn save(&mut self, user: User) -> AppResult<UserId> {
+        if self.exists_by_username(&user.username) {
+            return Err(AppError::UserAlreadyExists(user.username));
+        }
+
+        let tx = self.conn.unchecked_transaction().map_err(db_error)?;
+        tx.execute(
+            "INSERT INTO users (username, email, password_hash, timezone, week_start, year_start, day_start)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                user.username,
+                user.email,
+                user.password_hash,
+                user.timezone.as_str(),
+                user.week_start.num_days_from_monday(),
+                user.year_start.number_from_month(),
+                user.day_start.to_string(),
+            ],
+        )
+        .map_err(db_error)?;
+        let id = UserId::new(tx.last_insert_rowid() as u64);
+
+        Self::store_locations(&tx, id, &user.locations)?;
+        tx.commit().map_err(db_error)?;
+
+        Ok(id)
+    }
+
+    fn find_by_id(&self, id: UserId) -> AppResult<User> {
+        self.find_one("id", &id.value().to_string())?
+            .map(|(_, user)| user)
+            .ok_or(AppError::UserNotFound(id))
+    }
+
+    fn find_by_username(&self, username: &str) -> AppResult<(UserId, User)> {
+        self.find_one("username", username)?
+            .ok_or_else(|| AppError::ValidationError(format!("User not found: {}", username)))
+    }
+
+    fn update(&mut self, id: UserId, user: User) -> AppResult<()> {
+        let tx = self.conn.unchecked_transaction().map_err(db_error)?;
+        let updated = tx.execute(
+            "UPDATE users SET username = ?2, email = ?3, password_hash = ?4, timezone = ?5,
+                              week_start = ?6, year_start = ?7, day_start = ?8
+             WHERE id = ?1",
+            params![
+                id.value() as i64,
+                user.username,
+                user.email,
+                user.password_hash,
+                user.timezone.as_str(),
+                user.week_start.num_days_from_monday(),
+                user.year_start.number_from_month(),
+                user.day_start.to_string(),
+            ],
+        )
+        .map_err(db_error)?;
+        if updated == 0 {
+            return Err(AppError::UserNotFound(id));
+        }
+
+        Self::store_locations(&tx, id, &user.locations)?;
+        tx.commit().map_err(db_error)
+    }
+
+    fn exists_by_username(&self, username: &str) -> bool {
+        self.conn
+            .query_row("SELECT 1 FROM users WHERE username = ?1", params![username], |_| Ok(()))
+            .optional()
+            .map(|row| row.is_some())
+            .unwrap_or(false)
+    }
+
+    fn get_active_schedule_template(&self, user_id: UserId) -> AppResult<Option<ScheduleTemplateId>> {
+        let template_id: Option<Option<i64>> = self.conn
+            .query_row(
+                "SELECT active_schedule_template_id FROM users WHERE id = ?1",
+                params![user_id.value() as i64],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(db_error)?;
+
+        template_id
+            .ok_or(AppError::UserNotFound(user_id))
+            .map(|id| id.map(|id| ScheduleTemplateId::new(id as u64)))
+    }
+
+    fn set_active_schedule_template(&mut self, user_id: UserId, template_id: Option<ScheduleTemplateId>) -> AppResult<()> {
+        self.ensure_exists(user_id)?;
+        self.conn
+            .execute(
+                "UPDATE users SET active_schedule_template_id = ?2 WHERE id = ?1",
+                params![user_id.value() as i64, template_id.map(|id| id.value() as i64)],
+            )
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        SqliteUserRepository::new(&conn).migrate().unwrap();
+        conn
+    }
+
+    fn alice() -> User {
+        let home = Location::new(
+            Some("Home".to_string()),
+            "Paris".to_string(),
+            "France".to_string(),
+            GeoCoordinates::new(48.856_613, 2.352_222).unwrap(),
+        ).unwrap();
+
+        User::with_all_settings(
+            "alice".to_string(),
+            "alice@example.com".to_string(),
+            "hash".to_string(),
+            Timezone::new("Europe/Paris".to_string()).unwrap(),
+            vec![Some(home), None],
+            Weekday::Sun,
+            Month::April,
+            NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_save_and_find_round_trip() {
+        let conn = connection();
+        let mut repo = SqliteUserRepository::new(&conn);
+
+        let id = repo.save(alice()).unwrap();
+
+        assert_eq!(repo.find_by_id(id).unwrap(), alice());
+        assert_eq!(repo.find_by_username("alice").unwrap(), (id, alice()));
+        assert_eq!(repo.find_by_email("alice@example.com").unwrap(), (id, alice()));
+        assert!(repo.exists_by_username("alice"));
+        assert!(!repo.exists_by_username("bob"));
+    }
+
+    #[test]
+    fn test_lookups_on_missing_user() {
+        let conn = connection();
+        let mut repo = SqliteUserRepository::new(&conn);
+        repo.save(alice()).unwrap();
+
+        assert!(matches!(repo.find_by_id(UserId::new(99)), Err(AppError::UserNotFound(_))));
+        assert!(matches!(repo.find_by_username("bob"), Err(AppError::ValidationError(_))));
+        assert!(matches!(repo.find_by_email("bob@example.com"), Err(AppError::ValidationError(_))));
+        // Lookups match exactly
+        assert!(repo.find_by_username("Alice").is_err());
+    }
+
+    #[test]
+    fn test_migrate_is_idempotent() {
+        let conn = connection();
+        let mut repo = SqliteUserRepository::new(&conn);
+        let id = repo.save(alice()).unwrap();
+
+        repo.migrate().unwrap();
+        assert!(repo.find_by_id(id).is_ok());
+    }
+
+    #[test]
+    fn test_update_replaces_fields_and_locations() {
+        let conn = connection();
+        let mut repo = SqliteUserRepository::new(&conn);
+        let id = repo.save(alice()).unwrap();
+
+        let mut user = alice();
+        user.username = "alice2".to_string();
+        user.locations = Vec::new();
+        user.week_start = Weekday::Mon;
+        repo.update(id, user.clone()).unwrap();
+
+        assert_eq!(repo.find_by_id(id).unwrap(), user);
+        assert!(!repo.exists_by_username("alice"));
+        assert!(matches!(repo.update(UserId::new(99), alice()), Err(AppError::UserNotFound(_))));
+    }
+
+    #[test]
+    fn test_duplicate_username_rejected() {
+        let conn = connection();
+        let mut repo = SqliteUserRepository::new(&conn);
+        repo.save(alice()).unwrap();
+
+        assert!(matches!(repo.save(alice()), Err(AppError::UserAlreadyExists(_))));
+    }
+
+    #[test]
+    fn test_active_schedule_template() {
+        let conn = connection();
+        let mut repo = SqliteUserRepository::new(&conn);
+        let id = repo.save(alice()).unwrap();
+
+        assert_eq!(repo.get_active_schedule_template(id).unwrap(), None);
+        repo.set_active_schedule_template(id, Some(ScheduleTemplateId::new(7))).unwrap();
+        assert_eq!(repo.get_active_schedule_template(id).unwrap(), Some(ScheduleTemplateId::new(7)));
+        repo.set_active_schedule_template(id, None).unwrap();
+        assert_eq!(repo.get_active_schedule_template(id).unwrap(), None);
+
+        let unknown = UserId::new(99);
+        assert!(matches!(repo.get_active_schedule_template(unknown), Err(AppError::UserNotFound(_))));
+        assert!(matches!(repo.set_active_schedule_template(unknown, None), Err(AppError::UserNotFound(_))));
+    }
+
+    #[test]
+    fn test_delete() {
+        let conn = connection();
+        let mut repo = SqliteUserRepository::new(&conn);
+        let id = repo.save(alice()).unwrap();
+
+        repo.delete(id).unwrap();
+        assert!(matches!(repo.find_by_id(id), Err(AppError::UserNotFound(_))));
+        assert!(matches!(repo.delete(id), Err(AppError::UserNotFound(_))));
+
+        let orphans: i64 = conn
+            .query_row("SELECT COUNT(*) FROM user_locations", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(orphans, 0);
+    }
+}
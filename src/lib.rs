@@ -1,3 +1,6 @@
+#[macro_use]
+mod logging;
+
 pub mod config;
 pub mod domain;
 pub mod application;
@@ -0,0 +1,33 @@
+//! Internal logging macros
+//!
+//! Forward to the `log` facade when the `log` feature is enabled. Without it
+//! they expand to dead code, so arguments still type-check (and count as
+//! used) but nothing is formatted or emitted.
+
+#[cfg(feature = "log")]
+macro_rules! debug {
+    ($($arg:tt)+) => { log::debug!($($arg)+) };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        if false {
+            let _ = format_args!($($arg)+);
+        }
+    };
+}
+
+#[cfg(feature = "log")]
+macro_rules! trace {
+    ($($arg:tt)+) => { log::trace!($($arg)+) };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        if false {
+            let _ = format_args!($($arg)+);
+        }
+    };
+}